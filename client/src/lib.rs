@@ -0,0 +1,167 @@
+//! Decoding and typed filters of task auction events, so that indexers don't hand-roll SCALE
+//! decoding of the runtime's events.
+//!
+//! Records are decoded one at a time from the encoded System Events storage item, and the
+//! filters take them as a stream. Filters are generic over the runtime, whose outer event only
+//! needs to convert into the event of the pallet.
+
+use codec::{Compact, Decode};
+use core::marker::PhantomData;
+use frame_system::EventRecord;
+use pallet_task_auction::{AuctionId, BidId, Config, Outcome};
+
+pub type AuctionEvent<T> = pallet_task_auction::Event<T>;
+pub type AuctionKey<T> =
+	AuctionId<<T as frame_system::Config>::AccountId, <T as frame_system::Config>::Index>;
+pub type BidKey<T> = BidId<<T as frame_system::Config>::AccountId>;
+pub type BalanceOf<T> = <<T as Config>::Currency as frame_support::traits::Currency<
+	<T as frame_system::Config>::AccountId,
+>>::Balance;
+
+// records of a block, decoded lazily from the encoded value of the System Events storage item
+pub struct EventRecords<'a, E, H> {
+	input: &'a [u8],
+	remaining: u32,
+	_marker: PhantomData<(E, H)>,
+}
+
+impl<'a, E: Decode, H: Decode> Iterator for EventRecords<'a, E, H> {
+	type Item = Result<EventRecord<E, H>, codec::Error>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.remaining == 0 {
+			return None
+		}
+		self.remaining -= 1;
+		let record = EventRecord::<E, H>::decode(&mut self.input);
+		// records can't be told apart after a malformed one
+		if record.is_err() {
+			self.remaining = 0;
+		}
+		Some(record)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(0, Some(self.remaining as usize))
+	}
+}
+
+pub fn decode_events<E: Decode, H: Decode>(
+	mut encoded: &[u8],
+) -> Result<EventRecords<'_, E, H>, codec::Error> {
+	let Compact(remaining) = Compact::<u32>::decode(&mut encoded)?;
+	Ok(EventRecords { input: encoded, remaining, _marker: PhantomData })
+}
+
+// task auction events out of a stream of records
+pub fn auction_events<T, E, H>(
+	records: impl IntoIterator<Item = EventRecord<E, H>>,
+) -> impl Iterator<Item = AuctionEvent<T>>
+where
+	T: Config,
+	E: TryInto<AuctionEvent<T>>,
+{
+	records.into_iter().filter_map(|record| record.event.try_into().ok())
+}
+
+// auctions settled with how they were settled
+pub fn filter_settlements<T, E, H>(
+	records: impl IntoIterator<Item = EventRecord<E, H>>,
+) -> impl Iterator<Item = (AuctionKey<T>, Outcome)>
+where
+	T: Config,
+	E: TryInto<AuctionEvent<T>>,
+{
+	auction_events(records).filter_map(|event| match event {
+		AuctionEvent::<T>::Confirmed { auction_key, .. } => Some((auction_key, Outcome::Confirmed)),
+		AuctionEvent::<T>::Cancelled { auction_key } |
+		AuctionEvent::<T>::OpenAuctionExpired { auction_key } |
+		AuctionEvent::<T>::CollusionReviewResolved { auction_key, collusion: true } =>
+			Some((auction_key, Outcome::Cancelled)),
+		AuctionEvent::<T>::Arbitrated { auction_key, fulfilled, .. } =>
+			Some((auction_key, Outcome::Arbitrated { fulfilled })),
+		AuctionEvent::<T>::DefaultJudgment { auction_key, .. } =>
+			Some((auction_key, Outcome::Defaulted)),
+		_ => None,
+	})
+}
+
+// bids placed or improved
+pub fn filter_bids<T, E, H>(
+	records: impl IntoIterator<Item = EventRecord<E, H>>,
+) -> impl Iterator<Item = (AuctionKey<T>, BidKey<T>, BalanceOf<T>)>
+where
+	T: Config,
+	E: TryInto<AuctionEvent<T>>,
+{
+	auction_events(records).filter_map(|event| match event {
+		AuctionEvent::<T>::Bid { auction_key, bid_key, price, .. } |
+		AuctionEvent::<T>::BidImproved { auction_key, bid_key, price, .. } =>
+			Some((auction_key, bid_key, price)),
+		_ => None,
+	})
+}
+
+// auctions that went into dispute
+pub fn filter_disputes<T, E, H>(
+	records: impl IntoIterator<Item = EventRecord<E, H>>,
+) -> impl Iterator<Item = AuctionKey<T>>
+where
+	T: Config,
+	E: TryInto<AuctionEvent<T>>,
+{
+	auction_events(records).filter_map(|event| match event {
+		AuctionEvent::<T>::Disputed { auction_key } |
+		AuctionEvent::<T>::Escalated { auction_key } |
+		AuctionEvent::<T>::CompletionDeadlineMissed { auction_key } => Some(auction_key),
+		_ => None,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use codec::Encode;
+	use frame_system::Phase;
+	use substrate_task_auction_runtime::{AccountId, Event, Hash, Runtime};
+
+	type Record = EventRecord<Event, Hash>;
+
+	fn record(event: Event) -> Record {
+		EventRecord { phase: Phase::Initialization, event, topics: Vec::new() }
+	}
+
+	fn decode(encoded: &[u8]) -> impl Iterator<Item = Record> + '_ {
+		decode_events(encoded).unwrap().map(Result::unwrap)
+	}
+
+	#[test]
+	fn decode_and_filter() {
+		let auction_key = AuctionId(AccountId::new([1; 32]), 0);
+		let encoded = vec![
+			record(Event::System(frame_system::Event::CodeUpdated)),
+			record(Event::TaskAuction(AuctionEvent::Disputed { auction_key: auction_key.clone() })),
+			record(Event::TaskAuction(AuctionEvent::Arbitrated {
+				auction_key: auction_key.clone(),
+				fulfilled: true,
+				verdict_hash: None,
+			})),
+		]
+		.encode();
+		assert_eq!(decode(&encoded).count(), 3);
+		assert_eq!(auction_events::<Runtime, _, _>(decode(&encoded)).count(), 2);
+		assert_eq!(
+			filter_disputes::<Runtime, _, _>(decode(&encoded)).collect::<Vec<_>>(),
+			vec![auction_key.clone()]
+		);
+		assert_eq!(
+			filter_settlements::<Runtime, _, _>(decode(&encoded)).collect::<Vec<_>>(),
+			vec![(auction_key, Outcome::Arbitrated { fulfilled: true })]
+		);
+		assert_eq!(filter_bids::<Runtime, _, _>(decode(&encoded)).count(), 0);
+		// decoding stops at the first malformed record
+		let mut records = decode_events::<Event, Hash>(&encoded[..encoded.len() - 1]).unwrap();
+		assert!(records.nth(2).unwrap().is_err());
+		assert!(records.next().is_none());
+	}
+}
@@ -0,0 +1,144 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+use sp_std::vec::Vec;
+
+// Asset that the amounts of a response are denominated in and its decimals, so that generic
+// wallets render them correctly. No asset id stands for the native currency.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct AssetDisplay {
+	pub asset_id: Option<u32>,
+	pub decimals: u8,
+}
+
+impl AssetDisplay {
+	pub fn native(decimals: u8) -> Self {
+		AssetDisplay { asset_id: None, decimals }
+	}
+
+	// amount in whole units of the asset, e.g. 1500 with 3 decimals as "1.5"
+	#[cfg(feature = "std")]
+	pub fn format(&self, amount: u128) -> String {
+		let unit = 10u128.saturating_pow(self.decimals.into());
+		let fraction = format!("{:0width$}", amount % unit, width = self.decimals as usize);
+		match fraction.trim_end_matches('0') {
+			"" => (amount / unit).to_string(),
+			fraction => format!("{}.{}", amount / unit, fraction),
+		}
+	}
+}
+
+// Breakdown of funds locked and weight charged by create.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct CreateEstimate<Balance> {
+	pub bounty: Balance,
+	pub deposit: Balance,
+	pub listing_fee_base: Balance,
+	pub listing_fee_bytes: Balance,
+	pub reserve_total: Balance,
+	pub weight: u64,
+	pub display: AssetDisplay,
+}
+
+// Funds movement performed when an auction is settled.
+#[derive(Encode, Decode, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub enum FundsMovement<AccountId, Balance> {
+	// escrowed funds returned to the free balance of an account
+	Unlock { who: AccountId, amount: Balance },
+	// payment from one account to another
+	Transfer { from: AccountId, to: AccountId, amount: Balance },
+	// listing fee withdrawn from the owner
+	ListingFee { owner: AccountId, amount: Balance },
+	// arbitration fee held in escrow of the payer until the appeal period is over
+	EscrowArbitrationFee { from: AccountId, to: AccountId, amount: Balance },
+	// fee quoted by the arbitrator when the dispute was raised, paid from escrow of the payer
+	QuotedArbitratorFee { payer: AccountId, arbitrator: AccountId, amount: Balance },
+}
+
+// Current state of an auction with its deadline as an estimated unix timestamp.
+#[derive(Encode, Decode, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct AuctionStatus<Balance, BlockNumber> {
+	pub terminal_block: BlockNumber,
+	// milliseconds since the unix epoch
+	pub terminal_timestamp: u64,
+	pub top_price: Option<Balance>,
+	pub assigned: bool,
+	pub display: AssetDisplay,
+}
+
+// Rulings of an arbitrator, for owners choosing arbitrators and registries reviewing them.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct ArbitratorScorecard<BlockNumber> {
+	pub rulings: u32,
+	pub average_resolution_blocks: BlockNumber,
+	// rulings that took longer than the dispute SLA of the auction category
+	pub sla_breaches: u32,
+	pub appealed: u32,
+	pub overturned: u32,
+}
+
+// Queue depths of the marketplace, for operators alerting on degradation.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct HealthSnapshot<BlockNumber> {
+	pub open_auctions: u32,
+	pub assigned: u32,
+	pub disputed: u32,
+	// auctions queued for a completion deadline
+	pub expiry_queue_depth: u32,
+	// blocks since the oldest dispute still awaiting a ruling was raised
+	pub oldest_dispute_age: Option<BlockNumber>,
+}
+
+// Totals of an account over one reporting period, for earnings summaries.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct AccountStatement<Balance, BlockNumber> {
+	pub start_block: BlockNumber,
+	pub end_block: BlockNumber,
+	// paid to the account as assigned worker
+	pub earned: Balance,
+	// paid by the account as owner or booster
+	pub spent: Balance,
+	// deposits lost to penalties and arbitration fees
+	pub slashed: Balance,
+	pub arbitration_income: Balance,
+}
+
+sp_api::decl_runtime_apis! {
+	// Queries that let clients build valid calls before submitting them.
+	pub trait TaskAuctionApi<AccountId, Index, Balance, BlockNumber> where
+		AccountId: Codec,
+		Index: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+	{
+		// Highest price the next bid may have, None if the auction can't take bids.
+		fn max_next_bid(auction_key: (AccountId, Index)) -> Option<Balance>;
+		// Minimum bounty and deposit accepted by create.
+		fn min_create_params() -> (Balance, Balance);
+		// Funds that create would lock and the weight it would be charged.
+		fn estimate_create(bounty: Balance, deposit: Balance, data_len: u32) -> CreateEstimate<Balance>;
+		// Sum and count of the ratings an account received.
+		fn rating(who: AccountId) -> (u32, u32);
+		// Funds movements of arbitrating a disputed auction as fulfilled and as not fulfilled.
+		fn arbitration_preview(
+			auction_key: (AccountId, Index),
+		) -> Option<(Vec<FundsMovement<AccountId, Balance>>, Vec<FundsMovement<AccountId, Balance>>)>;
+		// Deadline and bidding state of an auction, None if it doesn't exist.
+		fn auction_status(auction_key: (AccountId, Index)) -> Option<AuctionStatus<Balance, BlockNumber>>;
+		// Estimated unix timestamp in milliseconds at which a block is produced.
+		fn estimate_timestamp(block: BlockNumber) -> u64;
+		// Ruling record of an arbitrator.
+		fn arbitrator_scorecard(arbitrator: AccountId) -> ArbitratorScorecard<BlockNumber>;
+		// Counts of open, assigned and disputed auctions and the depth of the deadline queue.
+		fn marketplace_health() -> HealthSnapshot<BlockNumber>;
+		// Totals of an account over a reporting period, numbered from zero at genesis.
+		fn account_statement(who: AccountId, period: u32) -> AccountStatement<Balance, BlockNumber>;
+	}
+}
@@ -2,18 +2,966 @@
 
 use super::*;
 
+use crate::pallet::{
+	ActiveAssignments, ArbitrationEscrows, ArbitratorFees, ArbitratorStakes, Attachments,
+	Attestations, Auctions, AutoBids, BidCommitments, BidVersions, Boosts, Bundles, CircuitBreaker,
+	CollusionReviews, DeliveredItems, DisputeSlas, ExtensionProposals, FirmUntil,
+	MinBidRatioBounds, MisconductReports, Operators, PendingPayouts, PendingRescale, ProjectCount,
+	PromotedAuctions, QueuedRetractions, Ratings, ReceiptDestinations, ReleaseProposals,
+	TemplateCount, TransferOffers, Workers,
+};
 #[allow(unused)]
 use crate::Pallet as TaskAuction;
-use frame_benchmarking::{benchmarks, whitelisted_caller};
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::{
+	dispatch::DispatchError,
+	sp_runtime::{
+		traits::{BlockNumberProvider, Hash, Zero},
+		FixedPointNumber, FixedU128, Permill,
+	},
+	traits::{Currency, EnsureOrigin},
+};
 use frame_system::RawOrigin;
 
+const SEED: u32 = 0;
+
+type AuctionKey<T> =
+	AuctionId<<T as frame_system::Config>::AccountId, <T as frame_system::Config>::Index>;
+type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+// fund an account for the bounties, deposits and stakes of a benchmark
+fn fund<T: Config>(who: &T::AccountId) {
+	T::Currency::make_free_balance_be(who, T::MinBounty::get() * 1_000_000u32.into());
+}
+
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+	let who = account(name, index, SEED);
+	fund::<T>(&who);
+	who
+}
+
+// arbitrator of the auctions listed by benchmarks
+fn arbitrator<T: Config>() -> T::AccountId {
+	account("arbitrator", 0, SEED)
+}
+
+fn now<T: Config>() -> T::BlockNumber {
+	T::BlockNumberProvider::current_block_number()
+}
+
+// both the clock of the pallet and the one of the runtime follow the block number of the system
+fn set_block<T: Config>(block: T::BlockNumber) {
+	frame_system::Pallet::<T>::set_block_number(block);
+}
+
+// list an auction of the minimum bounty and deposit, returning its key
+fn create_auction<T: Config>(owner: &T::AccountId) -> Result<AuctionKey<T>, DispatchError> {
+	list_auction::<T>(owner, false)
+}
+
+// list an open-ended auction of the minimum bounty and deposit, returning its key
+fn create_open_auction<T: Config>(owner: &T::AccountId) -> Result<AuctionKey<T>, DispatchError> {
+	list_auction::<T>(owner, true)
+}
+
+fn list_auction<T: Config>(
+	owner: &T::AccountId,
+	open_ended: bool,
+) -> Result<AuctionKey<T>, DispatchError> {
+	let nonce = frame_system::Pallet::<T>::account_nonce(owner);
+	TaskAuction::<T>::create(
+		RawOrigin::Signed(owner.clone()).into(),
+		arbitrator::<T>(),
+		T::MinBounty::get(),
+		T::MinDeposit::get(),
+		now::<T>() + 1000u32.into(),
+		Vec::new(),
+		CreateOptions { open_ended, ..Default::default() },
+	)?;
+	// auctions are keyed by the nonce of the extrinsic, which benchmarks don't submit
+	frame_system::Pallet::<T>::inc_account_nonce(owner);
+	Ok(AuctionId(owner.clone(), nonce))
+}
+
+// bid the highest price the auction accepts, up to the bounty, returning the price
+fn place_bid<T: Config>(
+	bidder: &T::AccountId,
+	auction_key: &AuctionKey<T>,
+) -> Result<BalanceOf<T>, DispatchError> {
+	let price = TaskAuction::<T>::max_next_bid(auction_key)
+		.ok_or(DispatchError::Other("auction takes no bids"))?
+		.min(T::MinBounty::get());
+	TaskAuction::<T>::bid(RawOrigin::Signed(bidder.clone()).into(), auction_key.clone(), price)?;
+	Ok(price)
+}
+
+// open-ended auction of the owner assigned to the worker by accepting their bid
+fn assigned_auction<T: Config>(
+	owner: &T::AccountId,
+	worker: &T::AccountId,
+) -> Result<AuctionKey<T>, DispatchError> {
+	let auction_key = create_open_auction::<T>(owner)?;
+	place_bid::<T>(worker, &auction_key)?;
+	TaskAuction::<T>::accept_bid(RawOrigin::Signed(owner.clone()).into(), auction_key.clone())?;
+	Ok(auction_key)
+}
+
+// assigned auction disputed by the owner before an arbitrator who quotes a fee
+fn disputed_auction<T: Config>(
+	owner: &T::AccountId,
+	worker: &T::AccountId,
+) -> Result<AuctionKey<T>, DispatchError> {
+	// disputes of arbitrators without an account go to the fallback origin
+	fund::<T>(&arbitrator::<T>());
+	let auction_key = assigned_auction::<T>(owner, worker)?;
+	TaskAuction::<T>::dispute(RawOrigin::Signed(owner.clone()).into(), auction_key.clone())?;
+	Ok(auction_key)
+}
+
+// auction settled by the arbitrator ruling the task fulfilled
+fn arbitrated_auction<T: Config>(
+	owner: &T::AccountId,
+	worker: &T::AccountId,
+) -> Result<AuctionKey<T>, DispatchError> {
+	let auction_key = disputed_auction::<T>(owner, worker)?;
+	TaskAuction::<T>::arbitrate(
+		RawOrigin::Signed(arbitrator::<T>()).into(),
+		auction_key.clone(),
+		true,
+		None,
+	)
+	.map_err(|e| e.error)?;
+	Ok(auction_key)
+}
+
+// arbitration fee of a ruling against the payer, held back until the appeal deadline
+fn escrow_arbitration_fee<T: Config>(
+	payer: &T::AccountId,
+	appeal_deadline: T::BlockNumber,
+) -> Result<AuctionKey<T>, DispatchError> {
+	let amount = T::MinDeposit::get();
+	T::Escrow::lock(payer, amount)?;
+	let auction_key = AuctionId(payer.clone(), Default::default());
+	ArbitrationEscrows::<T>::insert(
+		&auction_key,
+		ArbitrationEscrow::<T> {
+			payer: payer.clone(),
+			arbitrator: funded_account::<T>("arbitrator", 0),
+			amount,
+			appeal_deadline,
+			appealed: false,
+			asset: None,
+		},
+	);
+	Ok(auction_key)
+}
+
 benchmarks! {
-	do_something {
-		let s in 0 .. 100;
+	create {
+		let s in 0 .. T::MaxDataSize::get();
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let nonce = frame_system::Pallet::<T>::account_nonce(&caller);
+	}: _(
+		RawOrigin::Signed(caller.clone()),
+		arbitrator::<T>(),
+		T::MinBounty::get(),
+		T::MinDeposit::get(),
+		now::<T>() + 1000u32.into(),
+		vec![0; s as usize],
+		Default::default()
+	)
+	verify {
+		assert!(Auctions::<T>::contains_key(AuctionId(caller, nonce)));
+	}
+
+	extend {
 		let caller: T::AccountId = whitelisted_caller();
-	}: _(RawOrigin::Signed(caller), s)
+		fund::<T>(&caller);
+		let auction_key = create_auction::<T>(&caller)?;
+		let bounty = T::MinBounty::get() * 3u32.into();
+	}: _(RawOrigin::Signed(caller), auction_key.clone(), bounty, now::<T>() + 2000u32.into())
+	verify {
+		assert_eq!(Auctions::<T>::get(&auction_key).unwrap().bounty, bounty);
+	}
+
+	set_deposit {
+		// top bidder escrows the difference along with the owner
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let auction_key = create_auction::<T>(&caller)?;
+		place_bid::<T>(&funded_account::<T>("bidder", 0), &auction_key)?;
+		let deposit = T::MinDeposit::get() * 2u32.into();
+	}: _(RawOrigin::Signed(caller), auction_key.clone(), deposit)
+	verify {
+		assert_eq!(Auctions::<T>::get(&auction_key).unwrap().deposit, deposit);
+	}
+
+	bid {
+		// outbid bidder counter-bids automatically
+		let owner = funded_account::<T>("owner", 0);
+		let auction_key = create_auction::<T>(&owner)?;
+		let outbid = funded_account::<T>("bidder", 0);
+		let price = place_bid::<T>(&outbid, &auction_key)?;
+		TaskAuction::<T>::set_auto_bid(
+			RawOrigin::Signed(outbid).into(),
+			auction_key.clone(),
+			T::MinBidPrice::get(),
+			T::MinBidPrice::get(),
+		)?;
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let price = TaskAuction::<T>::max_next_bid(&auction_key).unwrap();
+	}: _(RawOrigin::Signed(caller.clone()), auction_key.clone(), price)
+	verify {
+		assert!(BidVersions::<T>::contains_key(&auction_key, BidId(caller, 2)));
+	}
+
+	bid_firm_until {
+		let owner = funded_account::<T>("owner", 0);
+		let auction_key = create_auction::<T>(&owner)?;
+		place_bid::<T>(&funded_account::<T>("bidder", 0), &auction_key)?;
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let price = TaskAuction::<T>::max_next_bid(&auction_key).unwrap();
+	}: _(RawOrigin::Signed(caller.clone()), auction_key.clone(), price, now::<T>() + 10u32.into())
+	verify {
+		assert!(FirmUntil::<T>::contains_key(&auction_key, BidId(caller, 2)));
+	}
+
+	bid_private {
+		let owner = funded_account::<T>("owner", 0);
+		let auction_key = create_auction::<T>(&owner)?;
+		place_bid::<T>(&funded_account::<T>("bidder", 0), &auction_key)?;
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let price = TaskAuction::<T>::max_next_bid(&auction_key).unwrap();
+		let bidder: T::AccountId = account("bidder", 1, SEED);
+		let commitment = T::Hashing::hash_of(&(bidder, [0u8; 32]));
+	}: _(RawOrigin::Signed(caller.clone()), auction_key.clone(), price, commitment)
+	verify {
+		assert!(BidCommitments::<T>::contains_key(&auction_key, BidId(caller, 2)));
+	}
+
+	reveal_bidder {
+		// one-time account of the assigned bid reveals the real bidder
+		let owner = funded_account::<T>("owner", 0);
+		let auction_key = create_open_auction::<T>(&owner)?;
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let bidder: T::AccountId = account("bidder", 0, SEED);
+		let salt = [0u8; 32];
+		TaskAuction::<T>::bid_private(
+			RawOrigin::Signed(caller.clone()).into(),
+			auction_key.clone(),
+			T::MinBounty::get(),
+			T::Hashing::hash_of(&(&bidder, salt)),
+		)?;
+		TaskAuction::<T>::accept_bid(RawOrigin::Signed(owner).into(), auction_key.clone())?;
+	}: _(RawOrigin::Signed(caller.clone()), auction_key.clone(), bidder.clone(), salt)
+	verify {
+		let (_, revealed) = BidCommitments::<T>::get(&auction_key, BidId(caller, 1)).unwrap();
+		assert_eq!(revealed, Some(bidder));
+	}
+
+	// confirmation of an assigned auction, clearing the bids of k outbid bidders
+	settle {
+		let k in 0 .. T::MaxBidsPerAuction::get() - 1;
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let auction_key = create_open_auction::<T>(&caller)?;
+		for i in 0..k {
+			place_bid::<T>(&funded_account::<T>("bidder", i), &auction_key)?;
+		}
+		place_bid::<T>(&funded_account::<T>("worker", 0), &auction_key)?;
+		TaskAuction::<T>::accept_bid(
+			RawOrigin::Signed(caller.clone()).into(),
+			auction_key.clone(),
+		)?;
+	}: confirm(RawOrigin::Signed(caller), auction_key.clone())
+	verify {
+		assert!(!Auctions::<T>::contains_key(&auction_key));
+	}
+
+	dispute {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		fund::<T>(&arbitrator::<T>());
+		let auction_key = assigned_auction::<T>(&caller, &funded_account::<T>("worker", 0))?;
+	}: _(RawOrigin::Signed(caller), auction_key.clone())
+	verify {
+		assert!(Auctions::<T>::get(&auction_key).unwrap().in_dispute);
+	}
+
+	escalate {
+		// worker escalates once the owner was inactive since accepting
+		let owner = funded_account::<T>("owner", 0);
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		fund::<T>(&arbitrator::<T>());
+		let auction_key = assigned_auction::<T>(&owner, &caller)?;
+		set_block::<T>(now::<T>() + T::OwnerInactivity::get());
+	}: _(RawOrigin::Signed(caller), auction_key.clone())
+	verify {
+		assert!(Auctions::<T>::get(&auction_key).unwrap().escalated);
+	}
+
+	localize {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let auction_key = create_auction::<T>(&caller)?;
+	}: _(
+		RawOrigin::Signed(caller),
+		auction_key.clone(),
+		Location { x: 0, y: 0 },
+		T::MinBidPrice::get()
+	)
+	verify {
+		assert!(Auctions::<T>::get(&auction_key).unwrap().location.is_some());
+	}
+
+	set_settlement_mode {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let auction_key = create_auction::<T>(&caller)?;
+	}: _(RawOrigin::Signed(caller), auction_key.clone(), SettlementMode::SecondPrice)
+	verify {
+		assert_eq!(
+			Auctions::<T>::get(&auction_key).unwrap().settlement_mode,
+			SettlementMode::SecondPrice
+		);
+	}
+
+	require_joint_confirmation {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let auction_key = create_auction::<T>(&caller)?;
+	}: _(RawOrigin::Signed(caller), auction_key.clone())
+	verify {
+		assert!(Auctions::<T>::get(&auction_key).unwrap().joint_confirmation);
+	}
+
+	require_capability {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let auction_key = create_auction::<T>(&caller)?;
+	}: _(RawOrigin::Signed(caller), auction_key.clone(), 1)
+	verify {
+		assert_eq!(Auctions::<T>::get(&auction_key).unwrap().required_capability, Some(1));
+	}
+
+	set_min_bid_ratio {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let auction_key = create_auction::<T>(&caller)?;
+		MinBidRatioBounds::<T>::put((200, 255));
+	}: _(RawOrigin::Signed(caller), auction_key.clone(), 240)
+	verify {
+		assert_eq!(Auctions::<T>::get(&auction_key).unwrap().min_bid_ratio, Some(240));
+	}
+
+	set_assignment_confirmation {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let auction_key = create_auction::<T>(&caller)?;
+	}: _(RawOrigin::Signed(caller), auction_key.clone(), Some(10u32.into()))
+	verify {
+		assert!(Auctions::<T>::get(&auction_key).unwrap().assignment_confirmation_blocks.is_some());
+	}
+
+	set_min_bid_ratio_bounds {
+		let origin = T::GovernanceOrigin::successful_origin();
+	}: _<T::Origin>(origin, 200, 255)
+	verify {
+		assert_eq!(MinBidRatioBounds::<T>::get(), Some((200, 255)));
+	}
+
+	clear_circuit_breaker {
+		let origin = T::GovernanceOrigin::successful_origin();
+		CircuitBreaker::<T>::put(now::<T>());
+	}: _<T::Origin>(origin)
+	verify {
+		assert!(!CircuitBreaker::<T>::exists());
+	}
+
+	attest {
+		let origin = T::AttestationOrigin::successful_origin();
+		let worker: T::AccountId = account("worker", 0, SEED);
+	}: _<T::Origin>(origin, worker.clone(), 1, now::<T>() + 1000u32.into())
+	verify {
+		assert!(Attestations::<T>::contains_key(&worker, 1));
+	}
+
+	revoke_attestation {
+		let origin = T::AttestationOrigin::successful_origin();
+		let worker: T::AccountId = account("worker", 0, SEED);
+		Attestations::<T>::insert(&worker, 1, now::<T>() + 1000u32.into());
+	}: _<T::Origin>(origin, worker.clone(), 1)
+	verify {
+		assert!(!Attestations::<T>::contains_key(&worker, 1));
+	}
+
+	register_worker {
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller.clone()), Some(Location { x: 0, y: 0 }))
+	verify {
+		assert!(Workers::<T>::get(&caller).and_then(|profile| profile.location).is_some());
+	}
+
+	set_capacity {
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller.clone()), Some(4))
+	verify {
+		assert_eq!(Workers::<T>::get(&caller).unwrap().max_concurrent_assignments, Some(4));
+	}
+
+	force_transfer_ownership {
+		let origin = T::ForceOrigin::successful_origin();
+		let owner = funded_account::<T>("owner", 0);
+		let auction_key = create_auction::<T>(&owner)?;
+		place_bid::<T>(&funded_account::<T>("bidder", 0), &auction_key)?;
+		let new_owner = funded_account::<T>("owner", 1);
+	}: _<T::Origin>(origin, auction_key.clone(), new_owner.clone())
+	verify {
+		assert!(Auctions::<T>::contains_key(AuctionId(new_owner, auction_key.1)));
+	}
+
+	transfer_auction {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let auction_key = create_auction::<T>(&caller)?;
+		let new_owner: T::AccountId = account("owner", 1, SEED);
+	}: _(RawOrigin::Signed(caller), auction_key.clone(), new_owner.clone())
+	verify {
+		assert_eq!(TransferOffers::<T>::get(&auction_key), Some(new_owner));
+	}
+
+	accept_auction_transfer {
+		let owner = funded_account::<T>("owner", 0);
+		let auction_key = create_auction::<T>(&owner)?;
+		place_bid::<T>(&funded_account::<T>("bidder", 0), &auction_key)?;
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		TaskAuction::<T>::transfer_auction(
+			RawOrigin::Signed(owner).into(),
+			auction_key.clone(),
+			caller.clone(),
+		)?;
+	}: _(RawOrigin::Signed(caller.clone()), auction_key.clone())
+	verify {
+		assert!(Auctions::<T>::contains_key(AuctionId(caller, auction_key.1)));
+	}
+
+	stake_arbitrator {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+	}: _(RawOrigin::Signed(caller.clone()), T::MinDeposit::get())
+	verify {
+		assert_eq!(ArbitratorStakes::<T>::get(&caller), T::MinDeposit::get());
+	}
+
+	unstake_arbitrator {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		TaskAuction::<T>::stake_arbitrator(
+			RawOrigin::Signed(caller.clone()).into(),
+			T::MinDeposit::get(),
+		)?;
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert!(ArbitratorStakes::<T>::get(&caller).is_zero());
+	}
+
+	report_arbitrator {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let auction_key = arbitrated_auction::<T>(&caller, &funded_account::<T>("worker", 0))?;
+	}: _(RawOrigin::Signed(caller), auction_key.clone())
+	verify {
+		assert!(MisconductReports::<T>::contains_key(arbitrator::<T>(), &auction_key));
+	}
+
+	resolve_report {
+		// slashed stake compensates the reporter
+		let origin = T::GovernanceOrigin::successful_origin();
+		let arbitrator = funded_account::<T>("arbitrator", 0);
+		TaskAuction::<T>::stake_arbitrator(
+			RawOrigin::Signed(arbitrator.clone()).into(),
+			T::MinDeposit::get(),
+		)?;
+		let reporter = funded_account::<T>("owner", 0);
+		let auction_key = AuctionId(reporter.clone(), Default::default());
+		MisconductReports::<T>::insert(&arbitrator, &auction_key, reporter);
+	}: _<T::Origin>(origin, arbitrator.clone(), auction_key.clone(), true)
+	verify {
+		assert!(!MisconductReports::<T>::contains_key(&arbitrator, &auction_key));
+	}
+
+	attach {
+		// attachments are decoded and rewritten as a whole
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let worker = funded_account::<T>("worker", 0);
+		let auction_key = assigned_auction::<T>(&caller, &worker)?;
+		let reference = vec![0; T::MaxAttachmentSize::get() as usize];
+		for _ in 1..T::MaxAttachments::get() {
+			TaskAuction::<T>::attach(
+				RawOrigin::Signed(worker.clone()).into(),
+				auction_key.clone(),
+				reference.clone(),
+			)?;
+		}
+	}: _(RawOrigin::Signed(caller), auction_key.clone(), reference)
+	verify {
+		assert_eq!(Attachments::<T>::get(&auction_key).len() as u32, T::MaxAttachments::get());
+	}
+
+	bundle_bid {
+		let a in 1 .. T::MaxBundleSize::get();
+		let owner = funded_account::<T>("owner", 0);
+		let auction_keys =
+			(0..a).map(|_| create_auction::<T>(&owner)).collect::<Result<Vec<_>, _>>()?;
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+	}: _(RawOrigin::Signed(caller.clone()), auction_keys, T::MinBounty::get())
+	verify {
+		assert!(Bundles::<T>::contains_key(&owner, &caller));
+	}
+
+	accept_bundle {
+		// bundle displaces the top bid of each auction
+		let a in 1 .. T::MaxBundleSize::get();
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let outbid = funded_account::<T>("bidder", 0);
+		let mut auction_keys = Vec::new();
+		for _ in 0..a {
+			let auction_key = create_auction::<T>(&caller)?;
+			place_bid::<T>(&outbid, &auction_key)?;
+			auction_keys.push(auction_key);
+		}
+		let bidder = funded_account::<T>("bidder", 1);
+		TaskAuction::<T>::bundle_bid(
+			RawOrigin::Signed(bidder.clone()).into(),
+			auction_keys.clone(),
+			T::MinBounty::get(),
+		)?;
+	}: _(RawOrigin::Signed(caller.clone()), bidder.clone())
+	verify {
+		assert!(!Bundles::<T>::contains_key(&caller, &bidder));
+		assert!(auction_keys.iter().all(|key| Auctions::<T>::get(key).unwrap().accepted));
+	}
+
+	withdraw_bundle {
+		let owner = funded_account::<T>("owner", 0);
+		let auction_key = create_auction::<T>(&owner)?;
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		TaskAuction::<T>::bundle_bid(
+			RawOrigin::Signed(caller.clone()).into(),
+			vec![auction_key],
+			T::MinBounty::get(),
+		)?;
+	}: _(RawOrigin::Signed(caller.clone()), owner.clone())
+	verify {
+		assert!(!Bundles::<T>::contains_key(&owner, &caller));
+	}
+
+	pause_bidding {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let auction_key = create_auction::<T>(&caller)?;
+		place_bid::<T>(&funded_account::<T>("bidder", 0), &auction_key)?;
+	}: _(RawOrigin::Signed(caller), auction_key.clone())
+	verify {
+		assert!(Auctions::<T>::get(&auction_key).unwrap().paused_at.is_some());
+	}
+
+	resume_bidding {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let auction_key = create_auction::<T>(&caller)?;
+		TaskAuction::<T>::pause_bidding(
+			RawOrigin::Signed(caller.clone()).into(),
+			auction_key.clone(),
+		)?;
+		set_block::<T>(now::<T>() + 10u32.into());
+	}: _(RawOrigin::Signed(caller), auction_key.clone())
+	verify {
+		assert!(Auctions::<T>::get(&auction_key).unwrap().paused_at.is_none());
+	}
+
+	claim {
+		// queued payouts stay in escrow of the payer until claimed
+		let payer = funded_account::<T>("owner", 0);
+		let caller: T::AccountId = whitelisted_caller();
+		let amount = T::MinBounty::get();
+		T::Escrow::lock(&payer, amount)?;
+		PendingPayouts::<T>::insert(&caller, &payer, amount);
+	}: _(RawOrigin::Signed(caller.clone()), payer.clone())
+	verify {
+		assert!(!PendingPayouts::<T>::contains_key(&caller, &payer));
+	}
+
+	rate {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let worker = funded_account::<T>("worker", 0);
+		let auction_key = assigned_auction::<T>(&caller, &worker)?;
+		TaskAuction::<T>::confirm(RawOrigin::Signed(caller.clone()).into(), auction_key.clone())?;
+	}: _(RawOrigin::Signed(caller), auction_key, 5)
+	verify {
+		assert_eq!(Ratings::<T>::get(&worker).count, 1);
+	}
+
+	appeal {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let auction_key = escrow_arbitration_fee::<T>(&caller, now::<T>())?;
+	}: _(RawOrigin::Signed(caller), auction_key.clone())
+	verify {
+		assert!(ArbitrationEscrows::<T>::get(&auction_key).unwrap().appealed);
+	}
+
+	resolve_appeal {
+		// upheld rulings pay the arbitrator
+		let origin = T::GovernanceOrigin::successful_origin();
+		let payer = funded_account::<T>("owner", 0);
+		let auction_key = escrow_arbitration_fee::<T>(&payer, now::<T>())?;
+		TaskAuction::<T>::appeal(RawOrigin::Signed(payer).into(), auction_key.clone())?;
+	}: _<T::Origin>(origin, auction_key.clone(), false)
+	verify {
+		assert!(!ArbitrationEscrows::<T>::contains_key(&auction_key));
+	}
+
+	release_arbitration_fee {
+		let payer = funded_account::<T>("owner", 0);
+		let auction_key = escrow_arbitration_fee::<T>(&payer, now::<T>())?;
+		set_block::<T>(now::<T>() + 1u32.into());
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller), auction_key.clone())
+	verify {
+		assert!(!ArbitrationEscrows::<T>::contains_key(&auction_key));
+	}
+
+	publish_template {
+		let n in 0 .. T::MaxTemplateNameLength::get();
+		let d in 0 .. T::MaxDataSize::get();
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+	}: _(
+		RawOrigin::Signed(caller),
+		vec![0; n as usize],
+		T::MinBounty::get(),
+		T::MinDeposit::get(),
+		1000u32.into(),
+		vec![0; d as usize]
+	)
+	verify {
+		assert_eq!(TemplateCount::<T>::get(), 1);
+	}
+
+	fork_template {
+		let n in 0 .. T::MaxTemplateNameLength::get();
+		let author = funded_account::<T>("author", 0);
+		TaskAuction::<T>::publish_template(
+			RawOrigin::Signed(author).into(),
+			Vec::new(),
+			T::MinBounty::get(),
+			T::MinDeposit::get(),
+			1000u32.into(),
+			vec![0; T::MaxDataSize::get() as usize],
+		)?;
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+	}: _(RawOrigin::Signed(caller), 0, vec![0; n as usize], Default::default())
+	verify {
+		assert_eq!(TemplateCount::<T>::get(), 2);
+	}
+
+	unpublish_template {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		TaskAuction::<T>::publish_template(
+			RawOrigin::Signed(caller.clone()).into(),
+			Vec::new(),
+			T::MinBounty::get(),
+			T::MinDeposit::get(),
+			1000u32.into(),
+			Vec::new(),
+		)?;
+	}: _(RawOrigin::Signed(caller), 0)
+	verify {
+		assert_eq!(TemplateCount::<T>::get(), 0);
+	}
+
+	propose_release {
+		let owner = funded_account::<T>("owner", 0);
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let auction_key = assigned_auction::<T>(&owner, &caller)?;
+	}: _(RawOrigin::Signed(caller), auction_key.clone(), T::MinDeposit::get())
+	verify {
+		assert!(ReleaseProposals::<T>::contains_key(&auction_key));
+	}
+
+	set_completion_deadline {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let auction_key = create_auction::<T>(&caller)?;
+		let deadline = now::<T>() + 2000u32.into();
+	}: _(RawOrigin::Signed(caller), auction_key.clone(), deadline)
+	verify {
+		assert_eq!(Auctions::<T>::get(&auction_key).unwrap().completion_deadline, Some(deadline));
+	}
+
+	propose_extension {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let auction_key = assigned_auction::<T>(&caller, &funded_account::<T>("worker", 0))?;
+	}: _(RawOrigin::Signed(caller), auction_key.clone(), now::<T>() + 1000u32.into())
+	verify {
+		assert!(ExtensionProposals::<T>::contains_key(&auction_key));
+	}
+
+	accept_extension {
+		// worker accepts the deadline the owner proposed
+		let owner = funded_account::<T>("owner", 0);
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let auction_key = assigned_auction::<T>(&owner, &caller)?;
+		let deadline = now::<T>() + 1000u32.into();
+		TaskAuction::<T>::propose_extension(
+			RawOrigin::Signed(owner).into(),
+			auction_key.clone(),
+			deadline,
+		)?;
+	}: _(RawOrigin::Signed(caller), auction_key.clone())
+	verify {
+		assert_eq!(Auctions::<T>::get(&auction_key).unwrap().completion_deadline, Some(deadline));
+	}
+
+	promote {
+		// full list whose lowest promotion is evicted
+		let max_promoted = T::MaxPromoted::get();
+		let owner = funded_account::<T>("owner", 0);
+		for i in 0..max_promoted {
+			let auction_key = create_auction::<T>(&owner)?;
+			let stake = T::MinBounty::get() * (max_promoted - i).into();
+			TaskAuction::<T>::promote(RawOrigin::Signed(owner.clone()).into(), auction_key, stake)?;
+		}
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let auction_key = create_auction::<T>(&caller)?;
+		let stake = T::MinBounty::get() * (max_promoted + 1).into();
+	}: _(RawOrigin::Signed(caller), auction_key.clone(), stake)
+	verify {
+		assert_eq!(PromotedAuctions::<T>::get()[0], (auction_key, stake));
+	}
+
+	set_vesting_period {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let auction_key = create_auction::<T>(&caller)?;
+	}: _(RawOrigin::Signed(caller), auction_key.clone(), Some(10u32.into()))
+	verify {
+		assert!(Auctions::<T>::get(&auction_key).unwrap().vesting_period.is_some());
+	}
+
+	accept_bid {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let auction_key = create_open_auction::<T>(&caller)?;
+		place_bid::<T>(&funded_account::<T>("worker", 0), &auction_key)?;
+	}: _(RawOrigin::Signed(caller), auction_key.clone())
+	verify {
+		assert!(Auctions::<T>::get(&auction_key).unwrap().accepted);
+	}
+
+	set_dispute_sla {
+		let origin = T::GovernanceOrigin::successful_origin();
+	}: _<T::Origin>(origin, Some(1), Some(10u32.into()))
+	verify {
+		assert!(DisputeSlas::<T>::contains_key(Some(1)));
+	}
+
+	set_arbitrator_fee {
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller.clone()), T::MinDeposit::get())
+	verify {
+		assert_eq!(ArbitratorFees::<T>::get(&caller), T::MinDeposit::get());
+	}
+
+	flag_collusion {
+		// arbitrator of the auction flags it, after the force origin is ruled out
+		let owner = funded_account::<T>("owner", 0);
+		let auction_key = create_auction::<T>(&owner)?;
+	}: _(RawOrigin::Signed(arbitrator::<T>()), auction_key.clone())
+	verify {
+		assert!(CollusionReviews::<T>::contains_key(&auction_key));
+	}
+
+	boost_bounty {
+		let owner = funded_account::<T>("owner", 0);
+		let auction_key = create_auction::<T>(&owner)?;
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+	}: _(RawOrigin::Signed(caller.clone()), auction_key.clone(), T::MinBounty::get())
+	verify {
+		assert_eq!(Boosts::<T>::get(&auction_key, &caller), T::MinBounty::get());
+	}
+
+	queue_retract {
+		let owner = funded_account::<T>("owner", 0);
+		let auction_key = create_auction::<T>(&owner)?;
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		place_bid::<T>(&caller, &auction_key)?;
+	}: _(RawOrigin::Signed(caller.clone()), auction_key.clone(), None)
+	verify {
+		assert!(QueuedRetractions::<T>::contains_key(&auction_key, &caller));
+	}
+
+	set_bid_batching {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let auction_key = create_auction::<T>(&caller)?;
+	}: _(RawOrigin::Signed(caller), auction_key.clone(), true)
+	verify {
+		assert!(Auctions::<T>::get(&auction_key).unwrap().batched_bids);
+	}
+
+	request_settlement_receipt {
+		let origin = T::RemoteOrigin::successful_origin();
+		let (owner, _) = T::RemoteOrigin::try_origin(origin.clone())
+			.map_err(|_| DispatchError::Other("remote origin without an owner"))?;
+		fund::<T>(&owner);
+		let auction_key = create_auction::<T>(&owner)?;
+	}: _<T::Origin>(origin, auction_key.clone())
+	verify {
+		assert!(ReceiptDestinations::<T>::contains_key(&auction_key));
+	}
+
+	bid_with_deliverable {
+		let owner = funded_account::<T>("owner", 0);
+		let nonce = frame_system::Pallet::<T>::account_nonce(&owner);
+		TaskAuction::<T>::create(
+			RawOrigin::Signed(owner.clone()).into(),
+			arbitrator::<T>(),
+			T::MinBounty::get(),
+			T::MinDeposit::get(),
+			now::<T>() + 1000u32.into(),
+			Vec::new(),
+			CreateOptions {
+				requirements: Some(TaskRequirements {
+					deadline: now::<T>() + 2000u32.into(),
+					deliverable: Deliverable::Document,
+					quantity: 1,
+					capability: None,
+				}),
+				..Default::default()
+			},
+		)?;
+		let auction_key = AuctionId(owner, nonce);
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+	}: _(
+		RawOrigin::Signed(caller.clone()),
+		auction_key.clone(),
+		T::MinBounty::get(),
+		Deliverable::Document
+	)
+	verify {
+		assert!(BidVersions::<T>::contains_key(&auction_key, BidId(caller, 1)));
+	}
+
+	set_savings_share {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let auction_key = create_auction::<T>(&caller)?;
+	}: _(RawOrigin::Signed(caller), auction_key.clone(), Permill::from_percent(10))
+	verify {
+		assert_eq!(
+			Auctions::<T>::get(&auction_key).unwrap().savings_share,
+			Permill::from_percent(10)
+		);
+	}
+
+	set_auto_bid {
+		let owner = funded_account::<T>("owner", 0);
+		let auction_key = create_auction::<T>(&owner)?;
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(
+		RawOrigin::Signed(caller.clone()),
+		auction_key.clone(),
+		T::MinBidPrice::get(),
+		T::MinBidPrice::get()
+	)
+	verify {
+		assert!(AutoBids::<T>::contains_key(&auction_key, &caller));
+	}
+
+	set_operator {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let auction_key = create_auction::<T>(&caller)?;
+		let operator: T::AccountId = account("operator", 0, SEED);
+	}: _(RawOrigin::Signed(caller.clone()), Some(auction_key.clone()), operator.clone(), 1)
+	verify {
+		assert!(Operators::<T>::contains_key(&caller, (operator, Some(auction_key))));
+	}
+
+	top_up {
+		// top bid promoted while the bidder was short of the deposit
+		let owner = funded_account::<T>("owner", 0);
+		let auction_key = create_auction::<T>(&owner)?;
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		place_bid::<T>(&caller, &auction_key)?;
+		T::Escrow::unlock(&caller, T::MinDeposit::get());
+		T::DepositReceipts::burn(&caller, T::MinDeposit::get())?;
+		ActiveAssignments::<T>::remove(&caller);
+		Auctions::<T>::mutate(&auction_key, |auction| {
+			if let Some(auction) = auction {
+				auction.top_up_deadline = Some(now::<T>() + 10u32.into());
+			}
+		});
+	}: _(RawOrigin::Signed(caller), auction_key.clone())
+	verify {
+		assert!(Auctions::<T>::get(&auction_key).unwrap().top_funded());
+	}
+
+	create_project {
+		let n in 0 .. T::MaxProjectNameLength::get();
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+	}: _(
+		RawOrigin::Signed(caller),
+		vec![0; n as usize],
+		account("arbitrator", 0, SEED),
+		T::MinDeposit::get(),
+		1000u32.into()
+	)
+	verify {
+		assert_eq!(ProjectCount::<T>::get(), 1);
+	}
+
+	submit_items {
+		let owner = funded_account::<T>("owner", 0);
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let auction_key = assigned_auction::<T>(&owner, &caller)?;
+	}: _(RawOrigin::Signed(caller), auction_key.clone(), 10)
+	verify {
+		assert_eq!(DeliveredItems::<T>::get(&auction_key), Some((10, 0)));
+	}
+
+	rescale_open_auctions {
+		let origin = T::GovernanceOrigin::successful_origin();
+	}: _<T::Origin>(origin, FixedU128::saturating_from_integer(2))
 	verify {
-		assert_eq!(Something::<T>::get(), Some(s));
+		assert!(PendingRescale::<T>::get().is_some());
 	}
 
 	impl_benchmark_test_suite!(TaskAuction, crate::mock::new_test_ext(), crate::mock::Test);
@@ -1,8 +1,22 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub use pallet::*;
+pub use weights::WeightInfo;
+
+use codec::{Decode, Encode};
+use core::{fmt, marker::PhantomData};
+use frame_support::{
+	sp_runtime::{
+		traits::{DispatchInfoOf, PostDispatchInfoOf, SignedExtension},
+		transaction_validity::{TransactionValidity, TransactionValidityError, ValidTransaction},
+		DispatchResult,
+	},
+	traits::IsSubType,
+};
+use scale_info::TypeInfo;
 
 #[cfg(test)]
+#[macro_use]
 mod mock;
 
 #[cfg(test)]
@@ -11,35 +25,282 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod weights;
+
+#[cfg(feature = "std")]
+pub mod replay;
+
+#[cfg(feature = "std")]
+pub mod storage_report;
+
 #[frame_support::pallet]
 pub mod pallet {
+	use crate::weights::WeightInfo;
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
 
+	use pallet_task_auction_runtime_api::{
+		AccountStatement, ArbitratorScorecard, AssetDisplay, AuctionStatus, CreateEstimate,
+		FundsMovement, HealthSnapshot,
+	};
+
 	use frame_support::{
 		inherent::Vec,
-		sp_runtime::SaturatedConversion,
-		traits::{Currency, ExistenceRequirement, ReservableCurrency},
+		sp_io::KillStorageResult,
+		sp_runtime::{
+			helpers_128bit,
+			traits::{
+				AccountIdConversion, BlockNumberProvider, Bounded, CheckedAdd, CheckedDiv,
+				CheckedMul, CheckedSub, Hash, IdentifyAccount, One, Saturating, Verify, Zero,
+			},
+			ArithmeticError, FixedPointNumber, FixedU128, Perbill, Permill, SaturatedConversion,
+		},
+		sp_std::collections::btree_set::BTreeSet,
+		storage::{
+			self,
+			child::{self, ChildInfo},
+		},
+		traits::{
+			fungibles, BalanceStatus, Contains, Currency, ExistenceRequirement, OnUnbalanced,
+			ReservableCurrency, UnixTime, VestingSchedule, WithdrawReasons,
+		},
+		transactional, PalletId,
 	};
 
 	type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 	type BalanceOf<T> = <<T as Config>::Currency as Currency<AccountIdOf<T>>>::Balance;
-	type Key<T> = (AccountIdOf<T>, <T as frame_system::Config>::Index);
+	type NegativeImbalanceOf<T> =
+		<<T as Config>::Currency as Currency<AccountIdOf<T>>>::NegativeImbalance;
+	type AuctionKey<T> = AuctionId<AccountIdOf<T>, <T as frame_system::Config>::Index>;
+	type SignedQuoteOf<T> = SignedQuote<
+		AccountIdOf<T>,
+		BalanceOf<T>,
+		<T as frame_system::Config>::BlockNumber,
+		<T as Config>::QuoteSignature,
+	>;
+	type BidKey<T> = BidId<AccountIdOf<T>>;
+	type CreateOptionsOf<T> = CreateOptions<
+		<T as Config>::AssetId,
+		<T as Config>::QuoteAsset,
+		BalanceOf<T>,
+		<T as frame_system::Config>::BlockNumber,
+		SignedQuoteOf<T>,
+	>;
+	type DeadlineQueue<T> = BoundedVec<AuctionKey<T>, <T as Config>::MaxDeadlinesPerBlock>;
+
+	// Number of maps keyed by bid, and by bidder, that are cleared with the bids of an auction,
+	// each holding at most one entry per bid of the stack.
+	const CLEARED_BID_MAPS: usize = 4;
+	const CLEARED_BIDDER_MAPS: usize = 4;
+
+	// Calls of the owner an operator may be permitted, combined as a bitmask.
+	pub const OPERATOR_EXTEND: u8 = 1 << 0;
+	pub const OPERATOR_CONFIRM: u8 = 1 << 1;
+	pub const OPERATOR_CANCEL: u8 = 1 << 2;
 
 	// Configure the pallet by specifying the parameters and types on which it depends.
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
-		type Currency: ReservableCurrency<Self::AccountId>;
+		// currency of listing fees and bounties, whose funds are held by the escrow handler
+		type Currency: Currency<Self::AccountId>;
 
 		#[pallet::constant]
 		type MinBounty: Get<BalanceOf<Self>>;
 		#[pallet::constant]
+		type MaxBounty: Get<BalanceOf<Self>>;
+		#[pallet::constant]
 		type MinDeposit: Get<BalanceOf<Self>>;
 		#[pallet::constant]
 		type MinBidRatio: Get<u8>;
 		#[pallet::constant]
+		type MinBidPrice: Get<BalanceOf<Self>>;
+		#[pallet::constant]
 		type MaxDataSize: Get<u32>;
+		#[pallet::constant]
+		type ListingFeeBase: Get<BalanceOf<Self>>;
+		#[pallet::constant]
+		type ListingFeePerByte: Get<BalanceOf<Self>>;
+		#[pallet::constant]
+		type ListingRefundPeriod: Get<Self::BlockNumber>;
+		#[pallet::constant]
+		type RetractCooldown: Get<Self::BlockNumber>;
+		#[pallet::constant]
+		type OwnerInactivity: Get<Self::BlockNumber>;
+		#[pallet::constant]
+		type MaxAttachments: Get<u32>;
+		#[pallet::constant]
+		type MaxAttachmentSize: Get<u32>;
+		#[pallet::constant]
+		type AttachmentDepositPerByte: Get<BalanceOf<Self>>;
+		#[pallet::constant]
+		type MaxBundleSize: Get<u32>;
+		#[pallet::constant]
+		type DeferPayouts: Get<bool>;
+		#[pallet::constant]
+		type MaxBidsPerAuction: Get<u32>;
+		#[pallet::constant]
+		type MaxLocationLocks: Get<u32>;
+		#[pallet::constant]
+		type RatingPeriod: Get<Self::BlockNumber>;
+		#[pallet::constant]
+		type AppealPeriod: Get<Self::BlockNumber>;
+		#[pallet::constant]
+		type MaxTemplates: Get<u32>;
+		#[pallet::constant]
+		type MaxTemplateNameLength: Get<u32>;
+		#[pallet::constant]
+		type TemplateDeposit: Get<BalanceOf<Self>>;
+		// projects grouping auctions of an owner
+		#[pallet::constant]
+		type MaxProjects: Get<u32>;
+		#[pallet::constant]
+		type MaxProjectNameLength: Get<u32>;
+		#[pallet::constant]
+		type MaxAuctionsPerProject: Get<u32>;
+		#[pallet::constant]
+		type ProjectDeposit: Get<BalanceOf<Self>>;
+		#[pallet::constant]
+		type MaxMilestones: Get<u32>;
+		#[pallet::constant]
+		type ExpectedBlockTime: Get<u64>;
+		// blocks after creation during which cancel refunds everyone in full
+		#[pallet::constant]
+		type CreationGracePeriod: Get<Self::BlockNumber>;
+		// reject bids beyond capacity when placed, otherwise only when assigned immediately
+		#[pallet::constant]
+		type CheckCapacityAtBid: Get<bool>;
+		// emit a full snapshot of every auction at settlement, for indexers that can't query
+		// historical state
+		#[pallet::constant]
+		type EmitSettlementSnapshots: Get<bool>;
+		#[pallet::constant]
+		type MaxOpenDisputesPerArbitrator: Get<u32>;
+		#[pallet::constant]
+		type MaxDeadlinesPerBlock: Get<u32>;
+		#[pallet::constant]
+		type MaxPromoted: Get<u32>;
+		// blocks without a new top bid after which an open-ended auction can be expired
+		#[pallet::constant]
+		type OpenAuctionTimeout: Get<Self::BlockNumber>;
+		// blocks after assignment within which the worker must acknowledge or attach progress
+		#[pallet::constant]
+		type AbandonmentWindow: Get<Self::BlockNumber>;
+		// part of the deposit a worker who abandoned an assignment pays to the owner
+		#[pallet::constant]
+		type AbandonmentPenalty: Get<Perbill>;
+		// blocks confirmation stays frozen while an auction flagged for collusion is reviewed
+		#[pallet::constant]
+		type CollusionReviewPeriod: Get<Self::BlockNumber>;
+		// contributors to the bounty of an auction, bounding refunds at settlement
+		#[pallet::constant]
+		type MaxBoostersPerAuction: Get<u32>;
+		// share of the deposit paid by an owner who cancels with a penalty to each outbid
+		// bidder still in the stack, capped per bidder and in the number of bidders
+		#[pallet::constant]
+		type CancelConsolation: Get<Perbill>;
+		#[pallet::constant]
+		type MaxConsolation: Get<BalanceOf<Self>>;
+		#[pallet::constant]
+		type MaxConsoledBidders: Get<u32>;
+		// blocks the arbitrator has to rule on a dispute before either party can settle it by
+		// default judgment, never when zero
+		#[pallet::constant]
+		type ArbitrationPeriod: Get<Self::BlockNumber>;
+		// share of the price a default judgment pays the worker, the rest stays with the owner
+		#[pallet::constant]
+		type DefaultJudgmentShare: Get<Perbill>;
+		// blocks over which disputes are counted against settlements, the circuit breaker
+		// suspending creation of auctions never trips when zero
+		#[pallet::constant]
+		type DisputeRateWindow: Get<Self::BlockNumber>;
+		// share of settlements that disputes of the current and previous window may reach
+		// before the circuit breaker trips, once there are at least the minimum of them
+		#[pallet::constant]
+		type MaxDisputeRate: Get<Perbill>;
+		#[pallet::constant]
+		type MinDisputeSample: Get<u32>;
+		// account holding the escrow of disputed auctions out of reach of slashing
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+		// blocks the tombstone of an arbitrated auction is kept before being pruned
+		#[pallet::constant]
+		type DisputeRetention: Get<Self::BlockNumber>;
+		// decimals of the currency, reported with amounts of runtime API responses
+		#[pallet::constant]
+		type Decimals: Get<u8>;
+		// length of the reporting periods of account totals
+		#[pallet::constant]
+		type StatsPeriod: Get<Self::BlockNumber>;
+		// blocks a bidder short of the deposit has to reserve it once a retraction promotes
+		// their bid to the top, such bids are skipped when zero
+		#[pallet::constant]
+		type TopUpPeriod: Get<Self::BlockNumber>;
+
+		type ListingFeeDestination: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		type PriceModel: PriceModel<Self>;
+
+		type DecrementSchedule: DecrementSchedule<Self>;
+
+		type Escrow: EscrowHandler<Self::AccountId, BalanceOf<Self>>;
+
+		type Telemetry: Telemetry<BalanceOf<Self>>;
+
+		type DepositReceipts: DepositReceipts<Self::AccountId, BalanceOf<Self>>;
+
+		type UnixTime: UnixTime;
+
+		// clock of base prices, deadlines and periods, such as the relay chain block number on
+		// parachains with asynchronous backing
+		type BlockNumberProvider: BlockNumberProvider<BlockNumber = Self::BlockNumber>;
+
+		// accounts that may be named as arbitrator of an auction
+		type ArbitratorSet: Contains<Self::AccountId>;
+
+		// locks payouts of auctions with a vesting period
+		type Vesting: VestingSchedule<
+			Self::AccountId,
+			Moment = Self::BlockNumber,
+			Currency = Self::Currency,
+		>;
+
+		type ForceOrigin: EnsureOrigin<Self::Origin>;
+		type GovernanceOrigin: EnsureOrigin<Self::Origin>;
+		type ArbitrationOrigin: EnsureOrigin<Self::Origin>;
+		type AttestationOrigin: EnsureOrigin<Self::Origin>;
+
+		// signature of a worker over a quote redeemed on-chain by the owner
+		type QuoteSignature: Verify<Signer = Self::QuoteSigner> + Parameter;
+		type QuoteSigner: IdentifyAccount<AccountId = Self::AccountId>;
+
+		// accounts acting for the owner or arbitrator, which may not bid on the auction either
+		type AccountLinks: AccountLinks<Self::AccountId>;
+
+		// location of another chain that owners may create auctions from, such as a
+		// MultiLocation of a sibling parachain
+		type ReceiptDestination: Parameter + MaxEncodedLen;
+		// origin of calls arriving over XCM, resolving to the local account of the remote owner
+		// and the location of its chain
+		type RemoteOrigin: EnsureOrigin<
+			Self::Origin,
+			Success = (Self::AccountId, Self::ReceiptDestination),
+		>;
+		// notifies the chain of remote owners of the settlement of their auctions
+		type ReceiptSender: ReceiptSender<Self::ReceiptDestination, AuctionKey<Self>, Receipt<Self>>;
+
+		// asset that bids of an auction may be quoted in, such as a stablecoin
+		type QuoteAsset: Parameter + MaxEncodedLen;
+		// rate of quote assets in the currency of the pallet, such as an oracle feed or an AMM
+		type PriceOracle: PriceOracle<Self::QuoteAsset>;
+
+		// asset that bounties and deposits of an auction may be denominated in, such as an
+		// asset of the assets pallet
+		type AssetId: Parameter + MaxEncodedLen;
+		// escrow of auctions denominated in an asset, keyed by the asset
+		type AssetEscrow: AssetEscrowHandler<Self::AccountId, Self::AssetId, BalanceOf<Self>>;
+		// weights of the calls, measured by the benchmarks of the pallet
+		type WeightInfo: WeightInfo;
 	}
 
 	// Errors inform users that something went wrong.
@@ -48,17 +309,112 @@ pub mod pallet {
 		AuctionKeyNotFound,
 		AuctionAssigned,
 		AuctionNotAssigned,
+		UnexpectedAuctionState,
 		AuctionDisputed,
 		AuctionNotDisputed,
+		AuctionPaused,
+		AuctionNotPaused,
+		OwnerInactivityRequired,
 
 		MinBountyRequired,
+		BountyIncrementRequired,
 		MinDepositRequired,
 		MinBidRatioRequired,
+		BidImprovementRequired,
+		BidPriceTied,
+		BidTooLow,
+		RetractCooldownActive,
+		BidNotExpired,
 		MaxDataSizeExceeded,
+		MaxAttachmentsExceeded,
+		MaxBundleSizeExceeded,
+		DuplicateBundleAuction,
+		BundleNotFound,
+		BundleExists,
+		BundlePriceExceedsBounty,
+		BundleDepositChanged,
 
 		TopBidRequired,
 		OwnerRequired,
 		OriginProhibited,
+		SelfArbitrationProhibited,
+		AttestationRequired,
+		PayoutNotFound,
+		TransferOfferRequired,
+		MaxBidsReached,
+		ReceiptNotFound,
+		RatingOutOfRange,
+		RatingPeriodExpired,
+		AlreadyRated,
+		ArbitrationEscrowNotFound,
+		AppealPeriodActive,
+		AppealPeriodExpired,
+		AppealExists,
+		AppealRequired,
+		TemplateNotFound,
+		MaxTemplatesReached,
+		MaxTemplateNameLengthExceeded,
+		ProjectNotFound,
+		MaxProjectsReached,
+		MaxProjectNameLengthExceeded,
+		MaxAuctionsPerProjectReached,
+		ProjectCancelled,
+		MinBidRatioOutOfBounds,
+		CapacityExceeded,
+		ReleaseProposalNotFound,
+		AbandonmentWindowActive,
+		AuctionUnderReview,
+		ReviewExists,
+		ReviewNotFound,
+		PenaltyExceedsDeposit,
+		ArbitratorNotApproved,
+		CommitmentNotFound,
+		CommitmentMismatch,
+		RevealRequired,
+		ArbitratorOverloaded,
+		InvalidCompletionDeadline,
+		DeadlineQueueFull,
+		InvalidVestingPeriod,
+		ExtensionProposalNotFound,
+		OpenEndedRequired,
+		BidExceedsBounty,
+		OpenAuctionActive,
+		OpenEndedProhibited,
+		AlreadyPromoted,
+		PromotionStakeTooLow,
+		AuctionHasBids,
+		WorkerLocationLocked,
+		TooManyLocationLocks,
+		AuctionKeyExists,
+		ArbitrationRequired,
+		ArbitratorReported,
+		ReportExists,
+		DisputeQuoteRequired,
+		MaxBoostersReached,
+		QuoteExpired,
+		InvalidQuoteSignature,
+		QuoteAlreadyRedeemed,
+		BidBatchingEnabled,
+		InvalidRequirements,
+		DeliverableMismatch,
+		MaxBountyExceeded,
+		UnknownQuoteAsset,
+		TopUpRequired,
+		TopUpNotRequired,
+		RescaleInProgress,
+		InvalidRescaleFactor,
+		NativeAuctionRequired,
+		ArbitrationPeriodActive,
+		FutureTerminalBlockRequired,
+		InvalidMilestones,
+		MilestoneNotFound,
+		MilestoneConfirmed,
+		MilestonesDeclared,
+		ItemsNotSubmitted,
+		ItemsInProgress,
+		ItemCountExceeded,
+		CreationSuspended,
+		CircuitBreakerNotTripped,
 	}
 
 	// Pallets use events to inform users when important changes are made.
@@ -66,30 +422,909 @@ pub mod pallet {
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
-		Created { auction_key: Key<T>, bounty: BalanceOf<T>, terminal_block: T::BlockNumber },
-		Extended { auction_key: Key<T>, bounty: BalanceOf<T>, terminal_block: T::BlockNumber },
+		Created {
+			auction_key: AuctionKey<T>,
+			bounty: BalanceOf<T>,
+			terminal_block: T::BlockNumber,
+		},
+		Extended {
+			auction_key: AuctionKey<T>,
+			bounty: BalanceOf<T>,
+			terminal_block: T::BlockNumber,
+		},
+		// auction extended after its terminal block, restarting the price curve
+		Relisted {
+			auction_key: AuctionKey<T>,
+			bounty: BalanceOf<T>,
+			initial_block: T::BlockNumber,
+			terminal_block: T::BlockNumber,
+		},
+		DepositSet {
+			auction_key: AuctionKey<T>,
+			deposit: BalanceOf<T>,
+		},
+		Paused {
+			auction_key: AuctionKey<T>,
+		},
+		Resumed {
+			auction_key: AuctionKey<T>,
+			terminal_block: T::BlockNumber,
+		},
+
+		Bid {
+			auction_key: AuctionKey<T>,
+			bid_key: BidKey<T>,
+			price: BalanceOf<T>,
+			version: u32,
+			// highest price the next bid may have, so clients don't derive it from stale state
+			max_next_bid: Option<BalanceOf<T>>,
+		},
+		BidImproved {
+			auction_key: AuctionKey<T>,
+			bid_key: BidKey<T>,
+			price: BalanceOf<T>,
+			version: u32,
+			max_next_bid: Option<BalanceOf<T>>,
+		},
+		Retracted {
+			auction_key: AuctionKey<T>,
+			bid_key: BidKey<T>,
+			price: BalanceOf<T>,
+		},
+		ExpiredBidWithdrawn {
+			auction_key: AuctionKey<T>,
+			bid_key: BidKey<T>,
+			price: BalanceOf<T>,
+		},
+		BidderRevealed {
+			auction_key: AuctionKey<T>,
+			bid_key: BidKey<T>,
+			bidder: T::AccountId,
+		},
+
+		Confirmed {
+			auction_key: AuctionKey<T>,
+			vesting_period: Option<T::BlockNumber>,
+			price: BalanceOf<T>,
+			// part of the price paid out of the savings below the bounty
+			savings: BalanceOf<T>,
+			// rate the price was converted at, for auctions quoted in another asset
+			rate: Option<FixedU128>,
+		},
+		OwnerConfirmed {
+			auction_key: AuctionKey<T>,
+		},
+		WorkerAcknowledged {
+			auction_key: AuctionKey<T>,
+		},
+		JointConfirmationRequired {
+			auction_key: AuctionKey<T>,
+		},
+		Cancelled {
+			auction_key: AuctionKey<T>,
+		},
+
+		Disputed {
+			auction_key: AuctionKey<T>,
+		},
+		Escalated {
+			auction_key: AuctionKey<T>,
+		},
+		Arbitrated {
+			auction_key: AuctionKey<T>,
+			fulfilled: bool,
+			verdict_hash: Option<T::Hash>,
+		},
+		FallbackArbitrationRequired {
+			auction_key: AuctionKey<T>,
+		},
+
+		Localized {
+			auction_key: AuctionKey<T>,
+			location: Location,
+		},
+		WorkerRegistered {
+			worker: T::AccountId,
+			location: Option<Location>,
+		},
+		CapacitySet {
+			worker: T::AccountId,
+			max_concurrent_assignments: Option<u32>,
+		},
+		Attested {
+			worker: T::AccountId,
+			capability: Capability,
+			expiry: T::BlockNumber,
+		},
+		AttestationRevoked {
+			worker: T::AccountId,
+			capability: Capability,
+		},
+		CapabilityRequired {
+			auction_key: AuctionKey<T>,
+			capability: Capability,
+		},
+		MinBidRatioSet {
+			auction_key: AuctionKey<T>,
+			min_bid_ratio: u8,
+		},
+		AssignmentConfirmationSet {
+			auction_key: AuctionKey<T>,
+			blocks: Option<T::BlockNumber>,
+		},
+		CompletionDeadlineSet {
+			auction_key: AuctionKey<T>,
+			deadline: T::BlockNumber,
+		},
+		ExtensionProposed {
+			auction_key: AuctionKey<T>,
+			proposer: T::AccountId,
+			deadline: T::BlockNumber,
+		},
+		ExtensionAgreed {
+			auction_key: AuctionKey<T>,
+			deadline: T::BlockNumber,
+		},
+		VestingPeriodSet {
+			auction_key: AuctionKey<T>,
+			vesting_period: Option<T::BlockNumber>,
+		},
+		VestingFailed {
+			auction_key: AuctionKey<T>,
+			worker: T::AccountId,
+		},
+		ReceiptBurnFailed {
+			auction_key: AuctionKey<T>,
+			worker: T::AccountId,
+		},
+		BidAccepted {
+			auction_key: AuctionKey<T>,
+			bid_key: BidKey<T>,
+			price: BalanceOf<T>,
+		},
+		OpenAuctionExpired {
+			auction_key: AuctionKey<T>,
+		},
+		DisputeSlaSet {
+			category: Option<Capability>,
+			sla: Option<T::BlockNumber>,
+		},
+		ArbitratorFeeSet {
+			arbitrator: T::AccountId,
+			fee: BalanceOf<T>,
+		},
+		DisputeFeeQuoted {
+			auction_key: AuctionKey<T>,
+			payer: T::AccountId,
+			fee: BalanceOf<T>,
+		},
+		CompletionDeadlineMissed {
+			auction_key: AuctionKey<T>,
+		},
+		Promoted {
+			auction_key: AuctionKey<T>,
+			stake: BalanceOf<T>,
+		},
+		PromotionEnded {
+			auction_key: AuctionKey<T>,
+		},
+		MinBidRatioBoundsSet {
+			lower: u8,
+			upper: u8,
+		},
+		CircuitBreakerTripped {
+			disputes: u32,
+			settlements: u32,
+		},
+		CircuitBreakerCleared,
+
+		TransferOffered {
+			auction_key: AuctionKey<T>,
+			new_owner: T::AccountId,
+		},
+		ReleaseProposed {
+			auction_key: AuctionKey<T>,
+			worker: T::AccountId,
+			penalty: BalanceOf<T>,
+		},
+		Released {
+			auction_key: AuctionKey<T>,
+			worker: T::AccountId,
+			penalty: BalanceOf<T>,
+		},
+		Abandoned {
+			auction_key: AuctionKey<T>,
+			worker: T::AccountId,
+			penalty: BalanceOf<T>,
+		},
+		OwnershipTransferred {
+			auction_key: AuctionKey<T>,
+			new_auction_key: AuctionKey<T>,
+		},
+		SettlementModeSet {
+			auction_key: AuctionKey<T>,
+			settlement_mode: SettlementMode,
+		},
+		Attached {
+			auction_key: AuctionKey<T>,
+			author: T::AccountId,
+		},
 
-		Bid { auction_key: Key<T>, bid_key: Key<T>, price: BalanceOf<T> },
-		Retracted { auction_key: Key<T>, bid_key: Key<T>, price: BalanceOf<T> },
+		BundleBid {
+			owner: T::AccountId,
+			bidder: T::AccountId,
+			total_price: BalanceOf<T>,
+		},
+		BundleAccepted {
+			owner: T::AccountId,
+			bidder: T::AccountId,
+		},
+		BundleWithdrawn {
+			owner: T::AccountId,
+			bidder: T::AccountId,
+		},
 
-		Confirmed { auction_key: Key<T> },
-		Cancelled { auction_key: Key<T> },
+		TemplatePublished {
+			template_id: u32,
+			author: T::AccountId,
+		},
+		TemplateUnpublished {
+			template_id: u32,
+		},
+		ProjectCreated {
+			project_id: u32,
+			owner: T::AccountId,
+		},
+		ProjectAuctionCreated {
+			project_id: u32,
+			auction_key: AuctionKey<T>,
+		},
+		// member auctions left to process, the project is removed once none remain
+		ProjectCancelled {
+			project_id: u32,
+			remaining: u32,
+		},
+		// member auction that could not be cancelled and was left out of the project
+		ProjectAuctionCancelFailed {
+			project_id: u32,
+			auction_key: AuctionKey<T>,
+			error: DispatchError,
+		},
+		MilestonesSet {
+			auction_key: AuctionKey<T>,
+			count: u32,
+		},
+		MilestoneConfirmed {
+			auction_key: AuctionKey<T>,
+			index: u32,
+			paid: BalanceOf<T>,
+		},
+		ItemsSubmitted {
+			auction_key: AuctionKey<T>,
+			count: u32,
+		},
+		ItemsConfirmed {
+			auction_key: AuctionKey<T>,
+			confirmed: u32,
+			paid: BalanceOf<T>,
+		},
 
-		Disputed { auction_key: Key<T> },
-		Arbitrated { auction_key: Key<T>, fulfilled: bool },
+		Appealed {
+			auction_key: AuctionKey<T>,
+			appellant: T::AccountId,
+		},
+		AppealResolved {
+			auction_key: AuctionKey<T>,
+			overturned: bool,
+		},
+		ArbitrationFeeReleased {
+			auction_key: AuctionKey<T>,
+			arbitrator: T::AccountId,
+		},
+
+		ArbitratorStaked {
+			arbitrator: T::AccountId,
+			stake: BalanceOf<T>,
+		},
+		ArbitratorUnstaked {
+			arbitrator: T::AccountId,
+			stake: BalanceOf<T>,
+		},
+		MisconductReported {
+			arbitrator: T::AccountId,
+			auction_key: AuctionKey<T>,
+		},
+		MisconductResolved {
+			arbitrator: T::AccountId,
+			auction_key: AuctionKey<T>,
+			slashed: bool,
+		},
+		CollusionFlagged {
+			auction_key: AuctionKey<T>,
+			until: T::BlockNumber,
+		},
+		CollusionReviewResolved {
+			auction_key: AuctionKey<T>,
+			collusion: bool,
+		},
+		BountyBoosted {
+			auction_key: AuctionKey<T>,
+			booster: T::AccountId,
+			amount: BalanceOf<T>,
+			bounty: BalanceOf<T>,
+		},
+		QuoteRedeemed {
+			auction_key: AuctionKey<T>,
+			bid_key: BidKey<T>,
+			price: BalanceOf<T>,
+		},
+		RetractQueued {
+			auction_key: AuctionKey<T>,
+			bidder: T::AccountId,
+		},
+		ConsolationPaid {
+			auction_key: AuctionKey<T>,
+			bidder: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		QueuedRetractProcessed {
+			auction_key: AuctionKey<T>,
+			bidder: T::AccountId,
+			result: DispatchResult,
+		},
+		BidBatchingSet {
+			auction_key: AuctionKey<T>,
+			enabled: bool,
+		},
+		BidStaged {
+			auction_key: AuctionKey<T>,
+			bidder: T::AccountId,
+			price: BalanceOf<T>,
+		},
+		StagedBidRefunded {
+			auction_key: AuctionKey<T>,
+			bidder: T::AccountId,
+			price: BalanceOf<T>,
+		},
+		StagedBidUnlockFailed {
+			auction_key: AuctionKey<T>,
+			bidder: T::AccountId,
+			deposit: BalanceOf<T>,
+		},
+		SettlementReceiptRequested {
+			auction_key: AuctionKey<T>,
+			destination: T::ReceiptDestination,
+		},
+		AutoBidSet {
+			auction_key: AuctionKey<T>,
+			bidder: T::AccountId,
+			floor: BalanceOf<T>,
+			decrement: BalanceOf<T>,
+		},
+		AutoBidExhausted {
+			auction_key: AuctionKey<T>,
+			bidder: T::AccountId,
+		},
+		RescaleStarted {
+			factor: FixedU128,
+		},
+		RescaleCompleted {
+			factor: FixedU128,
+		},
+		BountyRescaled {
+			auction_key: AuctionKey<T>,
+			bounty: BalanceOf<T>,
+		},
+		BountyAssetSet {
+			auction_key: AuctionKey<T>,
+			asset_id: T::AssetId,
+		},
+		TopUpRequested {
+			auction_key: AuctionKey<T>,
+			bid_key: BidKey<T>,
+			deadline: T::BlockNumber,
+		},
+		ToppedUp {
+			auction_key: AuctionKey<T>,
+			bid_key: BidKey<T>,
+		},
+		// bid promoted after the top bid was dropped for a missed top-up
+		TopUpExpired {
+			auction_key: AuctionKey<T>,
+			bid_key: BidKey<T>,
+			price: BalanceOf<T>,
+		},
+		OperatorSet {
+			owner: T::AccountId,
+			operator: T::AccountId,
+			auction_key: Option<AuctionKey<T>>,
+			permissions: u8,
+		},
+		QuoteAssetSet {
+			auction_key: AuctionKey<T>,
+			quote_asset: T::QuoteAsset,
+			rate: FixedU128,
+		},
+		SavingsShareSet {
+			auction_key: AuctionKey<T>,
+			savings_share: Permill,
+		},
+		SettlementReceiptSent {
+			auction_key: AuctionKey<T>,
+			destination: T::ReceiptDestination,
+			result: DispatchResult,
+		},
+		DefaultJudgment {
+			auction_key: AuctionKey<T>,
+			worker: T::AccountId,
+			paid: BalanceOf<T>,
+		},
+		// head is the SCALE encoded auction as last stored, decoded like Auctions values
+		SettlementSnapshot {
+			auction_key: AuctionKey<T>,
+			head: Vec<u8>,
+			winning_bid: Option<(BidKey<T>, BalanceOf<T>)>,
+			worker: Option<T::AccountId>,
+			price: BalanceOf<T>,
+			boosted: BalanceOf<T>,
+			outcome: Outcome,
+			settled_block: T::BlockNumber,
+		},
+
+		BlockMetrics {
+			metrics: MarketMetrics<BalanceOf<T>>,
+		},
+		Rated {
+			auction_key: AuctionKey<T>,
+			rater: T::AccountId,
+			ratee: T::AccountId,
+			score: u8,
+		},
+		PayoutClaimed {
+			recipient: T::AccountId,
+			payer: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		PayoutFailed {
+			recipient: T::AccountId,
+			payer: T::AccountId,
+			amount: BalanceOf<T>,
+		},
 	}
 
 	// Pallets types to use in dispatchable interface.
-	#[derive(Encode, Decode, TypeInfo)]
+	// Auction listed by an owner, numbered by the account nonce of the owner when listed.
+	#[derive(
+		Encode,
+		Decode,
+		MaxEncodedLen,
+		Clone,
+		Copy,
+		PartialEq,
+		Eq,
+		PartialOrd,
+		Ord,
+		Default,
+		RuntimeDebug,
+		TypeInfo,
+	)]
+	pub struct AuctionId<AccountId, Index>(pub AccountId, pub Index);
+
+	// runtime APIs and RPC take auction keys as plain tuples, which encode the same way
+	impl<AccountId, Index> From<(AccountId, Index)> for AuctionId<AccountId, Index> {
+		fn from((owner, nonce): (AccountId, Index)) -> Self {
+			AuctionId(owner, nonce)
+		}
+	}
+
+	// Position of a bid in the bid stack of an auction, counted from the bottom.
+	pub type BidSeq = u32;
+
+	// Bid placed by a bidder at a position of the bid stack, the default id keys the top bid.
+	#[derive(
+		Encode, Decode, MaxEncodedLen, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug, TypeInfo,
+	)]
+	pub struct BidId<AccountId>(pub AccountId, pub BidSeq);
+
+	// Auction head read by every call, the task payload is kept apart in AuctionData.
+	#[derive(Encode, Decode, MaxEncodedLen, TypeInfo)]
+	#[codec(mel_bound())]
 	#[scale_info(skip_type_params(T))]
 	pub struct Auction<T: Config> {
 		pub arbitrator: T::AccountId,
 		pub bounty: BalanceOf<T>,
 		pub deposit: BalanceOf<T>,
+		pub listing_fee: BalanceOf<T>,
 		pub initial_block: T::BlockNumber,
 		pub terminal_block: T::BlockNumber,
-		pub data: Vec<u8>,
 		pub in_dispute: bool,
+		pub escalated: bool,
+		pub joint_confirmation: bool,
+		pub owner_confirmed: bool,
+		pub worker_acknowledged: bool,
+		pub location: Option<Location>,
+		pub distance_premium_per_unit: Option<BalanceOf<T>>,
+		pub settlement_mode: SettlementMode,
+		pub paused_at: Option<T::BlockNumber>,
+		pub required_capability: Option<Capability>,
+		pub version: u32,
+		pub fallback_arbitration: bool,
+		pub min_bid_ratio: Option<u8>,
+		pub assignment_confirmation_blocks: Option<T::BlockNumber>,
+		pub top_since: T::BlockNumber,
+		pub completion_deadline: Option<T::BlockNumber>,
+		pub vesting_period: Option<T::BlockNumber>,
+		// no price curve, assigned only once the owner accepts the top bid
+		pub open_ended: bool,
+		pub accepted: bool,
+		pub disputed_at: Option<T::BlockNumber>,
+		pub data_size_class: DataSizeClass,
+		// part of the bounty contributed by boosters rather than the owner
+		pub boosted: BalanceOf<T>,
+		pub boosters: u32,
+		// bids are staged and only the best of each block is committed
+		pub batched_bids: bool,
+		// share of the difference between bounty and price promised to the worker
+		pub savings_share: Permill,
+		// bids are priced in this asset and converted to the currency of the pallet at the
+		// rate of the oracle, or the rate of creation while the oracle can't price it
+		pub quote_asset: Option<T::QuoteAsset>,
+		pub quote_rate: FixedU128,
+		// top bid was promoted without its deposit, which must be reserved by this block
+		pub top_up_deadline: Option<T::BlockNumber>,
+		// opted out of governance rescaling of open bounties at creation
+		pub fixed_bounty: bool,
+		// bounty and deposit are escrowed in this asset rather than the currency of the
+		// pallet, the listing fee is always paid in the currency of the pallet
+		pub asset: Option<T::AssetId>,
+		// part of the bounty already paid to the worker for confirmed milestones or items
+		pub released: BalanceOf<T>,
+	}
+
+	// What the assigned worker gets paid.
+	#[derive(Encode, Decode, MaxEncodedLen, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+	pub enum SettlementMode {
+		// lowest bid is paid its own price
+		FirstPrice,
+		// lowest bid is paid the second lowest price, bounded by bounty
+		SecondPrice,
+	}
+
+	impl Default for SettlementMode {
+		fn default() -> Self {
+			SettlementMode::FirstPrice
+		}
+	}
+
+	// Size class of the task data of an auction, scaling its byte fee and weight.
+	#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub enum DataSizeClass {
+		Small,
+		Medium,
+		Large,
+	}
+
+	impl DataSizeClass {
+		// largest task data accepted by auctions of the class
+		pub fn max_size<T: Config>(&self) -> u32 {
+			match self {
+				DataSizeClass::Small => T::MaxDataSize::get() / 16,
+				DataSizeClass::Medium => T::MaxDataSize::get() / 4,
+				DataSizeClass::Large => T::MaxDataSize::get(),
+			}
+		}
+
+		// factor applied to the listing fee per byte of task data
+		pub fn fee_multiplier(&self) -> u32 {
+			match self {
+				DataSizeClass::Small => 1,
+				DataSizeClass::Medium => 2,
+				DataSizeClass::Large => 4,
+			}
+		}
+
+		// smallest class that fits task data of the given length
+		pub fn fitting<T: Config>(data_len: u32) -> Self {
+			[DataSizeClass::Small, DataSizeClass::Medium]
+				.into_iter()
+				.find(|class| data_len <= class.max_size::<T>())
+				.unwrap_or(DataSizeClass::Large)
+		}
+	}
+
+	// Grid coordinates of a task or worker.
+	#[derive(
+		Encode, Decode, MaxEncodedLen, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug, TypeInfo,
+	)]
+	pub struct Location {
+		pub x: i32,
+		pub y: i32,
+	}
+
+	impl Location {
+		// manhattan distance in grid units
+		pub fn distance(&self, other: &Location) -> u32 {
+			let dx = (self.x as i64 - other.x as i64).abs();
+			let dy = (self.y as i64 - other.y as i64).abs();
+			(dx + dy).saturated_into()
+		}
+	}
+
+	// Identifier of a credential such as a trade licence.
+	pub type Capability = u32;
+
+	#[derive(Encode, Decode, MaxEncodedLen, Clone, PartialEq, Default, RuntimeDebug, TypeInfo)]
+	pub struct WorkerProfile {
+		pub location: Option<Location>,
+		pub max_concurrent_assignments: Option<u32>,
+	}
+
+	// Coordination reference posted by owner or worker, e.g. hash of delivery instructions.
+	#[derive(Encode, Decode, MaxEncodedLen, TypeInfo)]
+	#[codec(mel_bound())]
+	#[scale_info(skip_type_params(T))]
+	pub struct Attachment<T: Config> {
+		pub author: T::AccountId,
+		pub reference: BoundedVec<u8, T::MaxAttachmentSize>,
+		pub deposit: BalanceOf<T>,
+	}
+
+	// Kind of deliverable a task asks for.
+	#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub enum Deliverable {
+		Document,
+		Code,
+		Dataset,
+		Media,
+		Physical,
+	}
+
+	// Machine-readable requirements of a task, validated for consistency at creation.
+	#[derive(Encode, Decode, MaxEncodedLen, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub struct TaskRequirements<BlockNumber> {
+		// block by which the deliverables are due, after the auction is assigned
+		pub deadline: BlockNumber,
+		pub deliverable: Deliverable,
+		pub quantity: u32,
+		pub capability: Option<Capability>,
+	}
+
+	// Combined bid of one worker on several auctions of the same owner.
+	#[derive(Encode, Decode, MaxEncodedLen, TypeInfo)]
+	#[codec(mel_bound())]
+	#[scale_info(skip_type_params(T))]
+	pub struct Bundle<T: Config> {
+		pub auction_keys: BoundedVec<AuctionKey<T>, T::MaxBundleSize>,
+		pub total_price: BalanceOf<T>,
+		pub deposit: BalanceOf<T>,
+	}
+
+	// How an auction was settled.
+	#[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+	pub enum Outcome {
+		Confirmed,
+		Cancelled,
+		Arbitrated { fulfilled: bool },
+		// arbitrator did not rule within the arbitration period
+		Defaulted,
+	}
+
+	// Compact record of a settled auction, kept in a per-auction child trie.
+	#[derive(Encode, Decode, TypeInfo)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Receipt<T: Config> {
+		pub worker: Option<T::AccountId>,
+		pub arbitrator: T::AccountId,
+		pub price: BalanceOf<T>,
+		pub outcome: Outcome,
+		pub settled_block: T::BlockNumber,
+		pub owner_rating: Option<u8>,
+		pub worker_rating: Option<u8>,
+		// commitment to the reasoned off-chain decision of an arbitrated auction
+		pub verdict_hash: Option<T::Hash>,
+	}
+
+	// Compact record of the parties and ruling of an arbitrated auction, kept for the dispute
+	// retention period.
+	#[derive(Encode, Decode, MaxEncodedLen, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+	#[codec(mel_bound())]
+	#[scale_info(skip_type_params(T))]
+	pub struct Tombstone<T: Config> {
+		pub worker: T::AccountId,
+		pub arbitrator: T::AccountId,
+		pub fulfilled: bool,
+		pub verdict_hash: Option<T::Hash>,
+		// hash of the task data, which is deleted at settlement
+		pub data_hash: T::Hash,
+		pub settled_block: T::BlockNumber,
+	}
+
+	// Public task definition that anyone can list auctions from.
+	#[derive(Encode, Decode, MaxEncodedLen, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+	#[codec(mel_bound())]
+	#[scale_info(skip_type_params(T))]
+	pub struct Template<T: Config> {
+		pub author: T::AccountId,
+		pub name: BoundedVec<u8, T::MaxTemplateNameLength>,
+		pub bounty: BalanceOf<T>,
+		pub deposit: BalanceOf<T>,
+		pub duration: T::BlockNumber,
+		pub data: BoundedVec<u8, T::MaxDataSize>,
+		// held from the author until unpublished
+		pub template_deposit: BalanceOf<T>,
+	}
+
+	// Auctions of an owner sharing an arbitrator and parameters. Members stay listed after
+	// they settle, counting towards the bound of the project.
+	#[derive(Encode, Decode, MaxEncodedLen, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+	#[codec(mel_bound())]
+	#[scale_info(skip_type_params(T))]
+	pub struct Project<T: Config> {
+		pub owner: T::AccountId,
+		pub name: BoundedVec<u8, T::MaxProjectNameLength>,
+		pub arbitrator: T::AccountId,
+		pub deposit: BalanceOf<T>,
+		pub duration: T::BlockNumber,
+		pub auctions: BoundedVec<AuctionKey<T>, T::MaxAuctionsPerProject>,
+		// no auctions are added once cancellation started
+		pub cancelled: bool,
+	}
+
+	// Status of the member auctions of a project.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, RuntimeDebug, TypeInfo)]
+	pub struct ProjectSummary<Balance> {
+		pub open: u32,
+		pub assigned: u32,
+		pub disputed: u32,
+		pub settled: u32,
+		// bounties of the auctions not yet settled
+		pub total_bounty: Balance,
+	}
+
+	// Template parameters replaced when forking or listing from a template.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, RuntimeDebug, TypeInfo)]
+	pub struct TemplateOverrides<Balance, BlockNumber> {
+		pub bounty: Option<Balance>,
+		pub deposit: Option<Balance>,
+		pub duration: Option<BlockNumber>,
+	}
+
+	// Where create takes the terms of an auction from.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub enum TermsSource<Balance, BlockNumber> {
+		// the arguments of create
+		Given,
+		// a public template, whose bounty, deposit, duration and data replace the given ones
+		// unless overridden
+		Template { template_id: u32, overrides: TemplateOverrides<Balance, BlockNumber> },
+		// a project of the owner the auction joins, whose arbitrator, deposit and duration
+		// replace the given ones
+		Project { project_id: u32 },
+	}
+
+	// Firm price a worker quoted off-chain for an auction, valid up to the expiry block.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub struct Quote<AccountId, Balance, BlockNumber> {
+		pub worker: AccountId,
+		pub price: Balance,
+		pub expiry: BlockNumber,
+	}
+
+	// Quote with the signature of the worker over the auction key and the encoded quote.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub struct SignedQuote<AccountId, Balance, BlockNumber, Signature> {
+		pub quote: Quote<AccountId, Balance, BlockNumber>,
+		pub signature: Signature,
+	}
+
+	// Terms of an auction listed by create on top of its bounty, deposit and terminal block,
+	// each left unset by default.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub struct CreateOptions<AssetId, QuoteAsset, Balance, BlockNumber, SignedQuote> {
+		// template or project the terms are taken from instead of the arguments of create
+		pub source: TermsSource<Balance, BlockNumber>,
+		// escrow the bounty and deposits in an asset, bids are then priced in the asset and the
+		// listing fee is still paid in the currency of the pallet
+		pub asset: Option<AssetId>,
+		// size class of the task data, the smallest one fitting the data if unset
+		pub size_class: Option<DataSizeClass>,
+		// keep the auction open from now until the owner accepts a bid, ignoring the terminal
+		// block
+		pub open_ended: bool,
+		// leave the bounty as is when governance rescales open bounties
+		pub fixed_bounty: bool,
+		// quote bids in another asset, converted to the currency of the bounty at the rate of
+		// confirmation and bounded by the bounty
+		pub quote_asset: Option<QuoteAsset>,
+		// requirements checked for consistency, requiring the capability they name from bidders
+		pub requirements: Option<TaskRequirements<BlockNumber>>,
+		// shares of the price confirmed one milestone at a time, adding up to the whole price
+		pub milestones: Vec<Perbill>,
+		// bid a worker agreed to off-chain, signed over the key the auction is created under
+		pub quote: Option<SignedQuote>,
+	}
+
+	impl<AssetId, QuoteAsset, Balance, BlockNumber, SignedQuote> Default
+		for CreateOptions<AssetId, QuoteAsset, Balance, BlockNumber, SignedQuote>
+	{
+		fn default() -> Self {
+			Self {
+				source: TermsSource::Given,
+				asset: None,
+				size_class: None,
+				open_ended: false,
+				fixed_bounty: false,
+				quote_asset: None,
+				requirements: None,
+				milestones: Vec::new(),
+				quote: None,
+			}
+		}
+	}
+
+	// Arbitration fee held back from the arbitrator while the ruling can be appealed.
+	#[derive(Encode, Decode, MaxEncodedLen, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+	#[codec(mel_bound())]
+	#[scale_info(skip_type_params(T))]
+	pub struct ArbitrationEscrow<T: Config> {
+		pub payer: T::AccountId,
+		pub arbitrator: T::AccountId,
+		pub amount: BalanceOf<T>,
+		pub appeal_deadline: T::BlockNumber,
+		pub appealed: bool,
+		pub asset: Option<T::AssetId>,
+	}
+
+	// Aggregate of the rulings an arbitrator made and how they held up on appeal.
+	#[derive(Encode, Decode, MaxEncodedLen, Clone, PartialEq, Default, RuntimeDebug, TypeInfo)]
+	pub struct DisputeStats<BlockNumber> {
+		pub rulings: u32,
+		// blocks from dispute to ruling, summed over all rulings
+		pub resolution_blocks: BlockNumber,
+		// rulings made after the dispute SLA of the auction category
+		pub sla_breaches: u32,
+		pub appealed: u32,
+		pub overturned: u32,
+	}
+
+	// Amounts an account moved through settlements within one reporting period.
+	#[derive(
+		Encode, Decode, MaxEncodedLen, Clone, PartialEq, Eq, Default, RuntimeDebug, TypeInfo,
+	)]
+	pub struct PeriodTotals<Balance> {
+		pub earned: Balance,
+		pub spent: Balance,
+		pub slashed: Balance,
+		pub arbitration_income: Balance,
+	}
+
+	// Aggregate of the 1-5 ratings an account received.
+	#[derive(Encode, Decode, MaxEncodedLen, Clone, PartialEq, Default, RuntimeDebug, TypeInfo)]
+	pub struct RatingSummary {
+		pub sum: u32,
+		pub count: u32,
+	}
+
+	// Marketplace activity counted over a single block.
+	#[derive(Encode, Decode, MaxEncodedLen, Clone, PartialEq, Default, RuntimeDebug, TypeInfo)]
+	pub struct MarketMetrics<Balance> {
+		pub bids: u32,
+		pub disputes: u32,
+		pub settlements: u32,
+		pub settled_volume: Balance,
+	}
+
+	// Disputes and settlements counted over the window starting at the given block, and over
+	// the window before it.
+	#[derive(
+		Encode, Decode, MaxEncodedLen, Clone, PartialEq, Eq, Default, RuntimeDebug, TypeInfo,
+	)]
+	pub struct DisputeWindow<BlockNumber> {
+		pub window_start: BlockNumber,
+		pub disputes: u32,
+		pub settlements: u32,
+		pub previous_disputes: u32,
+		pub previous_settlements: u32,
 	}
 
 	// The pallet's runtime storage items.
@@ -97,329 +1332,5634 @@ pub mod pallet {
 	#[pallet::storage]
 	#[pallet::getter(fn auctions)]
 	pub(super) type Auctions<T: Config> =
-		StorageMap<_, Twox64Concat, Key<T>, Auction<T>, OptionQuery>;
+		StorageMap<_, Twox64Concat, AuctionKey<T>, Auction<T>, OptionQuery>;
+
+	// Auctions whose assigned worker must acknowledge completion by the given block.
+	#[pallet::storage]
+	#[pallet::getter(fn completion_deadlines)]
+	pub(super) type CompletionDeadlines<T: Config> =
+		StorageMap<_, Twox64Concat, T::BlockNumber, DeadlineQueue<T>, ValueQuery>;
+
+	// Tombstones of arbitrated auctions.
+	#[pallet::storage]
+	#[pallet::getter(fn tombstones)]
+	pub(super) type Tombstones<T: Config> =
+		StorageMap<_, Twox64Concat, AuctionKey<T>, Tombstone<T>, OptionQuery>;
+
+	// Tombstones pruned at the given block.
+	#[pallet::storage]
+	#[pallet::getter(fn tombstone_expiries)]
+	pub(super) type TombstoneExpiries<T: Config> =
+		StorageMap<_, Twox64Concat, T::BlockNumber, DeadlineQueue<T>, ValueQuery>;
+
+	// Rolling counts of disputes and settlements watched by the circuit breaker.
+	#[pallet::storage]
+	#[pallet::getter(fn dispute_rate)]
+	pub(super) type DisputeRate<T: Config> =
+		StorageValue<_, DisputeWindow<T::BlockNumber>, ValueQuery>;
+
+	// Block at which the circuit breaker tripped, suspending creation of auctions until
+	// governance clears it.
+	#[pallet::storage]
+	#[pallet::getter(fn circuit_breaker)]
+	pub(super) type CircuitBreaker<T: Config> = StorageValue<_, T::BlockNumber, OptionQuery>;
+
+	// Last provider block whose deadlines were processed.
+	#[pallet::storage]
+	#[pallet::getter(fn deadlines_processed_until)]
+	pub(super) type DeadlinesProcessedUntil<T: Config> =
+		StorageValue<_, T::BlockNumber, OptionQuery>;
+
+	// Auctions whose top bid is dropped at the given block unless its deposit was topped up.
+	#[pallet::storage]
+	#[pallet::getter(fn top_up_deadlines)]
+	pub(super) type TopUpDeadlines<T: Config> =
+		StorageMap<_, Twox64Concat, T::BlockNumber, DeadlineQueue<T>, ValueQuery>;
+
+	// Featured auctions ordered by descending promotion stake, for front-end discovery.
+	#[pallet::storage]
+	#[pallet::getter(fn promoted_auctions)]
+	pub(super) type PromotedAuctions<T: Config> =
+		StorageValue<_, BoundedVec<(AuctionKey<T>, BalanceOf<T>), T::MaxPromoted>, ValueQuery>;
+
+	// Task payload of each auction, only read by calls that need it, bounded so that proofs
+	// of its size can be accounted for.
+	#[pallet::storage]
+	#[pallet::getter(fn auction_data)]
+	pub(super) type AuctionData<T: Config> =
+		StorageMap<_, Twox64Concat, AuctionKey<T>, BoundedVec<u8, T::MaxDataSize>, ValueQuery>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn bids)]
 	pub(super) type Bids<T: Config> = StorageDoubleMap<
 		_,
 		Twox64Concat,
-		Key<T>,
+		AuctionKey<T>,
 		Twox64Concat,
-		Key<T>,
-		(Key<T>, BalanceOf<T>),
+		BidKey<T>,
+		(BidKey<T>, BalanceOf<T>),
 		OptionQuery,
 	>;
 
-	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
-	// These functions materialize as "extrinsics", which are often compared to transactions.
-	// Dispatchable functions must be annotated with a weight and must return a DispatchResult.
-	#[pallet::call]
-	impl<T: Config> Pallet<T> {
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
-		pub fn create(
-			origin: OriginFor<T>,
-			arbitrator: T::AccountId,
-			bounty: BalanceOf<T>,
-			deposit: BalanceOf<T>,
-			terminal_block: T::BlockNumber,
-			data: Vec<u8>,
-		) -> DispatchResult {
-			// input checks
-			let owner = ensure_signed(origin)?;
-			let initial_block = frame_system::Pallet::<T>::block_number();
-			ensure!(bounty >= T::MinBounty::get(), Error::<T>::MinBountyRequired);
-			ensure!(deposit >= T::MinDeposit::get(), Error::<T>::MinDepositRequired);
-			ensure!(
-				data.len() <= T::MaxDataSize::get().try_into().unwrap(),
-				Error::<T>::MaxDataSizeExceeded
-			);
+	#[pallet::storage]
+	#[pallet::getter(fn retracted_at)]
+	pub(super) type RetractedAt<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		AuctionKey<T>,
+		Twox64Concat,
+		T::AccountId,
+		T::BlockNumber,
+		OptionQuery,
+	>;
 
-			// reserve balance for bounty and deposit
-			T::Currency::reserve(&owner, bounty + deposit)?;
+	#[pallet::storage]
+	#[pallet::getter(fn firm_until)]
+	pub(super) type FirmUntil<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		AuctionKey<T>,
+		Twox64Concat,
+		BidKey<T>,
+		T::BlockNumber,
+		OptionQuery,
+	>;
 
-			// generate auction key
-			let nonce = frame_system::Pallet::<T>::account_nonce(&owner);
-			let auction_key = (owner, nonce);
+	#[pallet::storage]
+	#[pallet::getter(fn bid_versions)]
+	pub(super) type BidVersions<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, AuctionKey<T>, Twox64Concat, BidKey<T>, u32, ValueQuery>;
+
+	// Hash of the real bidder and a salt for bids placed through one-time accounts,
+	// with the real bidder once revealed.
+	#[pallet::storage]
+	#[pallet::getter(fn bid_commitments)]
+	pub(super) type BidCommitments<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		AuctionKey<T>,
+		Twox64Concat,
+		BidKey<T>,
+		(T::Hash, Option<T::AccountId>),
+		OptionQuery,
+	>;
+
+	// Number of disputes awaiting a ruling of each arbitrator.
+	#[pallet::storage]
+	#[pallet::getter(fn open_disputes)]
+	pub(super) type OpenDisputes<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn attestations)]
+	pub(super) type Attestations<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		Capability,
+		T::BlockNumber,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn pending_payouts)]
+	pub(super) type PendingPayouts<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		T::AccountId,
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
+	// Recipient and payer of the last queued payout flushed in on_idle.
+	#[pallet::storage]
+	pub(super) type PayoutCursor<T: Config> =
+		StorageValue<_, (T::AccountId, T::AccountId), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn stale_auctions)]
+	pub(super) type StaleAuctions<T: Config> =
+		StorageMap<_, Twox64Concat, AuctionKey<T>, (), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn block_metrics)]
+	pub(super) type BlockMetrics<T: Config> =
+		StorageValue<_, MarketMetrics<BalanceOf<T>>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn transfer_offers)]
+	pub(super) type TransferOffers<T: Config> =
+		StorageMap<_, Twox64Concat, AuctionKey<T>, T::AccountId, OptionQuery>;
+
+	// Penalty the assigned worker offers to pay to be released from an auction.
+	#[pallet::storage]
+	#[pallet::getter(fn release_proposals)]
+	pub(super) type ReleaseProposals<T: Config> =
+		StorageMap<_, Twox64Concat, AuctionKey<T>, (T::AccountId, BalanceOf<T>), OptionQuery>;
+
+	// Completion deadline one party of an assignment proposes to move to.
+	#[pallet::storage]
+	#[pallet::getter(fn extension_proposals)]
+	pub(super) type ExtensionProposals<T: Config> =
+		StorageMap<_, Twox64Concat, AuctionKey<T>, (T::AccountId, T::BlockNumber), OptionQuery>;
+
+	// Auctions flagged for owner-worker collusion, with the block until which confirmation
+	// is frozen pending a governance decision, or none once the review has been cleared.
+	#[pallet::storage]
+	#[pallet::getter(fn collusion_reviews)]
+	pub(super) type CollusionReviews<T: Config> =
+		StorageMap<_, Twox64Concat, AuctionKey<T>, Option<T::BlockNumber>, OptionQuery>;
+
+	// Escrow of disputed auctions moved to the pallet account, as the worker and the amounts
+	// held for the owner and the worker.
+	#[pallet::storage]
+	#[pallet::getter(fn dispute_holds)]
+	pub(super) type DisputeHolds<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		AuctionKey<T>,
+		(T::AccountId, BalanceOf<T>, BalanceOf<T>),
+		OptionQuery,
+	>;
+
+	// Top bids marked for withdrawal in on_idle, with the assignment state the bidder expects.
+	#[pallet::storage]
+	#[pallet::getter(fn queued_retractions)]
+	pub(super) type QueuedRetractions<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		AuctionKey<T>,
+		Twox64Concat,
+		T::AccountId,
+		Option<bool>,
+		OptionQuery,
+	>;
+
+	// Bids on batched auctions placed in the current block with their price and reserved deposit,
+	// drained in on_finalize.
+	#[pallet::storage]
+	#[pallet::getter(fn staged_bids)]
+	pub(super) type StagedBids<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		AuctionKey<T>,
+		Twox64Concat,
+		T::AccountId,
+		(BalanceOf<T>, BalanceOf<T>),
+		OptionQuery,
+	>;
+
+	// Floor and decrement of the counter-bids placed for a bidder when outbid.
+	#[pallet::storage]
+	#[pallet::getter(fn auto_bids)]
+	pub(super) type AutoBids<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		AuctionKey<T>,
+		Twox64Concat,
+		T::AccountId,
+		(BalanceOf<T>, BalanceOf<T>),
+		OptionQuery,
+	>;
+
+	// Calls operators may dispatch on behalf of an owner, for one auction or all auctions of
+	// the owner when no auction is given. Entries of settled auctions are inert.
+	#[pallet::storage]
+	#[pallet::getter(fn operators)]
+	pub(super) type Operators<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		(T::AccountId, Option<AuctionKey<T>>),
+		u8,
+		ValueQuery,
+	>;
+
+	// Chains of remote owners awaiting the settlement receipt of their auction.
+	#[pallet::storage]
+	#[pallet::getter(fn receipt_destinations)]
+	pub(super) type ReceiptDestinations<T: Config> =
+		StorageMap<_, Twox64Concat, AuctionKey<T>, T::ReceiptDestination, OptionQuery>;
+
+	// Requirements of auctions created with a validated requirements structure.
+	#[pallet::storage]
+	#[pallet::getter(fn requirements)]
+	pub(super) type Requirements<T: Config> =
+		StorageMap<_, Twox64Concat, AuctionKey<T>, TaskRequirements<T::BlockNumber>, OptionQuery>;
+
+	// Contributions of third parties to the bounty of an auction, reserved from each booster
+	// until the auction settles.
+	#[pallet::storage]
+	#[pallet::getter(fn boosts)]
+	pub(super) type Boosts<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		AuctionKey<T>,
+		Twox64Concat,
+		T::AccountId,
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
+	// Hashes of the signed quotes already redeemed on each auction, so none of them can be
+	// redeemed twice.
+	#[pallet::storage]
+	#[pallet::getter(fn redeemed_quotes)]
+	pub(super) type RedeemedQuotes<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, AuctionKey<T>, Twox64Concat, T::Hash, (), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn arbitration_escrows)]
+	pub(super) type ArbitrationEscrows<T: Config> =
+		StorageMap<_, Twox64Concat, AuctionKey<T>, ArbitrationEscrow<T>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn templates)]
+	pub(super) type Templates<T: Config> =
+		StorageMap<_, Twox64Concat, u32, Template<T>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn template_count)]
+	pub(super) type TemplateCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_template_id)]
+	pub(super) type NextTemplateId<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	// Factor of a rescale of open bounties applied in on_idle, with the block it started at
+	// and the key of the last auction visited.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_rescale)]
+	pub(super) type PendingRescale<T: Config> =
+		StorageValue<_, (FixedU128, T::BlockNumber, Option<AuctionKey<T>>), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn projects)]
+	pub(super) type Projects<T: Config> = StorageMap<_, Twox64Concat, u32, Project<T>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn project_count)]
+	pub(super) type ProjectCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_project_id)]
+	pub(super) type NextProjectId<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	// Share of the price paid for each milestone of an auction and whether it was confirmed.
+	#[pallet::storage]
+	#[pallet::getter(fn milestones)]
+	pub(super) type Milestones<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		AuctionKey<T>,
+		BoundedVec<(Perbill, bool), T::MaxMilestones>,
+		OptionQuery,
+	>;
+
+	// Items the worker of an auction declared as delivered, and how many the owner confirmed.
+	#[pallet::storage]
+	#[pallet::getter(fn delivered_items)]
+	pub(super) type DeliveredItems<T: Config> =
+		StorageMap<_, Twox64Concat, AuctionKey<T>, (u32, u32), OptionQuery>;
+
+	// Inclusive range of min bid ratios auctions may override the default with.
+	#[pallet::storage]
+	#[pallet::getter(fn min_bid_ratio_bounds)]
+	pub(super) type MinBidRatioBounds<T: Config> = StorageValue<_, (u8, u8), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn dispute_stats)]
+	pub(super) type ArbitratorStats<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, DisputeStats<T::BlockNumber>, ValueQuery>;
+
+	// Settlement totals of an account by reporting period.
+	#[pallet::storage]
+	#[pallet::getter(fn account_totals)]
+	pub(super) type AccountTotals<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		u32,
+		PeriodTotals<BalanceOf<T>>,
+		ValueQuery,
+	>;
+
+	// Blocks an arbitrator is expected to take to rule, by required capability of the auction.
+	#[pallet::storage]
+	#[pallet::getter(fn dispute_slas)]
+	pub(super) type DisputeSlas<T: Config> =
+		StorageMap<_, Twox64Concat, Option<Capability>, T::BlockNumber, OptionQuery>;
+
+	// Fee an approved arbitrator charges to rule on a dispute.
+	#[pallet::storage]
+	#[pallet::getter(fn arbitrator_fees)]
+	pub(super) type ArbitratorFees<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+	// Arbitrator fee quoted when a dispute was raised, held from the disputer until the ruling.
+	#[pallet::storage]
+	#[pallet::getter(fn dispute_quotes)]
+	pub(super) type DisputeQuotes<T: Config> =
+		StorageMap<_, Twox64Concat, AuctionKey<T>, (T::AccountId, BalanceOf<T>), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn ratings)]
+	pub(super) type Ratings<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, RatingSummary, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn workers)]
+	pub(super) type Workers<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, WorkerProfile, OptionQuery>;
+
+	// Workers that have bid on each localized auction, whose premium is priced from the worker's
+	// location, which can't change until the auction is settled.
+	#[pallet::storage]
+	#[pallet::getter(fn location_locks)]
+	pub(super) type LocationLocks<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		AuctionKey<T>,
+		Twox64Concat,
+		T::AccountId,
+		(),
+		OptionQuery,
+	>;
+
+	// Number of unsettled localized auctions each worker has bid on.
+	#[pallet::storage]
+	#[pallet::getter(fn location_lock_count)]
+	pub(super) type LocationLockCount<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+	// Number of auctions each account holds the top bid of.
+	#[pallet::storage]
+	#[pallet::getter(fn active_assignments)]
+	pub(super) type ActiveAssignments<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn attachments)]
+	pub(super) type Attachments<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		AuctionKey<T>,
+		BoundedVec<Attachment<T>, T::MaxAttachments>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn bundles)]
+	pub(super) type Bundles<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		T::AccountId,
+		Bundle<T>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn arbitrator_stakes)]
+	pub(super) type ArbitratorStakes<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn misconduct_reports)]
+	pub(super) type MisconductReports<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		AuctionKey<T>,
+		T::AccountId,
+		OptionQuery,
+	>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: T::BlockNumber) -> Weight {
+			// provider blocks may advance by more than one per block, such as relay chain
+			// blocks, so deadlines of every block passed since the last call are processed
+			let now = T::BlockNumberProvider::current_block_number();
+			let mut block =
+				DeadlinesProcessedUntil::<T>::get().map_or(now, |last| last + One::one());
+			DeadlinesProcessedUntil::<T>::put(now);
+			let mut weight = T::DbWeight::get().reads_writes(1, 1);
+			while block <= now {
+				weight = weight.saturating_add(Self::process_deadlines(block));
+				block += One::one();
+			}
+			weight
+		}
+
+		// commit the best staged bid of each batched auction and publish counters of the
+		// finished block for node side metrics
+		fn on_finalize(_n: T::BlockNumber) {
+			// staged bids of an auction are adjacent, sharing the hashed prefix of its key
+			let mut staged: Vec<(AuctionKey<T>, Vec<(T::AccountId, BalanceOf<T>, BalanceOf<T>)>)> =
+				Vec::new();
+			for (auction_key, bidder, (price, deposit)) in StagedBids::<T>::drain() {
+				match staged.last_mut() {
+					Some((key, bids)) if *key == auction_key => bids.push((bidder, price, deposit)),
+					_ => staged.push((auction_key, vec![(bidder, price, deposit)])),
+				}
+			}
+			for (auction_key, bids) in staged {
+				Self::commit_staged_bids(auction_key, bids);
+			}
+			let metrics = BlockMetrics::<T>::take();
+			Self::track_dispute_rate(&metrics);
+			if metrics != MarketMetrics::default() {
+				T::Telemetry::on_block(&metrics);
+				Self::deposit_event(Event::<T>::BlockMetrics { metrics });
+			}
+		}
+
+		// flush queued payouts and stale keys with the weight left over in the block
+		fn on_idle(_n: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			let cursor_weight = T::DbWeight::get().reads_writes(1, 1);
+			let payout_weight = T::DbWeight::get().reads_writes(2, 3);
+			let max_payouts =
+				Self::fitting_items(remaining_weight.saturating_sub(cursor_weight), payout_weight);
+			let flushed = Self::flush_payouts(max_payouts.saturated_into());
+			let payouts_weight = match max_payouts {
+				0 => 0,
+				_ => cursor_weight + payout_weight * flushed as Weight,
+			};
+			// continue deleting keys of settled auctions
+			let clear_weight = T::DbWeight::get().writes(Self::max_cleared_keys().into());
+			let max_cleared =
+				Self::fitting_items(remaining_weight.saturating_sub(payouts_weight), clear_weight);
+			let stale: Vec<_> =
+				StaleAuctions::<T>::iter_keys().take(max_cleared as usize).collect();
+			for auction_key in stale.iter() {
+				if Self::clear_auction(auction_key).1 {
+					StaleAuctions::<T>::remove(auction_key);
+				}
+			}
+			let stale_weight = clear_weight * stale.len() as Weight;
+			// withdraw queued bids, each bounded by the weight of an immediate retract
+			let retract_weight = Self::max_settlement_weight();
+			let max_retractions = remaining_weight
+				.saturating_sub(payouts_weight + stale_weight)
+				.checked_div(retract_weight)
+				.unwrap_or_default();
+			let retractions: Vec<_> =
+				QueuedRetractions::<T>::iter().take(max_retractions as usize).collect();
+			for (auction_key, bidder, expected_assigned) in retractions.iter() {
+				QueuedRetractions::<T>::remove(auction_key, bidder);
+				let result =
+					Self::do_retract(bidder.clone(), auction_key.clone(), *expected_assigned)
+						.map(|_| ());
+				Self::deposit_auction_event(
+					Self::topic(auction_key),
+					Event::<T>::QueuedRetractProcessed {
+						auction_key: auction_key.clone(),
+						bidder: bidder.clone(),
+						result,
+					},
+				);
+			}
+			let retractions_weight = retract_weight * retractions.len() as Weight;
+			// rescale open bounties after a governance repricing
+			let rescale_weight = T::DbWeight::get().reads_writes(3, 2);
+			let max_rescaled = Self::fitting_items(
+				remaining_weight.saturating_sub(payouts_weight + stale_weight + retractions_weight),
+				rescale_weight,
+			);
+			let rescaled = Self::continue_rescale(max_rescaled.saturated_into());
+			payouts_weight + stale_weight + retractions_weight + rescale_weight * rescaled as Weight
+		}
+	}
+
+	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
+	// These functions materialize as "extrinsics", which are often compared to transactions.
+	// Dispatchable functions must be annotated with a weight and must return a DispatchResult.
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		#[pallet::weight(Pallet::<T>::create_weight_with(options))]
+		pub fn create(
+			origin: OriginFor<T>,
+			arbitrator: T::AccountId,
+			bounty: BalanceOf<T>,
+			deposit: BalanceOf<T>,
+			terminal_block: T::BlockNumber,
+			data: Vec<u8>,
+			options: CreateOptionsOf<T>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			Self::do_create_with(owner, arbitrator, bounty, deposit, terminal_block, data, options)
+				.map(|_| ())
+		}
+
+		#[pallet::weight(T::WeightInfo::extend())]
+		pub fn extend(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+			bounty: BalanceOf<T>,
+			terminal_block: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut auction =
+				Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			// only owner of auction or its operator can extend, the owner escrows the bounty
+			Self::ensure_manager(&who, &auction_key, OPERATOR_EXTEND)?;
+			let owner = auction_key.0.clone();
+			// ensure auction is not assigned
+			if let Some((top_key, price)) = Bids::<T>::get(&auction_key, BidKey::<T>::default()) {
+				ensure!(!auction.is_assigned(&top_key.0, price), Error::<T>::AuctionAssigned);
+			}
+			// bounty must be higher than previous by MinBounty ammount
+			ensure!(
+				bounty > auction.bounty.saturating_add(T::MinBounty::get()),
+				Error::<T>::BountyIncrementRequired
+			);
+			ensure!(bounty <= T::MaxBounty::get(), Error::<T>::MaxBountyExceeded);
+			// an auction past its terminal block is relisted with a new price curve starting
+			// now, which must end in the future
+			let now = T::BlockNumberProvider::current_block_number();
+			let relisted = !auction.open_ended && now >= auction.terminal_block;
+			ensure!(!relisted || terminal_block > now, Error::<T>::FutureTerminalBlockRequired);
+			// reserve the difference in bounty
+			let increase = bounty.checked_sub(&auction.bounty).ok_or(ArithmeticError::Underflow)?;
+			Self::lock_in(&auction.asset, &owner, increase)?;
+			// update auction
+			auction.bounty = bounty;
+			auction.terminal_block = terminal_block;
+			if relisted {
+				auction.initial_block = now;
+			}
+			auction.version += 1;
+			let initial_block = auction.initial_block;
+			Auctions::<T>::insert(&auction_key, auction);
+
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				match relisted {
+					true =>
+						Event::<T>::Relisted { auction_key, bounty, initial_block, terminal_block },
+					false => Event::<T>::Extended { auction_key, bounty, terminal_block },
+				},
+			);
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::set_deposit())]
+		#[transactional]
+		pub fn set_deposit(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+			deposit: BalanceOf<T>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let mut auction =
+				Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			// only owner of auction can change the deposit
+			ensure!(owner == auction_key.0, Error::<T>::OwnerRequired);
+			ensure!(deposit >= T::MinDeposit::get(), Error::<T>::MinDepositRequired);
+			// ensure auction is not assigned
+			let top_bid = Bids::<T>::get(&auction_key, BidKey::<T>::default());
+			if let Some((top_key, price)) = &top_bid {
+				ensure!(!auction.is_assigned(&top_key.0, *price), Error::<T>::AuctionAssigned);
+			}
+			// owner and top bidder escrow the difference, lower bids reserve the new deposit
+			// when they return to the top
+			if deposit > auction.deposit {
+				let increase = deposit - auction.deposit;
+				Self::lock_in(&auction.asset, &owner, increase)?;
+				if let Some((top_key, _)) = top_bid.as_ref().filter(|_| auction.top_funded()) {
+					Self::lock_in(&auction.asset, &top_key.0, increase)?;
+					T::DepositReceipts::mint(&top_key.0, increase)?;
+				}
+			} else {
+				let decrease = auction.deposit - deposit;
+				Self::unlock_in(&auction.asset, &owner, decrease)?;
+				if let Some((top_key, _)) = top_bid.as_ref().filter(|_| auction.top_funded()) {
+					Self::unlock_in(&auction.asset, &top_key.0, decrease)?;
+					T::DepositReceipts::burn(&top_key.0, decrease)?;
+				}
+			}
+			// existing bids agreed to the previous deposit and may retract without penalty
+			auction.deposit = deposit;
+			auction.version += 1;
+			Auctions::<T>::insert(&auction_key, auction);
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::DepositSet { auction_key, deposit },
+			);
+			Ok(())
+		}
+
+		// covers the commit of staged bids in on_finalize and an automatic counter-bid
+		#[pallet::weight(T::WeightInfo::bid())]
+		pub fn bid(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+			price: BalanceOf<T>,
+		) -> DispatchResult {
+			let bidder = ensure_signed(origin)?;
+			if Auctions::<T>::get(&auction_key).map_or(false, |auction| auction.batched_bids) {
+				return Self::stage_bid(bidder, auction_key, price)
+			}
+			let outbid = Bids::<T>::get(&auction_key, BidKey::<T>::default())
+				.map(|(top_key, _)| top_key.0)
+				.filter(|top_bidder| *top_bidder != bidder);
+			Self::do_bid(bidder, auction_key.clone(), price)?;
+			if let Some(outbid) = outbid {
+				Self::counter_bid(outbid, auction_key, price);
+			}
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::bid_firm_until())]
+		pub fn bid_firm_until(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+			price: BalanceOf<T>,
+			firm_until: T::BlockNumber,
+		) -> DispatchResult {
+			let bidder = ensure_signed(origin)?;
+			// bid is only binding until firm_until
+			let bid_key = Self::do_bid(bidder, auction_key.clone(), price)?;
+			FirmUntil::<T>::insert(&auction_key, &bid_key, firm_until);
+			Ok(())
+		}
+
+		// Bid from a one-time account committing to the hash of the real bidder and a salt,
+		// so that the real bidder is only disclosed once the bid is assigned.
+		#[pallet::weight(T::WeightInfo::bid_private())]
+		pub fn bid_private(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+			price: BalanceOf<T>,
+			commitment: T::Hash,
+		) -> DispatchResult {
+			let bidder = ensure_signed(origin)?;
+			// deposit is reserved from the one-time account
+			let bid_key = Self::do_bid(bidder, auction_key.clone(), price)?;
+			BidCommitments::<T>::insert(&auction_key, &bid_key, (commitment, None));
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::reveal_bidder())]
+		pub fn reveal_bidder(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+			bidder: T::AccountId,
+			salt: [u8; 32],
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let auction = Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			let (top_key, top_price) = Bids::<T>::get(&auction_key, BidKey::<T>::default())
+				.ok_or(Error::<T>::AuctionNotAssigned)?;
+			// only the one-time account of the assigned bid can reveal
+			ensure!(origin == top_key.0, Error::<T>::TopBidRequired);
+			ensure!(auction.is_assigned(&top_key.0, top_price), Error::<T>::AuctionNotAssigned);
+			let (commitment, _) = BidCommitments::<T>::get(&auction_key, &top_key)
+				.ok_or(Error::<T>::CommitmentNotFound)?;
+			ensure!(
+				T::Hashing::hash_of(&(&bidder, salt)) == commitment,
+				Error::<T>::CommitmentMismatch
+			);
+			// real bidder is held to the same conflict of interest checks as a public bid
+			ensure!(!Self::acts_for(&bidder, &auction_key.0), Error::<T>::OriginProhibited);
+			ensure!(!Self::acts_for(&bidder, &auction.arbitrator), Error::<T>::OriginProhibited);
+			BidCommitments::<T>::insert(&auction_key, &top_key, (commitment, Some(bidder.clone())));
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::BidderRevealed { auction_key, bid_key: top_key, bidder },
+			);
+			Ok(())
+		}
+
+		#[pallet::weight(Pallet::<T>::max_settlement_weight())]
+		pub fn retract(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+			expected_assigned: Option<bool>,
+		) -> DispatchResultWithPostInfo {
+			let bidder = ensure_signed(origin)?;
+			let removed = Self::do_retract(bidder, auction_key, expected_assigned)?;
+			Ok(Some(Self::settlement_weight(removed)).into())
+		}
+
+		#[pallet::weight(Pallet::<T>::max_settlement_weight())]
+		#[transactional]
+		pub fn withdraw_expired_bid(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+		) -> DispatchResultWithPostInfo {
+			let bidder = ensure_signed(origin)?;
+			// fetch auction and top bid
+			let mut auction =
+				Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			let (top_key, _) = Bids::<T>::get(&auction_key, BidKey::<T>::default())
+				.ok_or(Error::<T>::TopBidRequired)?;
+			ensure!(bidder == top_key.0, Error::<T>::TopBidRequired);
+			ensure!(Self::is_expired(&auction_key, &auction, &top_key), Error::<T>::BidNotExpired);
+			// bids awaiting a top-up hold no deposit and are retracted instead
+			ensure!(auction.top_funded(), Error::<T>::TopUpRequired);
+			// expired bids are no longer binding, so the deposit is returned in full
+			Self::unlock_in(&auction.asset, &bidder, auction.deposit)?;
+			Self::vacate(&bidder, auction.deposit)?;
+			// the next firm bid of the stack becomes the top bid
+			let (removed, bid_key, price) = Self::promote_next(&auction_key, &mut auction, top_key);
+			// clear dispute and confirmations of the withdrawn bid,
+			// and restart the confirmation period of the new top bid
+			if auction.in_dispute ||
+				auction.owner_confirmed ||
+				auction.worker_acknowledged ||
+				auction.assignment_confirmation_blocks.is_some() ||
+				auction.open_ended
+			{
+				auction.top_since = T::BlockNumberProvider::current_block_number();
+				Self::close_dispute(&auction_key, &auction);
+				auction.in_dispute = false;
+				auction.escalated = false;
+				auction.owner_confirmed = false;
+				auction.worker_acknowledged = false;
+				auction.accepted = false;
+			}
+			Auctions::<T>::insert(&auction_key, auction);
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::ExpiredBidWithdrawn { auction_key, bid_key, price },
+			);
+			Ok(Some(Self::settlement_weight(removed)).into())
+		}
+
+		#[pallet::weight(Pallet::<T>::max_settlement_weight())]
+		#[transactional]
+		pub fn confirm(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			// fetch auction
+			let mut auction =
+				Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			// only owner of auction or its operator can confirm
+			Self::ensure_manager(&who, &auction_key, OPERATOR_CONFIRM)?;
+			// fetch to bid
+			let (top_key, top_price) = Bids::<T>::get(&auction_key, BidKey::<T>::default())
+				.ok_or(Error::<T>::AuctionNotAssigned)?;
+			// only assigned auctions can be confirmed
+			ensure!(auction.is_assigned(&top_key.0, top_price), Error::<T>::AuctionNotAssigned);
+			ensure!(!Self::under_review(&auction_key), Error::<T>::AuctionUnderReview);
+			Self::ensure_revealed(&auction_key, &top_key)?;
+			// jointly confirmed auctions wait for the worker to acknowledge completion
+			if auction.joint_confirmation && !auction.worker_acknowledged {
+				auction.owner_confirmed = true;
+				Auctions::<T>::insert(&auction_key, auction);
+				Self::deposit_auction_event(
+					Self::topic(&auction_key),
+					Event::<T>::OwnerConfirmed { auction_key },
+				);
+				return Ok(Some(Self::settlement_weight(0)).into())
+			}
+			let removed = Self::pay_worker(auction_key, auction, top_key, top_price)?;
+			Ok(Some(Self::settlement_weight(removed)).into())
+		}
+
+		#[pallet::weight(Pallet::<T>::max_settlement_weight())]
+		#[transactional]
+		pub fn acknowledge_completion(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+		) -> DispatchResultWithPostInfo {
+			let worker = ensure_signed(origin)?;
+			// fetch auction
+			let mut auction =
+				Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			// fetch to bid
+			let (top_key, top_price) = Bids::<T>::get(&auction_key, BidKey::<T>::default())
+				.ok_or(Error::<T>::AuctionNotAssigned)?;
+			// only assigned worker can acknowledge
+			ensure!(worker == top_key.0, Error::<T>::TopBidRequired);
+			ensure!(auction.is_assigned(&worker, top_price), Error::<T>::AuctionNotAssigned);
+			ensure!(!Self::under_review(&auction_key), Error::<T>::AuctionUnderReview);
+			Self::ensure_revealed(&auction_key, &top_key)?;
+			// settle if owner has already confirmed
+			if !auction.owner_confirmed {
+				auction.worker_acknowledged = true;
+				Auctions::<T>::insert(&auction_key, auction);
+				Self::deposit_auction_event(
+					Self::topic(&auction_key),
+					Event::<T>::WorkerAcknowledged { auction_key },
+				);
+				return Ok(Some(Self::settlement_weight(0)).into())
+			}
+			let removed = Self::pay_worker(auction_key, auction, top_key, top_price)?;
+			Ok(Some(Self::settlement_weight(removed)).into())
+		}
+
+		#[pallet::weight(
+			Pallet::<T>::max_settlement_weight() +
+				Pallet::<T>::consolation_weight(T::MaxBidsPerAuction::get())
+		)]
+		pub fn cancel(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+			expected_penalty: Option<bool>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::do_cancel(who, auction_key, expected_penalty)
+		}
+
+		#[pallet::weight(T::WeightInfo::dispute())]
+		pub fn dispute(origin: OriginFor<T>, auction_key: AuctionKey<T>) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			// fetch auction
+			let mut auction =
+				Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			// auction is already in dispute
+			ensure!(!auction.in_dispute, Error::<T>::AuctionDisputed);
+			// fetch top bid
+			let (BidId(bidder, _), price) = Bids::<T>::get(&auction_key, BidKey::<T>::default())
+				.ok_or(Error::<T>::AuctionNotAssigned)?;
+			// only owner or bidder can dispute
+			ensure!(origin == bidder || origin == auction_key.0, Error::<T>::OriginProhibited);
+			// only assigned auctions can be disputed
+			ensure!(auction.is_assigned(&bidder, price), Error::<T>::AuctionNotAssigned);
+			Self::route_dispute(&auction_key, &mut auction, &origin)?;
+			auction.in_dispute = true;
+			Auctions::<T>::insert(&auction_key, auction);
+			BlockMetrics::<T>::mutate(|metrics| metrics.disputes += 1);
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::Disputed { auction_key },
+			);
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::escalate())]
+		pub fn escalate(origin: OriginFor<T>, auction_key: AuctionKey<T>) -> DispatchResult {
+			let bidder = ensure_signed(origin)?;
+			// fetch auction
+			let mut auction =
+				Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			// auction is already in dispute
+			ensure!(!auction.in_dispute, Error::<T>::AuctionDisputed);
+			// fetch top bid
+			let (BidId(top_bidder, _), price) =
+				Bids::<T>::get(&auction_key, BidKey::<T>::default())
+					.ok_or(Error::<T>::AuctionNotAssigned)?;
+			// only assigned worker can escalate
+			ensure!(bidder == top_bidder, Error::<T>::TopBidRequired);
+			ensure!(auction.is_assigned(&bidder, price), Error::<T>::AuctionNotAssigned);
+			// owner must have been inactive since the deadline, or the acceptance of
+			// open-ended auctions
+			let deadline = match auction.open_ended {
+				true => auction.top_since,
+				false => auction.terminal_block,
+			};
+			ensure!(
+				T::BlockNumberProvider::current_block_number() >=
+					deadline + T::OwnerInactivity::get(),
+				Error::<T>::OwnerInactivityRequired
+			);
+			// arbitrator settles without owner, who pays the arbitration fee
+			Self::route_dispute(&auction_key, &mut auction, &bidder)?;
+			auction.in_dispute = true;
+			auction.escalated = true;
+			Auctions::<T>::insert(&auction_key, auction);
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::Escalated { auction_key },
+			);
+			Ok(())
+		}
+
+		#[pallet::weight(Pallet::<T>::max_settlement_weight())]
+		#[transactional]
+		pub fn arbitrate(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+			fulfilled: bool,
+			verdict_hash: Option<T::Hash>,
+		) -> DispatchResultWithPostInfo {
+			let arbitrator = ensure_signed(origin)?;
+			let auction = Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			// only the arbitrator is allowed
+			ensure!(arbitrator == auction.arbitrator, Error::<T>::OriginProhibited);
+			ensure!(auction.in_dispute, Error::<T>::AuctionNotDisputed);
+			// arbitrator can only rule for the fee locked when the dispute was raised, which is
+			// paid with the other funds movements of the ruling
+			ensure!(
+				DisputeQuotes::<T>::contains_key(&auction_key),
+				Error::<T>::DisputeQuoteRequired
+			);
+			let (disputed_at, category) = (auction.disputed_at, auction.required_capability);
+			let post_info = Self::do_arbitrate(auction_key, auction, fulfilled, verdict_hash)?;
+			// time to rule is counted towards the arbitrator's scorecard
+			let resolution_blocks = disputed_at.map_or(Zero::zero(), |disputed_at| {
+				T::BlockNumberProvider::current_block_number().saturating_sub(disputed_at)
+			});
+			let breached =
+				DisputeSlas::<T>::get(category).map_or(false, |sla| resolution_blocks > sla);
+			ArbitratorStats::<T>::mutate(&arbitrator, |stats| {
+				stats.rulings += 1;
+				stats.resolution_blocks = stats.resolution_blocks.saturating_add(resolution_blocks);
+				stats.sla_breaches += breached as u32;
+			});
+			Ok(post_info)
+		}
+
+		#[pallet::weight(Pallet::<T>::max_settlement_weight())]
+		#[transactional]
+		pub fn force_arbitrate(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+			fulfilled: bool,
+			verdict_hash: Option<T::Hash>,
+		) -> DispatchResultWithPostInfo {
+			T::ArbitrationOrigin::ensure_origin(origin)?;
+			let auction = Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			// arbitrator who didn't rule isn't paid the quoted fee
+			if let Some((payer, fee)) = DisputeQuotes::<T>::take(&auction_key) {
+				T::Escrow::unlock(&payer, fee);
+			}
+			Self::do_arbitrate(auction_key, auction, fulfilled, verdict_hash)
+		}
+
+		// Settle a dispute nobody ruled on within the arbitration period, splitting the price
+		// between worker and owner by the default judgment share. The arbitrator is not paid.
+		#[pallet::weight(Pallet::<T>::max_settlement_weight())]
+		#[transactional]
+		pub fn resolve_default(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let auction = Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			ensure!(auction.in_dispute, Error::<T>::AuctionNotDisputed);
+			let (top_key, top_price) = Bids::<T>::get(&auction_key, BidKey::<T>::default())
+				.ok_or(Error::<T>::AuctionNotAssigned)?;
+			// only owner or worker can ask for a default judgment
+			ensure!(who == auction_key.0 || who == top_key.0, Error::<T>::OriginProhibited);
+			Self::ensure_revealed(&auction_key, &top_key)?;
+			let now = T::BlockNumberProvider::current_block_number();
+			let period = T::ArbitrationPeriod::get();
+			ensure!(
+				!period.is_zero() && now >= auction.disputed_at.unwrap_or(now) + period,
+				Error::<T>::ArbitrationPeriodActive
+			);
+			let removed = Self::settle_by_default(auction_key, auction, top_key, top_price)?;
+			Ok(Some(Self::settlement_weight(removed)).into())
+		}
+
+		#[pallet::weight(T::WeightInfo::localize())]
+		pub fn localize(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+			location: Location,
+			distance_premium_per_unit: BalanceOf<T>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let mut auction =
+				Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			// only owner of auction can localize
+			ensure!(owner == auction_key.0, Error::<T>::OwnerRequired);
+			// location can't change the price of existing bids
+			ensure!(
+				Bids::<T>::get(&auction_key, BidKey::<T>::default()).is_none(),
+				Error::<T>::AuctionHasBids
+			);
+			auction.location = Some(location);
+			auction.distance_premium_per_unit = Some(distance_premium_per_unit);
+			Auctions::<T>::insert(&auction_key, auction);
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::Localized { auction_key, location },
+			);
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::set_settlement_mode())]
+		pub fn set_settlement_mode(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+			settlement_mode: SettlementMode,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let mut auction =
+				Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			// only owner of auction can change settlement mode
+			ensure!(owner == auction_key.0, Error::<T>::OwnerRequired);
+			// bidders must know the settlement mode when bidding
+			ensure!(
+				Bids::<T>::get(&auction_key, BidKey::<T>::default()).is_none(),
+				Error::<T>::AuctionHasBids
+			);
+			auction.settlement_mode = settlement_mode.clone();
+			Auctions::<T>::insert(&auction_key, auction);
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::SettlementModeSet { auction_key, settlement_mode },
+			);
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::require_joint_confirmation())]
+		pub fn require_joint_confirmation(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let mut auction =
+				Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			// only owner of auction can require joint confirmation
+			ensure!(owner == auction_key.0, Error::<T>::OwnerRequired);
+			// bidders must know the confirmation terms when bidding
+			ensure!(
+				Bids::<T>::get(&auction_key, BidKey::<T>::default()).is_none(),
+				Error::<T>::AuctionHasBids
+			);
+			auction.joint_confirmation = true;
+			Auctions::<T>::insert(&auction_key, auction);
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::JointConfirmationRequired { auction_key },
+			);
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::require_capability())]
+		pub fn require_capability(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+			capability: Capability,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let mut auction =
+				Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			// only owner of auction can require a capability
+			ensure!(owner == auction_key.0, Error::<T>::OwnerRequired);
+			// existing bidders were never checked for the capability
+			ensure!(
+				Bids::<T>::get(&auction_key, BidKey::<T>::default()).is_none(),
+				Error::<T>::AuctionHasBids
+			);
+			auction.required_capability = Some(capability);
+			Auctions::<T>::insert(&auction_key, auction);
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::CapabilityRequired { auction_key, capability },
+			);
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::set_min_bid_ratio())]
+		pub fn set_min_bid_ratio(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+			min_bid_ratio: u8,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let mut auction =
+				Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			// only owner of auction can override the ratio
+			ensure!(owner == auction_key.0, Error::<T>::OwnerRequired);
+			// existing bids were validated against the previous ratio
+			ensure!(
+				Bids::<T>::get(&auction_key, BidKey::<T>::default()).is_none(),
+				Error::<T>::AuctionHasBids
+			);
+			// overrides are only allowed within governance set bounds
+			let (lower, upper) =
+				MinBidRatioBounds::<T>::get().ok_or(Error::<T>::MinBidRatioOutOfBounds)?;
+			ensure!((lower..=upper).contains(&min_bid_ratio), Error::<T>::MinBidRatioOutOfBounds);
+			auction.min_bid_ratio = Some(min_bid_ratio);
+			Auctions::<T>::insert(&auction_key, auction);
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::MinBidRatioSet { auction_key, min_bid_ratio },
+			);
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::set_assignment_confirmation())]
+		pub fn set_assignment_confirmation(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+			blocks: Option<T::BlockNumber>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let mut auction =
+				Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			// only owner of auction can change the assignment rule
+			ensure!(owner == auction_key.0, Error::<T>::OwnerRequired);
+			// existing bids may already be assigned under the previous rule
+			ensure!(
+				Bids::<T>::get(&auction_key, BidKey::<T>::default()).is_none(),
+				Error::<T>::AuctionHasBids
+			);
+			auction.assignment_confirmation_blocks = blocks;
+			Auctions::<T>::insert(&auction_key, auction);
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::AssignmentConfirmationSet { auction_key, blocks },
+			);
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::set_min_bid_ratio_bounds())]
+		pub fn set_min_bid_ratio_bounds(
+			origin: OriginFor<T>,
+			lower: u8,
+			upper: u8,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			ensure!(lower <= upper, Error::<T>::MinBidRatioOutOfBounds);
+			MinBidRatioBounds::<T>::put((lower, upper));
+			Self::deposit_event(Event::<T>::MinBidRatioBoundsSet { lower, upper });
+			Ok(())
+		}
+
+		// Resume creation of auctions suspended by the circuit breaker, counting disputes
+		// afresh.
+		#[pallet::weight(T::WeightInfo::clear_circuit_breaker())]
+		pub fn clear_circuit_breaker(origin: OriginFor<T>) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			ensure!(CircuitBreaker::<T>::exists(), Error::<T>::CircuitBreakerNotTripped);
+			CircuitBreaker::<T>::kill();
+			DisputeRate::<T>::kill();
+			Self::deposit_event(Event::<T>::CircuitBreakerCleared);
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::attest())]
+		pub fn attest(
+			origin: OriginFor<T>,
+			worker: T::AccountId,
+			capability: Capability,
+			expiry: T::BlockNumber,
+		) -> DispatchResult {
+			T::AttestationOrigin::ensure_origin(origin)?;
+			Attestations::<T>::insert(&worker, capability, expiry);
+			Self::deposit_event(Event::<T>::Attested { worker, capability, expiry });
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::revoke_attestation())]
+		pub fn revoke_attestation(
+			origin: OriginFor<T>,
+			worker: T::AccountId,
+			capability: Capability,
+		) -> DispatchResult {
+			T::AttestationOrigin::ensure_origin(origin)?;
+			Attestations::<T>::remove(&worker, capability);
+			Self::deposit_event(Event::<T>::AttestationRevoked { worker, capability });
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::register_worker())]
+		pub fn register_worker(origin: OriginFor<T>, location: Option<Location>) -> DispatchResult {
+			let worker = ensure_signed(origin)?;
+			// bids can't be repriced by moving away from the task
+			ensure!(LocationLockCount::<T>::get(&worker) == 0, Error::<T>::WorkerLocationLocked);
+			Workers::<T>::mutate(&worker, |profile| {
+				profile.get_or_insert_with(Default::default).location = location
+			});
+			Self::deposit_event(Event::<T>::WorkerRegistered { worker, location });
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::set_capacity())]
+		pub fn set_capacity(
+			origin: OriginFor<T>,
+			max_concurrent_assignments: Option<u32>,
+		) -> DispatchResult {
+			let worker = ensure_signed(origin)?;
+			Workers::<T>::mutate(&worker, |profile| {
+				profile.get_or_insert_with(Default::default).max_concurrent_assignments =
+					max_concurrent_assignments
+			});
+			Self::deposit_event(Event::<T>::CapacitySet { worker, max_concurrent_assignments });
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::force_transfer_ownership())]
+		#[transactional]
+		pub fn force_transfer_ownership(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+			new_owner: T::AccountId,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let auction = Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			ensure!(new_owner != auction.arbitrator, Error::<T>::SelfArbitrationProhibited);
+			// worker of the auction can't become its owner
+			if let Some((top_key, _)) = Bids::<T>::get(&auction_key, BidKey::<T>::default()) {
+				ensure!(top_key.0 != new_owner, Error::<T>::OriginProhibited);
+			}
+			// auction keeps its nonce under the new owner
+			let new_auction_key = AuctionId(new_owner, auction_key.1);
+			ensure!(!Auctions::<T>::contains_key(&new_auction_key), Error::<T>::AuctionKeyExists);
+			// move reserved bounty and deposit to new owner, except what is held for a dispute
+			let held = DisputeHolds::<T>::get(&auction_key)
+				.map_or(Zero::zero(), |(_, owner_hold, _)| owner_hold);
+			let escrowed = auction.owner_bounty() + auction.deposit - held;
+			if auction.asset.is_some() {
+				T::Escrow::transfer_locked(
+					&auction_key.0,
+					&new_auction_key.0,
+					auction.listing_fee,
+				)?;
+				Self::transfer_locked_in(
+					&auction.asset,
+					&auction_key.0,
+					&new_auction_key.0,
+					escrowed,
+				)?;
+			} else {
+				T::Escrow::transfer_locked(
+					&auction_key.0,
+					&new_auction_key.0,
+					escrowed + auction.listing_fee,
+				)?;
+			}
+			Self::rekey_auction(auction_key, new_auction_key, auction)
+		}
+
+		#[pallet::weight(T::WeightInfo::transfer_auction())]
+		pub fn transfer_auction(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+			new_owner: T::AccountId,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let auction = Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			// only owner of auction can offer it to a new owner
+			ensure!(owner == auction_key.0, Error::<T>::OwnerRequired);
+			ensure!(new_owner != owner, Error::<T>::OriginProhibited);
+			ensure!(new_owner != auction.arbitrator, Error::<T>::SelfArbitrationProhibited);
+			// new owner has to accept before taking over the reserves
+			TransferOffers::<T>::insert(&auction_key, &new_owner);
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::TransferOffered { auction_key, new_owner },
+			);
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::accept_auction_transfer())]
+		#[transactional]
+		pub fn accept_auction_transfer(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+		) -> DispatchResult {
+			let new_owner = ensure_signed(origin)?;
+			let auction = Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			// only the account the auction was offered to can accept
+			ensure!(
+				TransferOffers::<T>::get(&auction_key).as_ref() == Some(&new_owner),
+				Error::<T>::TransferOfferRequired
+			);
+			ensure!(new_owner != auction.arbitrator, Error::<T>::SelfArbitrationProhibited);
+			ensure!(!auction.in_dispute, Error::<T>::AuctionDisputed);
+			// owner cannot be the worker of their own auction
+			if let Some((top_key, _)) = Bids::<T>::get(&auction_key, BidKey::<T>::default()) {
+				ensure!(top_key.0 != new_owner, Error::<T>::OriginProhibited);
+			}
+			let new_auction_key = AuctionId(new_owner, auction_key.1);
+			ensure!(!Auctions::<T>::contains_key(&new_auction_key), Error::<T>::AuctionKeyExists);
+			// new owner reserves the bounty and deposit before the original owner is released
+			let reserve = auction.owner_bounty() + auction.deposit;
+			if auction.asset.is_some() {
+				T::Escrow::lock(&new_auction_key.0, auction.listing_fee)?;
+				if let Err(error) = Self::lock_in(&auction.asset, &new_auction_key.0, reserve) {
+					T::Escrow::unlock(&new_auction_key.0, auction.listing_fee);
+					return Err(error)
+				}
+				T::Escrow::unlock(&auction_key.0, auction.listing_fee);
+				Self::unlock_in(&auction.asset, &auction_key.0, reserve)?;
+			} else {
+				T::Escrow::lock(&new_auction_key.0, reserve + auction.listing_fee)?;
+				T::Escrow::unlock(&auction_key.0, reserve + auction.listing_fee);
+			}
+			Self::rekey_auction(auction_key, new_auction_key, auction)
+		}
+
+		#[pallet::weight(T::WeightInfo::stake_arbitrator())]
+		pub fn stake_arbitrator(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+			let arbitrator = ensure_signed(origin)?;
+			T::Escrow::lock(&arbitrator, amount)?;
+			let stake = ArbitratorStakes::<T>::mutate(&arbitrator, |stake| {
+				*stake += amount;
+				*stake
+			});
+			Self::deposit_event(Event::<T>::ArbitratorStaked { arbitrator, stake });
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::unstake_arbitrator())]
+		pub fn unstake_arbitrator(origin: OriginFor<T>) -> DispatchResult {
+			let arbitrator = ensure_signed(origin)?;
+			// stake stays locked while misconduct reports are pending
+			ensure!(
+				MisconductReports::<T>::iter_prefix(&arbitrator).next().is_none(),
+				Error::<T>::ArbitratorReported
+			);
+			let stake = ArbitratorStakes::<T>::take(&arbitrator);
+			T::Escrow::unlock(&arbitrator, stake);
+			Self::deposit_event(Event::<T>::ArbitratorUnstaked { arbitrator, stake });
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::report_arbitrator())]
+		pub fn report_arbitrator(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+		) -> DispatchResult {
+			let reporter = ensure_signed(origin)?;
+			// only arbitrated auctions can be reported
+			let receipt = Self::receipt(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			ensure!(
+				matches!(receipt.outcome, Outcome::Arbitrated { .. }),
+				Error::<T>::ArbitrationRequired
+			);
+			// only owner or worker can report
+			ensure!(
+				reporter == auction_key.0 || Some(&reporter) == receipt.worker.as_ref(),
+				Error::<T>::OriginProhibited
+			);
+			let arbitrator = receipt.arbitrator;
+			ensure!(
+				!MisconductReports::<T>::contains_key(&arbitrator, &auction_key),
+				Error::<T>::ReportExists
+			);
+			MisconductReports::<T>::insert(&arbitrator, &auction_key, reporter);
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::MisconductReported { arbitrator, auction_key },
+			);
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::resolve_report())]
+		pub fn resolve_report(
+			origin: OriginFor<T>,
+			arbitrator: T::AccountId,
+			auction_key: AuctionKey<T>,
+			slashed: bool,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			let reporter = MisconductReports::<T>::get(&arbitrator, &auction_key)
+				.ok_or(Error::<T>::AuctionKeyNotFound)?;
+			// slashed stake compensates the reporter, paid before the report is resolved
+			if slashed {
+				let stake = ArbitratorStakes::<T>::get(&arbitrator);
+				T::Escrow::transfer_locked(&arbitrator, &reporter, stake)?;
+				T::Escrow::unlock(&reporter, stake);
+				ArbitratorStakes::<T>::remove(&arbitrator);
+			}
+			MisconductReports::<T>::remove(&arbitrator, &auction_key);
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::MisconductResolved { arbitrator, auction_key, slashed },
+			);
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::attach())]
+		pub fn attach(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+			reference: Vec<u8>,
+		) -> DispatchResult {
+			let author = ensure_signed(origin)?;
+			let auction = Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			// only owner or assigned worker can attach
+			let (BidId(bidder, _), price) = Bids::<T>::get(&auction_key, BidKey::<T>::default())
+				.ok_or(Error::<T>::AuctionNotAssigned)?;
+			ensure!(author == bidder || author == auction_key.0, Error::<T>::OriginProhibited);
+			ensure!(auction.is_assigned(&bidder, price), Error::<T>::AuctionNotAssigned);
+			// attachments are bounded in count and size
+			let reference: BoundedVec<u8, T::MaxAttachmentSize> =
+				reference.try_into().map_err(|_| Error::<T>::MaxDataSizeExceeded)?;
+			let mut attachments = Attachments::<T>::get(&auction_key);
+			ensure!(
+				attachments.len() < T::MaxAttachments::get().try_into().unwrap(),
+				Error::<T>::MaxAttachmentsExceeded
+			);
+			// author locks a deposit proportional to size until settlement
+			let reference_len: u32 = reference.len().saturated_into();
+			let deposit = T::AttachmentDepositPerByte::get() * reference_len.into();
+			T::Escrow::lock(&author, deposit)?;
+			attachments
+				.try_push(Attachment::<T> { author: author.clone(), reference, deposit })
+				.map_err(|_| Error::<T>::MaxAttachmentsExceeded)?;
+			Attachments::<T>::insert(&auction_key, attachments);
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::Attached { auction_key, author },
+			);
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::bundle_bid(auction_keys.len() as u32))]
+		pub fn bundle_bid(
+			origin: OriginFor<T>,
+			auction_keys: Vec<AuctionKey<T>>,
+			total_price: BalanceOf<T>,
+		) -> DispatchResult {
+			let bidder = ensure_signed(origin)?;
+			ensure!(!auction_keys.is_empty(), Error::<T>::AuctionKeyNotFound);
+			let auction_keys: BoundedVec<AuctionKey<T>, T::MaxBundleSize> =
+				auction_keys.try_into().map_err(|_| Error::<T>::MaxBundleSizeExceeded)?;
+			// a repeated auction would be priced, escrowed and assigned twice
+			let unique: BTreeSet<_> = auction_keys.iter().collect();
+			ensure!(unique.len() == auction_keys.len(), Error::<T>::DuplicateBundleAuction);
+			ensure!(total_price >= T::MinBidPrice::get(), Error::<T>::BidTooLow);
+			// all auctions must belong to the same owner
+			let owner = auction_keys[0].0.clone();
+			ensure!(!Self::acts_for(&bidder, &owner), Error::<T>::OriginProhibited);
+			ensure!(!Bundles::<T>::contains_key(&owner, &bidder), Error::<T>::BundleExists);
+			let mut bounty: BalanceOf<T> = 0u32.into();
+			let mut deposit: BalanceOf<T> = 0u32.into();
+			for auction_key in auction_keys.iter() {
+				let auction =
+					Auctions::<T>::get(auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+				ensure!(auction_key.0 == owner, Error::<T>::OwnerRequired);
+				ensure!(
+					!Self::acts_for(&bidder, &auction.arbitrator),
+					Error::<T>::OriginProhibited
+				);
+				ensure!(Self::is_capable(&bidder, &auction), Error::<T>::AttestationRequired);
+				if let Some((top_key, top_price)) =
+					Bids::<T>::get(auction_key, BidKey::<T>::default())
+				{
+					ensure!(
+						!auction.is_assigned(&top_key.0, top_price),
+						Error::<T>::AuctionAssigned
+					);
+				}
+				bounty = bounty.checked_add(&auction.bounty).ok_or(ArithmeticError::Overflow)?;
+				deposit = deposit.checked_add(&auction.deposit).ok_or(ArithmeticError::Overflow)?;
+			}
+			// bundle can't cost more than the bounties combined
+			ensure!(total_price <= bounty, Error::<T>::BundlePriceExceedsBounty);
+			// deposits of all auctions are locked once for the whole bundle
+			T::Escrow::lock(&bidder, deposit)?;
+			Bundles::<T>::insert(
+				&owner,
+				&bidder,
+				Bundle::<T> { auction_keys, total_price, deposit },
+			);
+			Self::deposit_event(Event::<T>::BundleBid { owner, bidder, total_price });
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::accept_bundle(T::MaxBundleSize::get()))]
+		#[transactional]
+		pub fn accept_bundle(origin: OriginFor<T>, bidder: T::AccountId) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let bundle = Bundles::<T>::get(&owner, &bidder).ok_or(Error::<T>::BundleNotFound)?;
+			// check all auctions before assigning any of them
+			let mut auctions = Vec::with_capacity(bundle.auction_keys.len());
+			let mut bounty: BalanceOf<T> = 0u32.into();
+			let mut deposit: BalanceOf<T> = 0u32.into();
+			for auction_key in bundle.auction_keys.iter() {
+				let auction =
+					Auctions::<T>::get(auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+				ensure!(auction.paused_at.is_none(), Error::<T>::AuctionPaused);
+				// open-ended auctions are only assigned by accepting their own top bid
+				ensure!(!auction.open_ended, Error::<T>::OpenEndedProhibited);
+				// batched auctions only take the best of the bids staged in a block
+				ensure!(!auction.batched_bids, Error::<T>::BidBatchingEnabled);
+				// bundle deposits and prices are in the currency of the pallet
+				ensure!(auction.asset.is_none(), Error::<T>::NativeAuctionRequired);
+				let top_bid = Bids::<T>::get(auction_key, BidKey::<T>::default());
+				if let Some((top_key, top_price)) = &top_bid {
+					ensure!(
+						!auction.is_assigned(&top_key.0, *top_price),
+						Error::<T>::AuctionAssigned
+					);
+					ensure!(top_key.1 < T::MaxBidsPerAuction::get(), Error::<T>::MaxBidsReached);
+				}
+				bounty = bounty.checked_add(&auction.bounty).ok_or(ArithmeticError::Overflow)?;
+				deposit = deposit.checked_add(&auction.deposit).ok_or(ArithmeticError::Overflow)?;
+				auctions.push((auction_key, auction, top_bid));
+			}
+			// settlement unlocks the deposits of the auctions, which must match the locked one
+			ensure!(deposit == bundle.deposit, Error::<T>::BundleDepositChanged);
+			// each auction is priced at its share of the total price
+			let prices = auctions
+				.iter()
+				.map(|(_, auction, _)| {
+					let product = bundle.total_price.checked_mul(&auction.bounty);
+					product.map(|product| product / bounty).ok_or(ArithmeticError::Overflow)
+				})
+				.collect::<Result<Vec<_>, _>>()?;
+			ensure!(
+				Self::has_capacity(&bidder, auctions.len() as u32),
+				Error::<T>::CapacityExceeded
+			);
+			Bundles::<T>::remove(&owner, &bidder);
+			// assign every auction to the bidder
+			let now = T::BlockNumberProvider::current_block_number();
+			for ((auction_key, mut auction, top_bid), price) in auctions.into_iter().zip(prices) {
+				let prev_key = match top_bid {
+					Some((prev_key, _)) => {
+						// unreserve deposit of displaced bidder
+						if auction.top_up_deadline.take().is_none() {
+							T::Escrow::unlock(&prev_key.0, auction.deposit);
+							Self::vacate(&prev_key.0, auction.deposit)?;
+						}
+						prev_key
+					},
+					_ => BidKey::<T>::default(),
+				};
+				let bid_key = BidId(bidder.clone(), prev_key.1 + 1);
+				Bids::<T>::insert(auction_key, &bid_key, (prev_key, price));
+				Bids::<T>::insert(auction_key, BidKey::<T>::default(), (bid_key.clone(), price));
+				BidVersions::<T>::insert(auction_key, bid_key, auction.version);
+				if auction.location.is_some() {
+					Self::lock_location(&bidder, auction_key)?;
+				}
+				Self::occupy(&bidder, auction.deposit)?;
+				// owner acceptance closes the auction with the share assigned
+				auction.accepted = true;
+				auction.top_since = now;
+				Auctions::<T>::insert(auction_key, auction);
+			}
+			Self::deposit_event(Event::<T>::BundleAccepted { owner, bidder });
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::withdraw_bundle())]
+		pub fn withdraw_bundle(origin: OriginFor<T>, owner: T::AccountId) -> DispatchResult {
+			let bidder = ensure_signed(origin)?;
+			let bundle = Bundles::<T>::take(&owner, &bidder).ok_or(Error::<T>::BundleNotFound)?;
+			T::Escrow::unlock(&bidder, bundle.deposit);
+			Self::deposit_event(Event::<T>::BundleWithdrawn { owner, bidder });
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::pause_bidding())]
+		pub fn pause_bidding(origin: OriginFor<T>, auction_key: AuctionKey<T>) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let mut auction =
+				Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			// only owner of auction can pause
+			ensure!(owner == auction_key.0, Error::<T>::OwnerRequired);
+			ensure!(auction.paused_at.is_none(), Error::<T>::AuctionPaused);
+			// ensure auction is not assigned
+			if let Some((top_key, price)) = Bids::<T>::get(&auction_key, BidKey::<T>::default()) {
+				ensure!(!auction.is_assigned(&top_key.0, price), Error::<T>::AuctionAssigned);
+			}
+			auction.paused_at = Some(T::BlockNumberProvider::current_block_number());
+			Auctions::<T>::insert(&auction_key, auction);
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::Paused { auction_key },
+			);
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::resume_bidding())]
+		pub fn resume_bidding(origin: OriginFor<T>, auction_key: AuctionKey<T>) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let mut auction =
+				Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			// only owner of auction can resume
+			ensure!(owner == auction_key.0, Error::<T>::OwnerRequired);
+			let paused_at = auction.paused_at.take().ok_or(Error::<T>::AuctionNotPaused)?;
+			// shift the base price curve by the paused duration
+			let paused_for = T::BlockNumberProvider::current_block_number() - paused_at;
+			auction.initial_block += paused_for;
+			auction.terminal_block += paused_for;
+			let terminal_block = auction.terminal_block;
+			Auctions::<T>::insert(&auction_key, auction);
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::Resumed { auction_key, terminal_block },
+			);
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::claim())]
+		pub fn claim(origin: OriginFor<T>, payer: T::AccountId) -> DispatchResult {
+			let recipient = ensure_signed(origin)?;
+			ensure!(
+				PendingPayouts::<T>::contains_key(&recipient, &payer),
+				Error::<T>::PayoutNotFound
+			);
+			Self::release_payout(&recipient, &payer)
+		}
+
+		#[pallet::weight(T::WeightInfo::rate())]
+		pub fn rate(origin: OriginFor<T>, auction_key: AuctionKey<T>, score: u8) -> DispatchResult {
+			let rater = ensure_signed(origin)?;
+			ensure!((1..=5).contains(&score), Error::<T>::RatingOutOfRange);
+			let mut receipt = Self::receipt(&auction_key).ok_or(Error::<T>::ReceiptNotFound)?;
+			// only confirmed auctions are rated
+			ensure!(receipt.outcome == Outcome::Confirmed, Error::<T>::ReceiptNotFound);
+			ensure!(
+				T::BlockNumberProvider::current_block_number() <=
+					receipt.settled_block + T::RatingPeriod::get(),
+				Error::<T>::RatingPeriodExpired
+			);
+			let worker = receipt.worker.clone().ok_or(Error::<T>::ReceiptNotFound)?;
+			// owner rates the worker and the worker rates the owner, once each
+			let (rating, ratee) = if rater == auction_key.0 {
+				(&mut receipt.owner_rating, worker)
+			} else if rater == worker {
+				(&mut receipt.worker_rating, auction_key.0.clone())
+			} else {
+				return Err(Error::<T>::OriginProhibited.into())
+			};
+			ensure!(rating.is_none(), Error::<T>::AlreadyRated);
+			*rating = Some(score);
+			child::put(&Self::receipt_child_info(&auction_key), b"receipt", &receipt);
+			Ratings::<T>::mutate(&ratee, |summary| {
+				summary.sum = summary.sum.saturating_add(score.into());
+				summary.count = summary.count.saturating_add(1);
+			});
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::Rated { auction_key, rater, ratee, score },
+			);
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::appeal())]
+		pub fn appeal(origin: OriginFor<T>, auction_key: AuctionKey<T>) -> DispatchResult {
+			let appellant = ensure_signed(origin)?;
+			let mut escrow = ArbitrationEscrows::<T>::get(&auction_key)
+				.ok_or(Error::<T>::ArbitrationEscrowNotFound)?;
+			// only the side that lost the ruling can appeal
+			ensure!(appellant == escrow.payer, Error::<T>::OriginProhibited);
+			ensure!(!escrow.appealed, Error::<T>::AppealExists);
+			ensure!(
+				T::BlockNumberProvider::current_block_number() <= escrow.appeal_deadline,
+				Error::<T>::AppealPeriodExpired
+			);
+			escrow.appealed = true;
+			ArbitratorStats::<T>::mutate(&escrow.arbitrator, |stats| stats.appealed += 1);
+			ArbitrationEscrows::<T>::insert(&auction_key, escrow);
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::Appealed { auction_key, appellant },
+			);
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::resolve_appeal())]
+		#[transactional]
+		pub fn resolve_appeal(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+			overturned: bool,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			let escrow = ArbitrationEscrows::<T>::get(&auction_key)
+				.ok_or(Error::<T>::ArbitrationEscrowNotFound)?;
+			ensure!(escrow.appealed, Error::<T>::AppealRequired);
+			ArbitrationEscrows::<T>::remove(&auction_key);
+			// arbitrator forfeits only the escrowed fee of an overturned ruling, never their stake
+			Self::unlock_in(&escrow.asset, &escrow.payer, escrow.amount)?;
+			if overturned {
+				ArbitratorStats::<T>::mutate(&escrow.arbitrator, |stats| stats.overturned += 1);
+			} else {
+				Self::pay_in(&escrow.asset, &escrow.payer, &escrow.arbitrator, escrow.amount)?;
+				Self::record_arbitration_fee(&escrow.payer, &escrow.arbitrator, escrow.amount);
+			}
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::AppealResolved { auction_key, overturned },
+			);
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::release_arbitration_fee())]
+		#[transactional]
+		pub fn release_arbitration_fee(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let escrow = ArbitrationEscrows::<T>::get(&auction_key)
+				.ok_or(Error::<T>::ArbitrationEscrowNotFound)?;
+			// appealed fees wait for the appeal to be resolved
+			ensure!(
+				!escrow.appealed &&
+					T::BlockNumberProvider::current_block_number() > escrow.appeal_deadline,
+				Error::<T>::AppealPeriodActive
+			);
+			ArbitrationEscrows::<T>::remove(&auction_key);
+			Self::unlock_in(&escrow.asset, &escrow.payer, escrow.amount)?;
+			Self::pay_in(&escrow.asset, &escrow.payer, &escrow.arbitrator, escrow.amount)?;
+			Self::record_arbitration_fee(&escrow.payer, &escrow.arbitrator, escrow.amount);
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::ArbitrationFeeReleased { auction_key, arbitrator: escrow.arbitrator },
+			);
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::publish_template(name.len() as u32, data.len() as u32))]
+		pub fn publish_template(
+			origin: OriginFor<T>,
+			name: Vec<u8>,
+			bounty: BalanceOf<T>,
+			deposit: BalanceOf<T>,
+			duration: T::BlockNumber,
+			data: Vec<u8>,
+		) -> DispatchResult {
+			let author = ensure_signed(origin)?;
+			Self::do_publish_template(Template::<T> {
+				author,
+				name: name.try_into().map_err(|_| Error::<T>::MaxTemplateNameLengthExceeded)?,
+				bounty,
+				deposit,
+				duration,
+				data: data.try_into().map_err(|_| Error::<T>::MaxDataSizeExceeded)?,
+				template_deposit: T::TemplateDeposit::get(),
+			})
+		}
+
+		#[pallet::weight(T::WeightInfo::fork_template(name.len() as u32))]
+		pub fn fork_template(
+			origin: OriginFor<T>,
+			template_id: u32,
+			name: Vec<u8>,
+			overrides: TemplateOverrides<BalanceOf<T>, T::BlockNumber>,
+		) -> DispatchResult {
+			let author = ensure_signed(origin)?;
+			let template = Templates::<T>::get(template_id).ok_or(Error::<T>::TemplateNotFound)?;
+			Self::do_publish_template(Template::<T> {
+				author,
+				name: name.try_into().map_err(|_| Error::<T>::MaxTemplateNameLengthExceeded)?,
+				bounty: overrides.bounty.unwrap_or(template.bounty),
+				deposit: overrides.deposit.unwrap_or(template.deposit),
+				duration: overrides.duration.unwrap_or(template.duration),
+				data: template.data,
+				template_deposit: T::TemplateDeposit::get(),
+			})
+		}
+
+		#[pallet::weight(T::WeightInfo::unpublish_template())]
+		pub fn unpublish_template(origin: OriginFor<T>, template_id: u32) -> DispatchResult {
+			let author = ensure_signed(origin)?;
+			let template = Templates::<T>::get(template_id).ok_or(Error::<T>::TemplateNotFound)?;
+			ensure!(author == template.author, Error::<T>::OriginProhibited);
+			Templates::<T>::remove(template_id);
+			TemplateCount::<T>::mutate(|count| *count -= 1);
+			T::Escrow::unlock(&author, template.template_deposit);
+			Self::deposit_event(Event::<T>::TemplateUnpublished { template_id });
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::propose_release())]
+		pub fn propose_release(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+			penalty: BalanceOf<T>,
+		) -> DispatchResult {
+			let worker = ensure_signed(origin)?;
+			let auction = Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			let (top_key, top_price) = Bids::<T>::get(&auction_key, BidKey::<T>::default())
+				.ok_or(Error::<T>::AuctionNotAssigned)?;
+			// only the assigned worker can ask to be released
+			ensure!(worker == top_key.0, Error::<T>::TopBidRequired);
+			ensure!(auction.is_assigned(&worker, top_price), Error::<T>::AuctionNotAssigned);
+			ensure!(!auction.in_dispute, Error::<T>::AuctionDisputed);
+			// penalty is paid out of the worker's deposit
+			ensure!(penalty <= auction.deposit, Error::<T>::PenaltyExceedsDeposit);
+			ReleaseProposals::<T>::insert(&auction_key, (worker.clone(), penalty));
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::ReleaseProposed { auction_key, worker, penalty },
+			);
+			Ok(())
+		}
+
+		#[pallet::weight(Pallet::<T>::max_settlement_weight())]
+		#[transactional]
+		pub fn accept_release(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+		) -> DispatchResultWithPostInfo {
+			let owner = ensure_signed(origin)?;
+			let mut auction =
+				Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			ensure!(owner == auction_key.0, Error::<T>::OwnerRequired);
+			let (worker, penalty) = ReleaseProposals::<T>::get(&auction_key)
+				.ok_or(Error::<T>::ReleaseProposalNotFound)?;
+			// proposal lapses once the worker no longer holds the assignment
+			let top_bid = Bids::<T>::get(&auction_key, BidKey::<T>::default());
+			ensure!(
+				top_bid.map_or(false, |(top_key, _)| top_key.0 == worker),
+				Error::<T>::ReleaseProposalNotFound
+			);
+			ensure!(!auction.in_dispute, Error::<T>::AuctionDisputed);
+			// worker pays the agreed penalty and the rest of the deposit is returned
+			let removed = Self::reopen(&auction_key, &mut auction, &worker, penalty)?;
+			Auctions::<T>::insert(&auction_key, auction);
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::Released { auction_key, worker, penalty },
+			);
+			Ok(Some(Self::settlement_weight(removed)).into())
+		}
+
+		#[pallet::weight(Pallet::<T>::max_settlement_weight())]
+		#[transactional]
+		pub fn flag_abandoned(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let mut auction =
+				Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			let (top_key, top_price) = Bids::<T>::get(&auction_key, BidKey::<T>::default())
+				.ok_or(Error::<T>::AuctionNotAssigned)?;
+			let worker = top_key.0;
+			ensure!(auction.is_assigned(&worker, top_price), Error::<T>::AuctionNotAssigned);
+			ensure!(!auction.in_dispute, Error::<T>::AuctionDisputed);
+			// worker who acknowledged or attached progress hasn't abandoned the task
+			let assigned_at = match auction.accepted {
+				// accepted top bids are assigned when accepted, whatever the terminal block
+				true => auction.top_since,
+				false => auction.terminal_block.max(auction.top_since),
+			};
+			ensure!(
+				!auction.worker_acknowledged &&
+					Attachments::<T>::get(&auction_key)
+						.iter()
+						.all(|attachment| attachment.author != worker) &&
+					T::BlockNumberProvider::current_block_number() >=
+						assigned_at + T::AbandonmentWindow::get(),
+				Error::<T>::AbandonmentWindowActive
+			);
+			let penalty = T::AbandonmentPenalty::get() * auction.deposit;
+			let removed = Self::reopen(&auction_key, &mut auction, &worker, penalty)?;
+			Auctions::<T>::insert(&auction_key, auction);
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::Abandoned { auction_key, worker, penalty },
+			);
+			Ok(Some(Self::settlement_weight(removed)).into())
+		}
+
+		#[pallet::weight(T::WeightInfo::set_completion_deadline())]
+		pub fn set_completion_deadline(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+			deadline: T::BlockNumber,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let mut auction =
+				Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			// only owner of auction can set the deadline
+			ensure!(owner == auction_key.0, Error::<T>::OwnerRequired);
+			// bidders must know the deadline before committing
+			ensure!(
+				Bids::<T>::get(&auction_key, BidKey::<T>::default()).is_none(),
+				Error::<T>::AuctionHasBids
+			);
+			ensure!(deadline > auction.terminal_block, Error::<T>::InvalidCompletionDeadline);
+			Self::schedule_deadline(&auction_key, &mut auction, deadline)?;
+			Auctions::<T>::insert(&auction_key, auction);
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::CompletionDeadlineSet { auction_key, deadline },
+			);
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::propose_extension())]
+		pub fn propose_extension(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+			deadline: T::BlockNumber,
+		) -> DispatchResult {
+			let proposer = ensure_signed(origin)?;
+			let auction = Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			let (top_key, top_price) = Bids::<T>::get(&auction_key, BidKey::<T>::default())
+				.ok_or(Error::<T>::AuctionNotAssigned)?;
+			// only the owner and the assigned worker can agree on a new deadline
+			ensure!(
+				proposer == auction_key.0 || proposer == top_key.0,
+				Error::<T>::OriginProhibited
+			);
+			ensure!(auction.is_assigned(&top_key.0, top_price), Error::<T>::AuctionNotAssigned);
+			ensure!(!auction.in_dispute, Error::<T>::AuctionDisputed);
+			// deadlines can only be moved later
+			let now = T::BlockNumberProvider::current_block_number();
+			ensure!(
+				deadline > now && auction.completion_deadline.map_or(true, |prev| deadline > prev),
+				Error::<T>::InvalidCompletionDeadline
+			);
+			ExtensionProposals::<T>::insert(&auction_key, (proposer.clone(), deadline));
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::ExtensionProposed { auction_key, proposer, deadline },
+			);
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::accept_extension())]
+		pub fn accept_extension(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut auction =
+				Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			let (proposer, deadline) = ExtensionProposals::<T>::get(&auction_key)
+				.ok_or(Error::<T>::ExtensionProposalNotFound)?;
+			// proposal lapses once the assignment changes hands
+			let (top_key, top_price) = Bids::<T>::get(&auction_key, BidKey::<T>::default())
+				.ok_or(Error::<T>::ExtensionProposalNotFound)?;
+			let worker = top_key.0;
+			ensure!(
+				auction.is_assigned(&worker, top_price) &&
+					(proposer == auction_key.0 || proposer == worker),
+				Error::<T>::ExtensionProposalNotFound
+			);
+			// the other party of the assignment accepts
+			let counterparty = if proposer == worker { &auction_key.0 } else { &worker };
+			ensure!(who == *counterparty, Error::<T>::OriginProhibited);
+			ensure!(!auction.in_dispute, Error::<T>::AuctionDisputed);
+			ensure!(
+				deadline > T::BlockNumberProvider::current_block_number(),
+				Error::<T>::InvalidCompletionDeadline
+			);
+			Self::schedule_deadline(&auction_key, &mut auction, deadline)?;
+			ExtensionProposals::<T>::remove(&auction_key);
+			Auctions::<T>::insert(&auction_key, auction);
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::ExtensionAgreed { auction_key, deadline },
+			);
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::promote())]
+		pub fn promote(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+			stake: BalanceOf<T>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			ensure!(Auctions::<T>::contains_key(&auction_key), Error::<T>::AuctionKeyNotFound);
+			// only owner of auction can promote it
+			ensure!(owner == auction_key.0, Error::<T>::OwnerRequired);
+			let mut promoted = PromotedAuctions::<T>::get();
+			ensure!(
+				promoted.iter().all(|(key, _)| *key != auction_key),
+				Error::<T>::AlreadyPromoted
+			);
+			// a full list only admits stakes above the lowest one
+			if promoted.len() >= T::MaxPromoted::get() as usize {
+				ensure!(
+					promoted.last().map_or(false, |(_, lowest)| stake > *lowest),
+					Error::<T>::PromotionStakeTooLow
+				);
+			}
+			T::Escrow::lock(&owner, stake)?;
+			// lowest promotion is evicted and refunded
+			if promoted.len() >= T::MaxPromoted::get() as usize {
+				let (evicted_key, evicted_stake) = promoted.remove(promoted.len() - 1);
+				T::Escrow::unlock(&evicted_key.0, evicted_stake);
+				Self::deposit_auction_event(
+					Self::topic(&evicted_key),
+					Event::<T>::PromotionEnded { auction_key: evicted_key },
+				);
+			}
+			// earlier promotions rank first among equal stakes
+			let index = promoted.partition_point(|(_, other)| *other >= stake);
+			promoted
+				.try_insert(index, (auction_key.clone(), stake))
+				.map_err(|_| Error::<T>::PromotionStakeTooLow)?;
+			PromotedAuctions::<T>::put(promoted);
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::Promoted { auction_key, stake },
+			);
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::set_vesting_period())]
+		pub fn set_vesting_period(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+			vesting_period: Option<T::BlockNumber>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let mut auction =
+				Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			ensure!(owner == auction_key.0, Error::<T>::OwnerRequired);
+			// bidders must know how they get paid before committing
+			ensure!(
+				Bids::<T>::get(&auction_key, BidKey::<T>::default()).is_none(),
+				Error::<T>::AuctionHasBids
+			);
+			ensure!(vesting_period != Some(Zero::zero()), Error::<T>::InvalidVestingPeriod);
+			auction.vesting_period = vesting_period;
+			Auctions::<T>::insert(&auction_key, auction);
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::VestingPeriodSet { auction_key, vesting_period },
+			);
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::accept_bid())]
+		pub fn accept_bid(origin: OriginFor<T>, auction_key: AuctionKey<T>) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let mut auction =
+				Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			// only owner of auction can accept
+			ensure!(owner == auction_key.0, Error::<T>::OwnerRequired);
+			ensure!(auction.open_ended, Error::<T>::OpenEndedRequired);
+			ensure!(!auction.accepted, Error::<T>::AuctionAssigned);
+			ensure!(auction.paused_at.is_none(), Error::<T>::AuctionPaused);
+			let (bid_key, price) = Bids::<T>::get(&auction_key, BidKey::<T>::default())
+				.ok_or(Error::<T>::TopBidRequired)?;
+			// owner only escrowed the bounty
+			ensure!(auction.to_settlement(price) <= auction.bounty, Error::<T>::BidExceedsBounty);
+			// owner inactivity is counted from acceptance
+			auction.accepted = true;
+			auction.top_since = T::BlockNumberProvider::current_block_number();
+			Auctions::<T>::insert(&auction_key, auction);
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::BidAccepted { auction_key, bid_key, price },
+			);
+			Ok(())
+		}
+
+		#[pallet::weight(Pallet::<T>::max_settlement_weight())]
+		#[transactional]
+		pub fn expire_open_auction(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let auction = Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			ensure!(auction.open_ended, Error::<T>::OpenEndedRequired);
+			ensure!(!auction.accepted, Error::<T>::AuctionAssigned);
+			ensure!(
+				T::BlockNumberProvider::current_block_number() >=
+					auction.top_since + T::OpenAuctionTimeout::get(),
+				Error::<T>::OpenAuctionActive
+			);
+			// abandoned auction refunds everyone, the owner still pays the listing fee
+			let owner = &auction_key.0;
+			if let Some((BidId(bidder, _), _)) =
+				Bids::<T>::get(&auction_key, BidKey::<T>::default())
+					.filter(|_| auction.top_funded())
+			{
+				Self::unlock_in(&auction.asset, &bidder, auction.deposit)?;
+			}
+			Self::unlock_in(&auction.asset, owner, auction.deposit + auction.owner_bounty())?;
+			Self::release_boosts(&auction_key, &auction, None)?;
+			Self::charge_listing_fee(owner, &auction, 0u32.into())?;
+			let removed = Self::settle(&auction_key, None, auction.bounty, Outcome::Cancelled);
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::OpenAuctionExpired { auction_key },
+			);
+			Ok(Some(Self::settlement_weight(removed)).into())
+		}
+
+		#[pallet::weight(T::WeightInfo::set_dispute_sla())]
+		pub fn set_dispute_sla(
+			origin: OriginFor<T>,
+			category: Option<Capability>,
+			sla: Option<T::BlockNumber>,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			DisputeSlas::<T>::mutate_exists(category, |current| *current = sla);
+			Self::deposit_event(Event::<T>::DisputeSlaSet { category, sla });
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::set_arbitrator_fee())]
+		pub fn set_arbitrator_fee(origin: OriginFor<T>, fee: BalanceOf<T>) -> DispatchResult {
+			let arbitrator = ensure_signed(origin)?;
+			ensure!(T::ArbitratorSet::contains(&arbitrator), Error::<T>::ArbitratorNotApproved);
+			// disputes already raised keep the fee quoted to them
+			ArbitratorFees::<T>::mutate_exists(&arbitrator, |current| {
+				*current = Some(fee).filter(|fee| !fee.is_zero())
+			});
+			Self::deposit_event(Event::<T>::ArbitratorFeeSet { arbitrator, fee });
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::flag_collusion())]
+		pub fn flag_collusion(origin: OriginFor<T>, auction_key: AuctionKey<T>) -> DispatchResult {
+			let auction = Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			// the registered arbitrator of the auction or the force origin can flag it
+			if let Err(origin) = T::ForceOrigin::try_origin(origin) {
+				let arbitrator = ensure_signed(origin)?;
+				ensure!(
+					arbitrator == auction.arbitrator && T::ArbitratorSet::contains(&arbitrator),
+					Error::<T>::OriginProhibited
+				);
+			}
+			// an auction is reviewed at most once, so confirmation can't be frozen indefinitely
+			ensure!(!CollusionReviews::<T>::contains_key(&auction_key), Error::<T>::ReviewExists);
+			let until =
+				T::BlockNumberProvider::current_block_number() + T::CollusionReviewPeriod::get();
+			CollusionReviews::<T>::insert(&auction_key, Some(until));
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::CollusionFlagged { auction_key, until },
+			);
+			Ok(())
+		}
+
+		#[pallet::weight(Pallet::<T>::max_settlement_weight())]
+		#[transactional]
+		pub fn resolve_collusion_review(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+			collusion: bool,
+		) -> DispatchResultWithPostInfo {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			let auction = Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			ensure!(
+				CollusionReviews::<T>::get(&auction_key).flatten().is_some(),
+				Error::<T>::ReviewNotFound
+			);
+			let removed = if collusion {
+				// colluding parties get their funds back without the bounty changing hands
+				Self::release_dispute(&auction_key)?;
+				let owner = &auction_key.0;
+				if let Some((BidId(bidder, _), _)) =
+					Bids::<T>::get(&auction_key, BidKey::<T>::default())
+						.filter(|_| auction.top_funded())
+				{
+					Self::unlock_in(&auction.asset, &bidder, auction.deposit)?;
+				}
+				Self::unlock_in(&auction.asset, owner, auction.deposit + auction.owner_bounty())?;
+				Self::release_boosts(&auction_key, &auction, None)?;
+				Self::charge_listing_fee(owner, &auction, 0u32.into())?;
+				Self::settle(&auction_key, None, auction.bounty, Outcome::Cancelled)
+			} else {
+				// cleared auctions keep their entry so they can't be flagged again
+				CollusionReviews::<T>::insert(&auction_key, None::<T::BlockNumber>);
+				0
+			};
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::CollusionReviewResolved { auction_key, collusion },
+			);
+			Ok(Some(Self::settlement_weight(removed)).into())
+		}
+
+		#[pallet::weight(T::WeightInfo::boost_bounty())]
+		pub fn boost_bounty(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let booster = ensure_signed(origin)?;
+			let mut auction =
+				Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			// owner raises their own bounty by extending the auction
+			ensure!(booster != auction_key.0, Error::<T>::OriginProhibited);
+			ensure!(!amount.is_zero(), Error::<T>::BountyIncrementRequired);
+			// boosts only raise the price while the auction is unassigned
+			if let Some((top_key, price)) = Bids::<T>::get(&auction_key, BidKey::<T>::default()) {
+				ensure!(!auction.is_assigned(&top_key.0, price), Error::<T>::AuctionAssigned);
+			}
+			// contributors are bounded so that settlement can refund all of them
+			let new_booster = !Boosts::<T>::contains_key(&auction_key, &booster);
+			ensure!(
+				!new_booster || auction.boosters < T::MaxBoostersPerAuction::get(),
+				Error::<T>::MaxBoostersReached
+			);
+			// boosts are paid in the currency of the pallet
+			ensure!(auction.asset.is_none(), Error::<T>::NativeAuctionRequired);
+			let bounty = auction.bounty.checked_add(&amount).ok_or(ArithmeticError::Overflow)?;
+			ensure!(bounty <= T::MaxBounty::get(), Error::<T>::MaxBountyExceeded);
+			T::Escrow::lock(&booster, amount)?;
+			Boosts::<T>::mutate(&auction_key, &booster, |boost| *boost += amount);
+			auction.boosters += new_booster as u32;
+			auction.bounty = bounty;
+			auction.boosted += amount;
+			auction.version += 1;
+			Auctions::<T>::insert(&auction_key, auction);
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::BountyBoosted { auction_key, booster, amount, bounty },
+			);
+			Ok(())
+		}
+
+		#[pallet::weight(Pallet::<T>::redeem_quote_weight())]
+		pub fn redeem_quote(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+			quote: SignedQuoteOf<T>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			// only owner of auction can execute quotes given to them
+			ensure!(owner == auction_key.0, Error::<T>::OwnerRequired);
+			let SignedQuote { quote, signature } = quote;
+			ensure!(
+				T::BlockNumberProvider::current_block_number() <= quote.expiry,
+				Error::<T>::QuoteExpired
+			);
+			// quote is bound to the auction so it can't be replayed on another one
+			ensure!(
+				signature.verify(&(&auction_key, &quote).encode()[..], &quote.worker),
+				Error::<T>::InvalidQuoteSignature
+			);
+			// and is consumed once redeemed so it can't be replayed on the same one either
+			let quote_hash = T::Hashing::hash_of(&(&auction_key, &quote));
+			ensure!(
+				!RedeemedQuotes::<T>::contains_key(&auction_key, &quote_hash),
+				Error::<T>::QuoteAlreadyRedeemed
+			);
+			let auction = Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			// owner only escrowed the bounty
+			ensure!(
+				!auction.open_ended || auction.to_settlement(quote.price) <= auction.bounty,
+				Error::<T>::BidExceedsBounty
+			);
+			let bid_key = Self::do_bid(quote.worker, auction_key.clone(), quote.price)?;
+			RedeemedQuotes::<T>::insert(&auction_key, quote_hash, ());
+			// owner executing the quote of an open-ended auction accepts it at once
+			if auction.open_ended {
+				Auctions::<T>::mutate(&auction_key, |auction| {
+					if let Some(auction) = auction {
+						auction.accepted = true;
+						auction.top_since = T::BlockNumberProvider::current_block_number();
+					}
+				});
+			}
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::QuoteRedeemed { auction_key, bid_key, price: quote.price },
+			);
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::queue_retract())]
+		pub fn queue_retract(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+			expected_assigned: Option<bool>,
+		) -> DispatchResult {
+			let bidder = ensure_signed(origin)?;
+			ensure!(Auctions::<T>::contains_key(&auction_key), Error::<T>::AuctionKeyNotFound);
+			// only the top bid can be retracted
+			let (top_key, _) = Bids::<T>::get(&auction_key, BidKey::<T>::default())
+				.ok_or(Error::<T>::TopBidRequired)?;
+			ensure!(bidder == top_key.0, Error::<T>::TopBidRequired);
+			// retracted once spare weight is left at the end of a block
+			QueuedRetractions::<T>::insert(&auction_key, &bidder, expected_assigned);
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::RetractQueued { auction_key, bidder },
+			);
+			Ok(())
+		}
+
+		// Stage bids of the auction until the end of the block, so that competing bidders of a
+		// block pay for a single committed bid instead of underbidding each other.
+		#[pallet::weight(T::WeightInfo::set_bid_batching())]
+		pub fn set_bid_batching(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+			enabled: bool,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let mut auction =
+				Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			ensure!(owner == auction_key.0, Error::<T>::OwnerRequired);
+			auction.batched_bids = enabled;
+			Auctions::<T>::insert(&auction_key, auction);
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::BidBatchingSet { auction_key, enabled },
+			);
+			Ok(())
+		}
+
+		// Send the settlement receipt of an auction created over XCM back to the owner's chain,
+		// usually dispatched in the same message as the creation.
+		#[pallet::weight(T::WeightInfo::request_settlement_receipt())]
+		pub fn request_settlement_receipt(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+		) -> DispatchResult {
+			let (owner, destination) = T::RemoteOrigin::ensure_origin(origin)?;
+			ensure!(Auctions::<T>::contains_key(&auction_key), Error::<T>::AuctionKeyNotFound);
+			ensure!(owner == auction_key.0, Error::<T>::OwnerRequired);
+			ReceiptDestinations::<T>::insert(&auction_key, destination.clone());
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::SettlementReceiptRequested { auction_key, destination },
+			);
+			Ok(())
+		}
+
+		// Bid only if the auction asks for the deliverable the bidder can provide.
+		#[pallet::weight(T::WeightInfo::bid_with_deliverable())]
+		pub fn bid_with_deliverable(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+			price: BalanceOf<T>,
+			deliverable: Deliverable,
+		) -> DispatchResult {
+			let bidder = ensure_signed(origin)?;
+			let requirements =
+				Requirements::<T>::get(&auction_key).ok_or(Error::<T>::DeliverableMismatch)?;
+			ensure!(requirements.deliverable == deliverable, Error::<T>::DeliverableMismatch);
+			Self::do_bid(bidder, auction_key, price)?;
+			Ok(())
+		}
+
+		// Promise the worker a share of the difference between bounty and price, so that
+		// bidders can bid aggressively without giving up all of the savings.
+		#[pallet::weight(T::WeightInfo::set_savings_share())]
+		pub fn set_savings_share(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+			savings_share: Permill,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let mut auction =
+				Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			ensure!(owner == auction_key.0, Error::<T>::OwnerRequired);
+			// bidders must know the share when bidding
+			ensure!(
+				Bids::<T>::get(&auction_key, BidKey::<T>::default()).is_none(),
+				Error::<T>::AuctionHasBids
+			);
+			auction.savings_share = savings_share;
+			Auctions::<T>::insert(&auction_key, auction);
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::SavingsShareSet { auction_key, savings_share },
+			);
+			Ok(())
+		}
+
+		// Counter-bid automatically by decrement whenever outbid, down to the floor price.
+		// A zero decrement removes the strategy.
+		#[pallet::weight(T::WeightInfo::set_auto_bid())]
+		pub fn set_auto_bid(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+			floor: BalanceOf<T>,
+			decrement: BalanceOf<T>,
+		) -> DispatchResult {
+			let bidder = ensure_signed(origin)?;
+			ensure!(Auctions::<T>::contains_key(&auction_key), Error::<T>::AuctionKeyNotFound);
+			ensure!(floor >= T::MinBidPrice::get(), Error::<T>::BidTooLow);
+			if decrement.is_zero() {
+				AutoBids::<T>::remove(&auction_key, &bidder);
+			} else {
+				AutoBids::<T>::insert(&auction_key, &bidder, (floor, decrement));
+			}
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::AutoBidSet { auction_key, bidder, floor, decrement },
+			);
+			Ok(())
+		}
+
+		// Permit an operator to dispatch calls of the owner for an auction, or all auctions of
+		// the owner when no auction is given. Funds are still sourced from the owner, and no
+		// permissions remove the operator.
+		#[pallet::weight(T::WeightInfo::set_operator())]
+		pub fn set_operator(
+			origin: OriginFor<T>,
+			auction_key: Option<AuctionKey<T>>,
+			operator: T::AccountId,
+			permissions: u8,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			if let Some(auction_key) = &auction_key {
+				ensure!(Auctions::<T>::contains_key(auction_key), Error::<T>::AuctionKeyNotFound);
+				ensure!(owner == auction_key.0, Error::<T>::OwnerRequired);
+			}
+			let scope = (operator.clone(), auction_key.clone());
+			if permissions == 0 {
+				Operators::<T>::remove(&owner, scope);
+			} else {
+				Operators::<T>::insert(&owner, scope, permissions);
+			}
+			Self::deposit_event(Event::<T>::OperatorSet {
+				owner,
+				operator,
+				auction_key,
+				permissions,
+			});
+			Ok(())
+		}
+
+		// Reserve the deposit of a top bid promoted by a retraction while the bidder was short
+		// of it, before the top-up period ends.
+		#[pallet::weight(T::WeightInfo::top_up())]
+		#[transactional]
+		pub fn top_up(origin: OriginFor<T>, auction_key: AuctionKey<T>) -> DispatchResult {
+			let bidder = ensure_signed(origin)?;
+			let mut auction =
+				Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			let (bid_key, _) = Bids::<T>::get(&auction_key, BidKey::<T>::default())
+				.ok_or(Error::<T>::TopBidRequired)?;
+			ensure!(bidder == bid_key.0, Error::<T>::TopBidRequired);
+			ensure!(!auction.top_funded(), Error::<T>::TopUpNotRequired);
+			Self::fund_top(&auction.asset, &bidder, auction.deposit)?;
+			auction.top_up_deadline = None;
+			Auctions::<T>::insert(&auction_key, auction);
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::ToppedUp { auction_key, bid_key },
+			);
+			Ok(())
+		}
+
+		// Register a project fixing the arbitrator, bidder deposit and duration of the auctions
+		// listed under it, holding a deposit from the owner.
+		#[pallet::weight(T::WeightInfo::create_project(name.len() as u32))]
+		pub fn create_project(
+			origin: OriginFor<T>,
+			name: Vec<u8>,
+			arbitrator: T::AccountId,
+			deposit: BalanceOf<T>,
+			duration: T::BlockNumber,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let name: BoundedVec<u8, T::MaxProjectNameLength> =
+				name.try_into().map_err(|_| Error::<T>::MaxProjectNameLengthExceeded)?;
+			ensure!(deposit >= T::MinDeposit::get(), Error::<T>::MinDepositRequired);
+			ensure!(
+				ProjectCount::<T>::get() < T::MaxProjects::get(),
+				Error::<T>::MaxProjectsReached
+			);
+			T::Escrow::lock(&owner, T::ProjectDeposit::get())?;
+			let project_id = NextProjectId::<T>::mutate(|id| {
+				*id += 1;
+				*id - 1
+			});
+			ProjectCount::<T>::mutate(|count| *count += 1);
+			Projects::<T>::insert(
+				project_id,
+				Project::<T> {
+					owner: owner.clone(),
+					name,
+					arbitrator,
+					deposit,
+					duration,
+					auctions: Default::default(),
+					cancelled: false,
+				},
+			);
+			Self::deposit_event(Event::<T>::ProjectCreated { project_id, owner });
+			Ok(())
+		}
+
+		// Confirm one milestone of an assigned auction, paying the worker its share of the
+		// price. Confirming the last open milestone confirms the auction.
+		#[pallet::weight(Pallet::<T>::max_settlement_weight())]
+		#[transactional]
+		pub fn confirm_milestone(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+			index: u32,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let mut auction =
+				Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			Self::ensure_manager(&who, &auction_key, OPERATOR_CONFIRM)?;
+			let (top_key, top_price) = Bids::<T>::get(&auction_key, BidKey::<T>::default())
+				.ok_or(Error::<T>::AuctionNotAssigned)?;
+			ensure!(auction.is_assigned(&top_key.0, top_price), Error::<T>::AuctionNotAssigned);
+			ensure!(!auction.in_dispute, Error::<T>::AuctionDisputed);
+			ensure!(!Self::under_review(&auction_key), Error::<T>::AuctionUnderReview);
+			Self::ensure_revealed(&auction_key, &top_key)?;
+			let mut milestones =
+				Milestones::<T>::get(&auction_key).ok_or(Error::<T>::MilestoneNotFound)?;
+			let (share, confirmed) =
+				milestones.get_mut(index as usize).ok_or(Error::<T>::MilestoneNotFound)?;
+			ensure!(!*confirmed, Error::<T>::MilestoneConfirmed);
+			*confirmed = true;
+			let share = *share;
+			let last = milestones.iter().all(|(_, confirmed)| *confirmed);
+			Milestones::<T>::insert(&auction_key, milestones);
+			// the rest of the price is paid by confirming the auction
+			if last {
+				return Self::confirm(frame_system::RawOrigin::Signed(who).into(), auction_key)
+			}
+			let price = Self::settlement_price(&auction_key, &auction, &top_key, top_price);
+			let paid = (share * price).min(auction.owner_bounty());
+			Self::unlock_in(&auction.asset, &auction_key.0, paid)?;
+			Self::pay_in(&auction.asset, &auction_key.0, &top_key.0, paid)?;
+			auction.released += paid;
+			Auctions::<T>::insert(&auction_key, auction);
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::MilestoneConfirmed { auction_key, index, paid },
+			);
+			Ok(Some(Self::settlement_weight(0)).into())
+		}
+
+		// Declare the number of items the worker delivers, which the owner can then confirm in
+		// subsets. Items can be declared again until the first of them is confirmed.
+		#[pallet::weight(T::WeightInfo::submit_items())]
+		pub fn submit_items(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+			count: u32,
+		) -> DispatchResult {
+			let worker = ensure_signed(origin)?;
+			let auction = Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			let (top_key, top_price) = Bids::<T>::get(&auction_key, BidKey::<T>::default())
+				.ok_or(Error::<T>::AuctionNotAssigned)?;
+			ensure!(worker == top_key.0, Error::<T>::TopBidRequired);
+			ensure!(auction.is_assigned(&worker, top_price), Error::<T>::AuctionNotAssigned);
+			ensure!(count > 0, Error::<T>::ItemCountExceeded);
+			// milestones already split the payment
+			ensure!(!Milestones::<T>::contains_key(&auction_key), Error::<T>::MilestonesDeclared);
+			ensure!(
+				DeliveredItems::<T>::get(&auction_key)
+					.map_or(true, |(_, confirmed)| confirmed == 0),
+				Error::<T>::ItemsInProgress
+			);
+			DeliveredItems::<T>::insert(&auction_key, (count, 0));
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::ItemsSubmitted { auction_key, count },
+			);
+			Ok(())
+		}
+
+		// Confirm some of the items declared by the worker, paying the price in proportion to
+		// the items confirmed so far. Confirming the last item confirms the auction.
+		#[pallet::weight(Pallet::<T>::max_settlement_weight())]
+		#[transactional]
+		pub fn confirm_items(
+			origin: OriginFor<T>,
+			auction_key: AuctionKey<T>,
+			count: u32,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let mut auction =
+				Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			Self::ensure_manager(&who, &auction_key, OPERATOR_CONFIRM)?;
+			let (top_key, top_price) = Bids::<T>::get(&auction_key, BidKey::<T>::default())
+				.ok_or(Error::<T>::AuctionNotAssigned)?;
+			ensure!(auction.is_assigned(&top_key.0, top_price), Error::<T>::AuctionNotAssigned);
+			ensure!(!auction.in_dispute, Error::<T>::AuctionDisputed);
+			ensure!(!Self::under_review(&auction_key), Error::<T>::AuctionUnderReview);
+			Self::ensure_revealed(&auction_key, &top_key)?;
+			let (declared, confirmed) =
+				DeliveredItems::<T>::get(&auction_key).ok_or(Error::<T>::ItemsNotSubmitted)?;
+			ensure!(count > 0 && count <= declared - confirmed, Error::<T>::ItemCountExceeded);
+			let confirmed = confirmed + count;
+			DeliveredItems::<T>::insert(&auction_key, (declared, confirmed));
+			// the rest of the price is paid by confirming the auction
+			if confirmed == declared {
+				return Self::confirm(frame_system::RawOrigin::Signed(who).into(), auction_key)
+			}
+			let price = Self::settlement_price(&auction_key, &auction, &top_key, top_price);
+			let due = Perbill::from_rational(confirmed, declared) * price;
+			let paid = due.saturating_sub(auction.released).min(auction.owner_bounty());
+			Self::unlock_in(&auction.asset, &auction_key.0, paid)?;
+			Self::pay_in(&auction.asset, &auction_key.0, &top_key.0, paid)?;
+			auction.released += paid;
+			Auctions::<T>::insert(&auction_key, auction);
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::ItemsConfirmed { auction_key, confirmed, paid },
+			);
+			Ok(Some(Self::settlement_weight(0)).into())
+		}
+
+		// Cancel up to the given number of member auctions of a project, latest first. Assigned
+		// auctions can't be cancelled and leave the project to settle on their own. The project
+		// is removed and its deposit returned once no members remain.
+		#[pallet::weight(
+			(Pallet::<T>::max_settlement_weight() +
+				Pallet::<T>::consolation_weight(T::MaxBidsPerAuction::get()))
+			.saturating_mul((*max_auctions).into()) +
+				T::DbWeight::get().reads_writes(1, 1)
+		)]
+		#[transactional]
+		pub fn cancel_project(
+			origin: OriginFor<T>,
+			project_id: u32,
+			max_auctions: u32,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let mut project = Projects::<T>::get(project_id).ok_or(Error::<T>::ProjectNotFound)?;
+			ensure!(owner == project.owner, Error::<T>::OwnerRequired);
+			project.cancelled = true;
+			for _ in 0..max_auctions {
+				let auction_key = match project.auctions.len().checked_sub(1) {
+					Some(last) => project.auctions.remove(last),
+					None => break,
+				};
+				if Auctions::<T>::contains_key(&auction_key) {
+					if let Err(error) = Self::do_cancel(owner.clone(), auction_key.clone(), None) {
+						Self::deposit_event(Event::<T>::ProjectAuctionCancelFailed {
+							project_id,
+							auction_key,
+							error: error.error,
+						});
+					}
+				}
+			}
+			let remaining = project.auctions.len() as u32;
+			if remaining == 0 {
+				Projects::<T>::remove(project_id);
+				ProjectCount::<T>::mutate(|count| *count -= 1);
+				T::Escrow::unlock(&owner, T::ProjectDeposit::get());
+			} else {
+				Projects::<T>::insert(project_id, project);
+			}
+			Self::deposit_event(Event::<T>::ProjectCancelled { project_id, remaining });
+			Ok(())
+		}
+
+		// Scale the bounties of unassigned auctions by a factor, such as after a crash of the
+		// token price. Owners escrow or get back the difference as auctions are visited in
+		// on_idle, auctions whose owner can't escrow an increase keep their bounty.
+		#[pallet::weight(T::WeightInfo::rescale_open_auctions())]
+		pub fn rescale_open_auctions(origin: OriginFor<T>, factor: FixedU128) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			ensure!(!factor.is_zero(), Error::<T>::InvalidRescaleFactor);
+			ensure!(PendingRescale::<T>::get().is_none(), Error::<T>::RescaleInProgress);
+			let start = T::BlockNumberProvider::current_block_number();
+			PendingRescale::<T>::put((factor, start, None::<AuctionKey<T>>));
+			Self::deposit_event(Event::<T>::RescaleStarted { factor });
+			Ok(())
+		}
+	}
+
+	// Holds bounties and deposits of auction participants.
+	pub trait EscrowHandler<AccountId, Balance> {
+		// move free funds of an account into escrow
+		fn lock(who: &AccountId, amount: Balance) -> DispatchResult;
+		// return escrowed funds to the free balance of an account
+		fn unlock(who: &AccountId, amount: Balance);
+		// pay free funds from one account to another
+		fn transfer(from: &AccountId, to: &AccountId, amount: Balance) -> DispatchResult;
+		// move escrowed funds of one account into escrow of another
+		fn transfer_locked(from: &AccountId, to: &AccountId, amount: Balance) -> DispatchResult;
+	}
+
+	// Receives marketplace counters at the end of every active block.
+	pub trait Telemetry<Balance> {
+		fn on_block(metrics: &MarketMetrics<Balance>);
+	}
+
+	impl<Balance> Telemetry<Balance> for () {
+		fn on_block(_metrics: &MarketMetrics<Balance>) {}
+	}
+
+	// Tokens representing the escrowed deposit of each top bid, as proof of it to other pallets.
+	pub trait DepositReceipts<AccountId, Balance> {
+		// issue receipts for a deposit locked by a new top bidder
+		fn mint(who: &AccountId, amount: Balance) -> DispatchResult;
+		// destroy receipts once the deposit is released or slashed, failing unless the full
+		// amount is destroyed
+		fn burn(who: &AccountId, amount: Balance) -> DispatchResult;
+	}
+
+	impl<AccountId, Balance> DepositReceipts<AccountId, Balance> for () {
+		fn mint(_who: &AccountId, _amount: Balance) -> DispatchResult {
+			Ok(())
+		}
+
+		fn burn(_who: &AccountId, _amount: Balance) -> DispatchResult {
+			Ok(())
+		}
+	}
+
+	// Accounts acting for one another, treated as one party by conflict of interest checks.
+	pub trait AccountLinks<AccountId> {
+		fn are_linked(a: &AccountId, b: &AccountId) -> bool;
+	}
+
+	impl<AccountId> AccountLinks<AccountId> for () {
+		fn are_linked(_a: &AccountId, _b: &AccountId) -> bool {
+			false
+		}
+	}
+
+	// Links of the proxy pallet, either account being registered as a proxy of the other.
+	pub struct ProxyLinks<T>(PhantomData<T>);
+	impl<T: pallet_proxy::Config> AccountLinks<T::AccountId> for ProxyLinks<T> {
+		fn are_linked(a: &T::AccountId, b: &T::AccountId) -> bool {
+			let delegates = |delegator: &T::AccountId, delegate: &T::AccountId| {
+				pallet_proxy::Pallet::<T>::proxies(delegator)
+					.0
+					.iter()
+					.any(|proxy| proxy.delegate == *delegate)
+			};
+			delegates(a, b) || delegates(b, a)
+		}
+	}
+
+	// Rate of an asset in the currency of the pallet, None while the asset can't be priced.
+	pub trait PriceOracle<AssetId> {
+		fn rate(asset: &AssetId) -> Option<FixedU128>;
+	}
+
+	impl<AssetId> PriceOracle<AssetId> for () {
+		fn rate(_asset: &AssetId) -> Option<FixedU128> {
+			None
+		}
+	}
+
+	// Delivers settlement receipts to the chain an auction was created from, e.g. as an XCM
+	// Transact of a call of the origin chain or a custom instruction set.
+	pub trait ReceiptSender<Destination, AuctionKey, Receipt> {
+		fn send(
+			destination: &Destination,
+			auction_key: &AuctionKey,
+			receipt: &Receipt,
+		) -> DispatchResult;
+	}
+
+	// No delivery, receipts can only be queried on this chain.
+	impl<Destination, AuctionKey, Receipt> ReceiptSender<Destination, AuctionKey, Receipt> for () {
+		fn send(
+			_destination: &Destination,
+			_auction_key: &AuctionKey,
+			_receipt: &Receipt,
+		) -> DispatchResult {
+			Err(DispatchError::Other("receipts are not sent"))
+		}
+	}
+
+	// Receipts issued as an asset of the assets integration. Receipts are held for each bidder
+	// in an account derived from the pallet id, so they can't be moved away before the deposit
+	// is released.
+	pub struct AssetReceipts<Assets, AssetId, Id>(PhantomData<(Assets, AssetId, Id)>);
+	impl<Assets, AssetId, Id> AssetReceipts<Assets, AssetId, Id>
+	where
+		Id: Get<PalletId>,
+	{
+		pub fn receipt_account<AccountId: Encode + Decode>(who: &AccountId) -> AccountId {
+			Id::get().into_sub_account(who)
+		}
+	}
+
+	impl<AccountId, Assets, AssetId, Id> DepositReceipts<AccountId, Assets::Balance>
+		for AssetReceipts<Assets, AssetId, Id>
+	where
+		AccountId: Encode + Decode,
+		Assets: fungibles::Mutate<AccountId>,
+		AssetId: Get<Assets::AssetId>,
+		Id: Get<PalletId>,
+	{
+		fn mint(who: &AccountId, amount: Assets::Balance) -> DispatchResult {
+			Assets::mint_into(AssetId::get(), &Self::receipt_account(who), amount)
+		}
+
+		fn burn(who: &AccountId, amount: Assets::Balance) -> DispatchResult {
+			let holder = Self::receipt_account(who);
+			ensure!(
+				Assets::balance(AssetId::get(), &holder) >= amount,
+				DispatchError::Other("deposit receipts missing")
+			);
+			let burned = Assets::burn_from(AssetId::get(), &holder, amount)?;
+			ensure!(burned == amount, DispatchError::Other("deposit receipts missing"));
+			Ok(())
+		}
+	}
+
+	// Escrow of bounties and deposits denominated in an asset other than the currency of the
+	// pallet.
+	pub trait AssetEscrowHandler<AccountId, AssetId, Balance> {
+		fn lock(asset: &AssetId, who: &AccountId, amount: Balance) -> DispatchResult;
+		fn unlock(asset: &AssetId, who: &AccountId, amount: Balance) -> DispatchResult;
+		fn transfer(
+			asset: &AssetId,
+			from: &AccountId,
+			to: &AccountId,
+			amount: Balance,
+		) -> DispatchResult;
+		fn transfer_locked(
+			asset: &AssetId,
+			from: &AccountId,
+			to: &AccountId,
+			amount: Balance,
+		) -> DispatchResult;
+	}
+
+	// No assets, auctions can only be denominated in the currency of the pallet.
+	impl<AccountId, AssetId, Balance> AssetEscrowHandler<AccountId, AssetId, Balance> for () {
+		fn lock(_asset: &AssetId, _who: &AccountId, _amount: Balance) -> DispatchResult {
+			Err(DispatchError::Other("assets are not supported"))
+		}
+
+		fn unlock(_asset: &AssetId, _who: &AccountId, _amount: Balance) -> DispatchResult {
+			Err(DispatchError::Other("assets are not supported"))
+		}
+
+		fn transfer(
+			_asset: &AssetId,
+			_from: &AccountId,
+			_to: &AccountId,
+			_amount: Balance,
+		) -> DispatchResult {
+			Err(DispatchError::Other("assets are not supported"))
+		}
+
+		fn transfer_locked(
+			_asset: &AssetId,
+			_from: &AccountId,
+			_to: &AccountId,
+			_amount: Balance,
+		) -> DispatchResult {
+			Err(DispatchError::Other("assets are not supported"))
+		}
+	}
+
+	// Escrow of fungible assets, such as those of the assets pallet which can't reserve
+	// balances. Locked assets are moved to an escrow account derived from the pallet id for
+	// each participant and moved back when unlocked.
+	pub struct FungiblesEscrow<Assets, Id>(PhantomData<(Assets, Id)>);
+	impl<Assets, Id> FungiblesEscrow<Assets, Id>
+	where
+		Id: Get<PalletId>,
+	{
+		pub fn escrow_account<AccountId: Encode + Decode>(who: &AccountId) -> AccountId {
+			Id::get().into_sub_account(who)
+		}
+	}
+
+	impl<AccountId, Assets, Id> AssetEscrowHandler<AccountId, Assets::AssetId, Assets::Balance>
+		for FungiblesEscrow<Assets, Id>
+	where
+		AccountId: Encode + Decode,
+		Assets: fungibles::Transfer<AccountId>,
+		Id: Get<PalletId>,
+	{
+		fn lock(
+			asset: &Assets::AssetId,
+			who: &AccountId,
+			amount: Assets::Balance,
+		) -> DispatchResult {
+			let escrow = Self::escrow_account(who);
+			Assets::transfer(*asset, who, &escrow, amount, false).map(|_| ())
+		}
+
+		fn unlock(
+			asset: &Assets::AssetId,
+			who: &AccountId,
+			amount: Assets::Balance,
+		) -> DispatchResult {
+			let escrow = Self::escrow_account(who);
+			Assets::transfer(*asset, &escrow, who, amount, false).map(|_| ())
+		}
+
+		fn transfer(
+			asset: &Assets::AssetId,
+			from: &AccountId,
+			to: &AccountId,
+			amount: Assets::Balance,
+		) -> DispatchResult {
+			Assets::transfer(*asset, from, to, amount, false).map(|_| ())
+		}
+
+		fn transfer_locked(
+			asset: &Assets::AssetId,
+			from: &AccountId,
+			to: &AccountId,
+			amount: Assets::Balance,
+		) -> DispatchResult {
+			let (from, to) = (Self::escrow_account(from), Self::escrow_account(to));
+			Assets::transfer(*asset, &from, &to, amount, false).map(|_| ())
+		}
+	}
+
+	// Escrow backed by reserved balances of the participants.
+	pub struct ReserveEscrow<C>(PhantomData<C>);
+	impl<AccountId, C: ReservableCurrency<AccountId>> EscrowHandler<AccountId, C::Balance>
+		for ReserveEscrow<C>
+	{
+		fn lock(who: &AccountId, amount: C::Balance) -> DispatchResult {
+			C::reserve(who, amount)
+		}
+
+		fn unlock(who: &AccountId, amount: C::Balance) {
+			C::unreserve(who, amount);
+		}
+
+		fn transfer(from: &AccountId, to: &AccountId, amount: C::Balance) -> DispatchResult {
+			C::transfer(from, to, amount, ExistenceRequirement::AllowDeath)
+		}
+
+		fn transfer_locked(from: &AccountId, to: &AccountId, amount: C::Balance) -> DispatchResult {
+			// a partial move would leave part of the escrow behind with the sender
+			ensure!(
+				C::reserved_balance(from) >= amount,
+				DispatchError::Other("insufficient reserved balance")
+			);
+			let remainder = C::repatriate_reserved(from, to, amount, BalanceStatus::Reserved)?;
+			ensure!(remainder.is_zero(), DispatchError::Other("insufficient reserved balance"));
+			Ok(())
+		}
+	}
+
+	// Computes the price below which a worker's bid gets the auction assigned.
+	pub trait PriceModel<T: Config> {
+		fn base_price(
+			auction: &Auction<T>,
+			worker: &T::AccountId,
+			block: T::BlockNumber,
+		) -> BalanceOf<T>;
+	}
+
+	// Base price rises linearly from zero to bounty between initial and terminal block.
+	pub struct LinearPrice;
+	impl<T: Config> PriceModel<T> for LinearPrice {
+		fn base_price(
+			auction: &Auction<T>,
+			_worker: &T::AccountId,
+			block: T::BlockNumber,
+		) -> BalanceOf<T> {
+			auction.base_price_at(block)
+		}
+	}
+
+	// Linear base price plus a premium for the distance between task and worker,
+	// capped at the bounty so that escrow always covers the price.
+	pub struct DistancePrice;
+	impl<T: Config> PriceModel<T> for DistancePrice {
+		fn base_price(
+			auction: &Auction<T>,
+			worker: &T::AccountId,
+			block: T::BlockNumber,
+		) -> BalanceOf<T> {
+			let base_price = auction.base_price_at(block);
+			let worker_location = Workers::<T>::get(worker).and_then(|profile| profile.location);
+			match (auction.location, worker_location, auction.distance_premium_per_unit) {
+				(Some(from), Some(to), Some(premium)) => base_price
+					.saturating_add(premium.saturating_mul(from.distance(&to).into()))
+					.min(auction.bounty),
+				_ => base_price,
+			}
+		}
+	}
+
+	// Ratio out of 255 that the price of the next bid must stay below the top price.
+	pub trait DecrementSchedule<T: Config> {
+		fn min_bid_ratio(auction: &Auction<T>, block: T::BlockNumber) -> u8;
+	}
+
+	// Ratio of the auction, or MinBidRatio, for the whole duration of the auction.
+	pub struct ConstantDecrement;
+	impl<T: Config> DecrementSchedule<T> for ConstantDecrement {
+		fn min_bid_ratio(auction: &Auction<T>, _block: T::BlockNumber) -> u8 {
+			auction.min_bid_ratio.unwrap_or_else(T::MinBidRatio::get)
+		}
+	}
+
+	// Ratio rises linearly from the ratio of the auction, or MinBidRatio, at the initial block
+	// to 255 at the terminal block, so that late bids only have to undercut the top bid.
+	pub struct ShrinkingDecrement;
+	impl<T: Config> DecrementSchedule<T> for ShrinkingDecrement {
+		fn min_bid_ratio(auction: &Auction<T>, block: T::BlockNumber) -> u8 {
+			let ratio = auction.min_bid_ratio.unwrap_or_else(T::MinBidRatio::get);
+			let duration = auction.terminal_block.saturating_sub(auction.initial_block);
+			if auction.open_ended || duration.is_zero() {
+				return ratio
+			}
+			let elapsed = block.saturating_sub(auction.initial_block).min(duration);
+			let (elapsed, duration): (u32, u32) =
+				(elapsed.saturated_into(), duration.saturated_into());
+			let raised = (255 - ratio) as u64 * elapsed as u64 / duration as u64;
+			ratio.saturating_add(raised as u8)
+		}
+	}
+
+	// helper functions
+	impl<T: Config> Auction<T> {
+		// base price is the elapsed fraction of the auction duration applied to the bounty,
+		// rounded down so that it never overshoots the bounty and never decreases over blocks
+		pub fn get_base_price(&self) -> BalanceOf<T> {
+			self.base_price_at(T::BlockNumberProvider::current_block_number())
+		}
+
+		pub fn base_price_at(&self, block: T::BlockNumber) -> BalanceOf<T> {
+			// base price jumps to the bounty once the owner accepts the top bid
+			if self.accepted {
+				return self.bounty
+			}
+			// open-ended auctions are only priced by acceptance
+			if self.open_ended {
+				return Zero::zero()
+			}
+			// clock stands still while bidding is paused
+			match self.paused_at.map_or(block, |paused_at| paused_at.min(block)) {
+				now if now < self.terminal_block => Perbill::from_rational(
+					(now - self.initial_block).saturated_into::<u32>(),
+					(self.terminal_block - self.initial_block).saturated_into::<u32>(),
+				)
+				.mul_floor(self.bounty),
+				_ => self.bounty,
+			}
+		}
+
+		// part of the bounty reserved from the owner
+		pub fn owner_bounty(&self) -> BalanceOf<T> {
+			self.bounty - self.boosted - self.released
+		}
+
+		// listing fee refund decreases linearly over the refund period
+		pub fn listing_fee_refund(&self) -> BalanceOf<T> {
+			let elapsed = T::BlockNumberProvider::current_block_number() - self.initial_block;
+			let period = T::ListingRefundPeriod::get();
+			match elapsed {
+				elapsed if elapsed < period =>
+					self.listing_fee * (period - elapsed).saturated_into::<u32>().into() /
+						period.saturated_into::<u32>().into(),
+				_ => 0u32.into(),
+			}
+		}
+
+		pub fn min_bid_ratio(&self) -> u8 {
+			T::DecrementSchedule::min_bid_ratio(
+				self,
+				T::BlockNumberProvider::current_block_number(),
+			)
+		}
+
+		pub fn in_grace_period(&self) -> bool {
+			T::BlockNumberProvider::current_block_number() <
+				self.initial_block + T::CreationGracePeriod::get()
+		}
+
+		// rate of the quote asset, falling back to the rate of creation
+		pub fn settlement_rate(&self) -> FixedU128 {
+			self.quote_asset
+				.as_ref()
+				.and_then(T::PriceOracle::rate)
+				.unwrap_or(self.quote_rate)
+		}
+
+		// amount quoted by a bid in the currency of the pallet
+		pub fn to_settlement(&self, price: BalanceOf<T>) -> BalanceOf<T> {
+			match self.quote_asset {
+				Some(_) => self
+					.settlement_rate()
+					.saturating_mul_int(price.saturated_into::<u128>())
+					.saturated_into(),
+				None => price,
+			}
+		}
+
+		// top bid holds its deposit, rather than awaiting a top-up
+		pub fn top_funded(&self) -> bool {
+			self.top_up_deadline.is_none()
+		}
+
+		pub fn is_assigned(&self, bidder: &T::AccountId, top_bid: BalanceOf<T>) -> bool {
+			self.is_assigned_at(bidder, top_bid, T::BlockNumberProvider::current_block_number())
+		}
+
+		pub fn is_assigned_at(
+			&self,
+			bidder: &T::AccountId,
+			top_bid: BalanceOf<T>,
+			block: T::BlockNumber,
+		) -> bool {
+			// bids promoted without their deposit are assigned only once topped up
+			if !self.top_funded() {
+				return false
+			}
+			let top_bid = self.to_settlement(top_bid);
+			// accepted top bids are assigned outright, open-ended auctions only that way
+			if self.accepted || self.open_ended {
+				return self.accepted && top_bid <= self.bounty
+			}
+			match self.assignment_confirmation_blocks {
+				// top bid must have stayed at or below base price for the confirmation blocks
+				Some(blocks) =>
+					block >= self.top_since + blocks &&
+						top_bid <= T::PriceModel::base_price(self, bidder, block - blocks),
+				None => top_bid <= T::PriceModel::base_price(self, bidder, block),
+			}
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		// settlement receipts live in a child trie derived from the auction key
+		fn receipt_child_info(auction_key: &AuctionKey<T>) -> ChildInfo {
+			let mut id = b"task_auction:receipt".to_vec();
+			auction_key.encode_to(&mut id);
+			ChildInfo::new_default(&id)
+		}
+
+		// auction events are indexed by the hash of the auction key
+		pub fn topic(auction_key: &AuctionKey<T>) -> T::Hash {
+			T::Hashing::hash_of(auction_key)
+		}
+
+		fn deposit_auction_event(topic: T::Hash, event: Event<T>) {
+			#[cfg(feature = "debug-trace")]
+			Self::trace_event(&event);
+			let event = <T as Config>::Event::from(event);
+			frame_system::Pallet::<T>::deposit_event_indexed(&[topic], event.into());
+		}
+
+		// log a state transition of an auction with its amounts under a target by lifecycle stage
+		#[cfg(feature = "debug-trace")]
+		fn trace_event(event: &Event<T>) {
+			let target = match event {
+				Event::Bid { .. } |
+				Event::BidImproved { .. } |
+				Event::Retracted { .. } |
+				Event::ExpiredBidWithdrawn { .. } |
+				Event::BidderRevealed { .. } |
+				Event::BidAccepted { .. } |
+				Event::QuoteRedeemed { .. } |
+				Event::RetractQueued { .. } |
+				Event::QueuedRetractProcessed { .. } |
+				Event::BidStaged { .. } |
+				Event::StagedBidRefunded { .. } |
+				Event::StagedBidUnlockFailed { .. } => "task_auction::bids",
+				Event::Confirmed { .. } |
+				Event::OwnerConfirmed { .. } |
+				Event::WorkerAcknowledged { .. } |
+				Event::Cancelled { .. } |
+				Event::Arbitrated { .. } |
+				Event::OpenAuctionExpired { .. } |
+				Event::Released { .. } |
+				Event::Abandoned { .. } |
+				Event::CollusionReviewResolved { .. } |
+				Event::DefaultJudgment { .. } |
+				Event::ConsolationPaid { .. } => "task_auction::settlement",
+				_ => "task_auction",
+			};
+			log::debug!(
+				target: target,
+				"block {:?}: {:?}",
+				T::BlockNumberProvider::current_block_number(),
+				event
+			);
+		}
+
+		// owner pays the worker and the confirmed auction is settled
+		fn pay_worker(
+			auction_key: AuctionKey<T>,
+			auction: Auction<T>,
+			top_key: BidKey<T>,
+			top_price: BalanceOf<T>,
+		) -> Result<u32, DispatchError> {
+			let owner = &auction_key.0;
+			let bid_price = Self::bid_settlement_price(&auction_key, &auction, &top_key, top_price);
+			let savings = Self::savings_paid(&auction, bid_price);
+			let price = bid_price + savings;
+			let rate = auction.quote_asset.as_ref().map(|_| auction.settlement_rate());
+			let bidder = top_key.0;
+			Self::release_dispute(&auction_key)?;
+			// unreserve deposits of bidder and owner
+			Self::unlock_in(&auction.asset, &bidder, auction.deposit)?;
+			Self::unlock_in(&auction.asset, owner, auction.deposit + auction.owner_bounty())?;
+			Self::charge_listing_fee(owner, &auction, 0u32.into())?;
+			// boosters pay their share of the price and the owner the rest, vesting linearly
+			// from now if required
+			let boosted = Self::release_boosts(&auction_key, &auction, Some((&bidder, price)))?;
+			let now = T::BlockNumberProvider::current_block_number();
+			// only payouts in the currency of the pallet can vest
+			// milestones already paid are not paid again, nor vested
+			let (due, vested) = (
+				(price - boosted).saturating_sub(auction.released),
+				price.saturating_sub(auction.released),
+			);
+			let vesting_period = auction.vesting_period.filter(|period| {
+				let per_block = Self::vesting_per_block(vested, *period);
+				auction.asset.is_none() &&
+					T::Vesting::can_add_vesting_schedule(&bidder, vested, per_block, now).is_ok()
+			});
+			let vesting_period = match vesting_period {
+				Some(period) => match T::Escrow::transfer(owner, &bidder, due) {
+					Ok(()) => {
+						let per_block = Self::vesting_per_block(vested, period);
+						T::Vesting::add_vesting_schedule(&bidder, vested, per_block, now)
+							.ok()
+							.map(|_| period)
+					},
+					Err(_) => {
+						Self::pay(owner, &bidder, due)?;
+						None
+					},
+				},
+				None => {
+					Self::pay_in(&auction.asset, owner, &bidder, due)?;
+					None
+				},
+			};
+			// payout was made in full without the requested schedule
+			if auction.vesting_period.is_some() && vesting_period.is_none() {
+				Self::deposit_auction_event(
+					Self::topic(&auction_key),
+					Event::<T>::VestingFailed {
+						auction_key: auction_key.clone(),
+						worker: bidder.clone(),
+					},
+				);
+			}
+			Self::record_totals(&bidder, |totals| {
+				totals.earned = totals.earned.saturating_add(price)
+			});
+			Self::record_totals(owner, |totals| {
+				totals.spent = totals.spent.saturating_add(price - boosted)
+			});
+			// delete auction from storage
+			let removed = Self::settle(&auction_key, Some(bidder), price, Outcome::Confirmed);
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::Confirmed { auction_key, vesting_period, price, savings, rate },
+			);
+			Ok(removed)
+		}
+
+		// worker gets the default judgment share of the price and the owner keeps the rest,
+		// both deposits are returned
+		fn settle_by_default(
+			auction_key: AuctionKey<T>,
+			auction: Auction<T>,
+			top_key: BidKey<T>,
+			top_price: BalanceOf<T>,
+		) -> Result<u32, DispatchError> {
+			let owner = &auction_key.0;
+			let price = Self::settlement_price(&auction_key, &auction, &top_key, top_price);
+			let paid = T::DefaultJudgmentShare::get() * price;
+			let worker = top_key.0;
+			Self::release_dispute(&auction_key)?;
+			Self::unlock_in(&auction.asset, &worker, auction.deposit)?;
+			Self::unlock_in(&auction.asset, owner, auction.deposit + auction.owner_bounty())?;
+			Self::charge_listing_fee(owner, &auction, 0u32.into())?;
+			let boosted = Self::release_boosts(&auction_key, &auction, Some((&worker, paid)))?;
+			// worker keeps milestones already paid even beyond the default judgment share
+			let due = (paid - boosted).saturating_sub(auction.released);
+			Self::pay_in(&auction.asset, owner, &worker, due)?;
+			let removed =
+				Self::settle(&auction_key, Some(worker.clone()), paid, Outcome::Defaulted);
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::DefaultJudgment { auction_key, worker, paid },
+			);
+			Ok(removed)
+		}
+
+		// amount unlocked per block to vest a payout over the given period
+		fn vesting_per_block(amount: BalanceOf<T>, period: T::BlockNumber) -> BalanceOf<T> {
+			let period: BalanceOf<T> = period.saturated_into::<u32>().into();
+			(amount / period).max(1u32.into())
+		}
+
+		// price paid to the worker of an assigned auction
+		pub(crate) fn settlement_price(
+			auction_key: &AuctionKey<T>,
+			auction: &Auction<T>,
+			top_key: &BidKey<T>,
+			top_price: BalanceOf<T>,
+		) -> BalanceOf<T> {
+			let price = Self::bid_settlement_price(auction_key, auction, top_key, top_price);
+			price + Self::savings_paid(auction, price)
+		}
+
+		// promised share of the savings below the bounty, paid on top of the price
+		fn savings_paid(auction: &Auction<T>, price: BalanceOf<T>) -> BalanceOf<T> {
+			auction.savings_share * auction.bounty.saturating_sub(price)
+		}
+
+		// price paid for the bid under the settlement mode of the auction
+		fn bid_settlement_price(
+			auction_key: &AuctionKey<T>,
+			auction: &Auction<T>,
+			top_key: &BidKey<T>,
+			top_price: BalanceOf<T>,
+		) -> BalanceOf<T> {
+			// owner only escrowed the bounty for prices converted from a quote asset
+			let converted = |price| match auction.quote_asset {
+				Some(_) => auction.to_settlement(price).min(auction.bounty),
+				None => price,
+			};
+			match auction.settlement_mode {
+				SettlementMode::FirstPrice => converted(top_price),
+				SettlementMode::SecondPrice => {
+					// best competing bid is the next one down the stack from another account,
+					// earlier bids of the winner don't compete
+					let mut bid_key = Bids::<T>::get(auction_key, top_key).map(|(prev, _)| prev);
+					while let Some(prev_key) = bid_key.filter(|key| *key != BidKey::<T>::default())
+					{
+						let (next_key, prev_price) = match Bids::<T>::get(auction_key, &prev_key) {
+							Some(bid) => bid,
+							None => break,
+						};
+						if prev_key.0 != top_key.0 {
+							return converted(prev_price).min(auction.bounty)
+						}
+						bid_key = Some(next_key);
+					}
+					// without competition the winner is paid their own price
+					converted(top_price)
+				},
+			}
+		}
+
+		// escrow the listing fee in the currency of the pallet, failing if the owner couldn't
+		// pay it out of the free balance it is later unlocked to
+		fn hold_listing_fee(owner: &T::AccountId, listing_fee: BalanceOf<T>) -> DispatchResult {
+			let new_balance = T::Currency::free_balance(owner).saturating_sub(listing_fee);
+			T::Currency::ensure_can_withdraw(
+				owner,
+				listing_fee,
+				WithdrawReasons::FEE,
+				new_balance,
+			)?;
+			T::Escrow::lock(owner, listing_fee)
+		}
+
+		// refund part of the listing fee and pay the rest to its destination
+		fn charge_listing_fee(
+			owner: &T::AccountId,
+			auction: &Auction<T>,
+			refund: BalanceOf<T>,
+		) -> DispatchResult {
+			T::Escrow::unlock(owner, auction.listing_fee);
+			Self::withdraw_listing_fee(owner, auction.listing_fee.saturating_sub(refund))
+		}
+
+		// listing fees are paid out of the free balance they are unlocked to
+		fn withdraw_listing_fee(owner: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+			let fee = T::Currency::withdraw(
+				owner,
+				amount,
+				WithdrawReasons::FEE,
+				ExistenceRequirement::AllowDeath,
+			)?;
+			T::ListingFeeDestination::on_unbalanced(fee);
+			Ok(())
+		}
+
+		// funds movements of arbitrating a disputed auction, with the worker and price
+		fn arbitration_movements(
+			auction_key: &AuctionKey<T>,
+			auction: &Auction<T>,
+			fulfilled: bool,
+		) -> (T::AccountId, BalanceOf<T>, Vec<FundsMovement<T::AccountId, BalanceOf<T>>>) {
+			let owner = auction_key.0.clone();
+			let (top_key, top_price) = Bids::<T>::get(auction_key, BidKey::<T>::default()).unwrap();
+			let price = Self::settlement_price(auction_key, auction, &top_key, top_price);
+			let bidder = top_key.0;
+			// unreserve funds and charge the listing fee
+			let mut movements = Vec::with_capacity(5);
+			// listing fee of an auction in an asset is unlocked when charged
+			let listing_fee =
+				if auction.asset.is_some() { Zero::zero() } else { auction.listing_fee };
+			movements.push(FundsMovement::Unlock {
+				who: owner.clone(),
+				amount: auction.deposit + auction.owner_bounty() + listing_fee,
+			});
+			movements.push(FundsMovement::Unlock { who: bidder.clone(), amount: auction.deposit });
+			movements.push(FundsMovement::ListingFee {
+				owner: owner.clone(),
+				amount: auction.listing_fee,
+			});
+			// boosters are refunded, less their share of the price if task is fulfilled
+			let mut boosted: BalanceOf<T> = Zero::zero();
+			for (booster, amount) in Boosts::<T>::iter_prefix(auction_key) {
+				movements.push(FundsMovement::Unlock { who: booster.clone(), amount });
+				if fulfilled {
+					let share = Self::boost_share(auction, amount, price);
+					movements.push(FundsMovement::Transfer {
+						from: booster,
+						to: bidder.clone(),
+						amount: share,
+					});
+					boosted += share;
+				}
+			}
+			// pay bidder if task is fulfilled
+			if fulfilled {
+				movements.push(FundsMovement::Transfer {
+					from: owner.clone(),
+					to: bidder.clone(),
+					amount: (price - boosted).saturating_sub(auction.released),
+				});
+			}
+			// inactive owner of escalated auction always pays the arbitrator
+			let loser = if fulfilled || auction.escalated { owner } else { bidder.clone() };
+			// losing side pays arbitrator their deposit, held back while the ruling can be
+			// appealed, unless the arbitrator is gone and the fallback origin rules instead
+			if !auction.fallback_arbitration {
+				let (from, to, amount) = (loser, auction.arbitrator.clone(), auction.deposit);
+				movements.push(if T::AppealPeriod::get().is_zero() {
+					FundsMovement::Transfer { from, to, amount }
+				} else {
+					FundsMovement::EscrowArbitrationFee { from, to, amount }
+				});
+			}
+			// disputer pays the fee the arbitrator quoted when the dispute was raised
+			if let Some((payer, amount)) =
+				DisputeQuotes::<T>::get(auction_key).filter(|(_, fee)| !fee.is_zero())
+			{
+				let arbitrator = auction.arbitrator.clone();
+				movements.push(FundsMovement::QuotedArbitratorFee { payer, arbitrator, amount });
+			}
+			(bidder, price, movements)
+		}
+
+		fn execute_movements(
+			auction_key: &AuctionKey<T>,
+			asset: &Option<T::AssetId>,
+			movements: Vec<FundsMovement<T::AccountId, BalanceOf<T>>>,
+		) -> DispatchResult {
+			for movement in movements {
+				match movement {
+					FundsMovement::Unlock { who, amount } => Self::unlock_in(asset, &who, amount)?,
+					FundsMovement::Transfer { from, to, amount } =>
+						Self::pay_in(asset, &from, &to, amount)?,
+					FundsMovement::ListingFee { owner, amount } => {
+						if asset.is_some() {
+							T::Escrow::unlock(&owner, amount);
+						}
+						Self::withdraw_listing_fee(&owner, amount)?;
+					},
+					FundsMovement::EscrowArbitrationFee { from, to, amount } => {
+						if Self::lock_in(asset, &from, amount).is_ok() {
+							let appeal_deadline = T::BlockNumberProvider::current_block_number() +
+								T::AppealPeriod::get();
+							ArbitrationEscrows::<T>::insert(
+								auction_key,
+								ArbitrationEscrow::<T> {
+									payer: from,
+									arbitrator: to,
+									amount,
+									appeal_deadline,
+									appealed: false,
+									asset: asset.clone(),
+								},
+							);
+						} else {
+							Self::pay_in(asset, &from, &to, amount)?;
+						}
+					},
+					// quoted fees are escrowed in the currency of the pallet
+					FundsMovement::QuotedArbitratorFee { payer, arbitrator, amount } => {
+						DisputeQuotes::<T>::remove(auction_key);
+						T::Escrow::unlock(&payer, amount);
+						Self::pay(&payer, &arbitrator, amount)?;
+					},
+				}
+			}
+			Ok(())
+		}
+
+		// what arbitrating a disputed auction as fulfilled and as not fulfilled would move
+		pub fn arbitration_preview(
+			auction_key: &AuctionKey<T>,
+		) -> Option<(
+			Vec<FundsMovement<T::AccountId, BalanceOf<T>>>,
+			Vec<FundsMovement<T::AccountId, BalanceOf<T>>>,
+		)> {
+			let auction = Auctions::<T>::get(auction_key).filter(|auction| auction.in_dispute)?;
+			let (_, _, if_fulfilled) = Self::arbitration_movements(auction_key, &auction, true);
+			let (_, _, if_not_fulfilled) =
+				Self::arbitration_movements(auction_key, &auction, false);
+			Some((if_fulfilled, if_not_fulfilled))
+		}
+
+		// highest acceptable price of the next bid, None if auction can't take bids
+		pub fn max_next_bid(auction_key: &AuctionKey<T>) -> Option<BalanceOf<T>> {
+			let auction = Auctions::<T>::get(auction_key)?;
+			let max_price = match Bids::<T>::get(auction_key, BidKey::<T>::default()) {
+				Some((prev_key, prev_price)) => {
+					if auction.is_assigned(&prev_key.0, prev_price) {
+						return None
+					}
+					// largest price satisfying prev_price * ratio > price * 255
+					(prev_price * auction.min_bid_ratio().into()).checked_sub(&1u8.into())? /
+						255u8.into()
+				},
+				_ => BalanceOf::<T>::max_value(),
+			};
+			Some(max_price).filter(|max_price| *max_price >= T::MinBidPrice::get())
+		}
+
+		// minimum bounty and deposit accepted by create
+		pub fn min_create_params() -> (BalanceOf<T>, BalanceOf<T>) {
+			(T::MinBounty::get(), T::MinDeposit::get())
+		}
+
+		pub fn create_weight() -> Weight {
+			T::WeightInfo::create(0)
+		}
+
+		// larger size classes pay for hashing and storing up to their cap
+		pub fn create_weight_for(size_class: &DataSizeClass) -> Weight {
+			T::WeightInfo::create(size_class.max_size::<T>())
+		}
+
+		// quotes are redeemed as a bid of the worker, recording the redeemed quote
+		pub fn redeem_quote_weight() -> Weight {
+			T::WeightInfo::bid() + T::DbWeight::get().reads_writes(1, 1)
+		}
+
+		// weight of create with the terms set in its options
+		pub fn create_weight_with(options: &CreateOptionsOf<T>) -> Weight {
+			let mut weight = options
+				.size_class
+				.as_ref()
+				.map_or_else(Self::create_weight, Self::create_weight_for);
+			if options.requirements.is_some() || !options.milestones.is_empty() {
+				weight += T::DbWeight::get().writes(1);
+			}
+			if options.quote.is_some() {
+				weight += Self::redeem_quote_weight();
+			}
+			weight += match options.source {
+				TermsSource::Given => 0,
+				TermsSource::Template { .. } => T::DbWeight::get().reads(1),
+				TermsSource::Project { .. } => T::DbWeight::get().reads_writes(1, 1),
+			};
+			weight
+		}
+
+		pub fn listing_fee(data_len: u32, size_class: DataSizeClass) -> BalanceOf<T> {
+			T::ListingFeeBase::get().saturating_add(Self::listing_fee_bytes(data_len, size_class))
+		}
+
+		fn listing_fee_bytes(data_len: u32, size_class: DataSizeClass) -> BalanceOf<T> {
+			T::ListingFeePerByte::get()
+				.saturating_mul(data_len.saturating_mul(size_class.fee_multiplier()).into())
+		}
+
+		// funds locked and weight charged by create
+		pub fn estimate_create(
+			bounty: BalanceOf<T>,
+			deposit: BalanceOf<T>,
+			data_len: u32,
+		) -> CreateEstimate<BalanceOf<T>> {
+			let size_class = DataSizeClass::fitting::<T>(data_len);
+			CreateEstimate {
+				bounty,
+				deposit,
+				listing_fee_base: T::ListingFeeBase::get(),
+				listing_fee_bytes: Self::listing_fee_bytes(data_len, size_class),
+				reserve_total: bounty
+					.saturating_add(deposit)
+					.saturating_add(Self::listing_fee(data_len, size_class)),
+				weight: Self::create_weight(),
+				display: AssetDisplay::native(T::Decimals::get()),
+			}
+		}
+
+		// assigned workers may acknowledge completion once without paying fees
+		pub fn is_fee_waived(who: &T::AccountId, call: &Call<T>) -> bool {
+			match call {
+				Call::acknowledge_completion { auction_key } => Auctions::<T>::get(auction_key)
+					.zip(Bids::<T>::get(auction_key, BidKey::<T>::default()))
+					.map_or(false, |(auction, (top_key, top_price))| {
+						&top_key.0 == who &&
+							!auction.worker_acknowledged &&
+							auction.is_assigned(who, top_price)
+					}),
+				_ => false,
+			}
+		}
+
+		pub fn receipt(auction_key: &AuctionKey<T>) -> Option<Receipt<T>> {
+			child::get(&Self::receipt_child_info(auction_key), b"receipt")
+		}
+
+		// delete auction from main trie and keep a compact receipt of the outcome
+		fn settle(
+			auction_key: &AuctionKey<T>,
+			worker: Option<T::AccountId>,
+			price: BalanceOf<T>,
+			outcome: Outcome,
+		) -> u32 {
+			let auction = Auctions::<T>::take(auction_key);
+			let top_bid = Bids::<T>::get(auction_key, BidKey::<T>::default());
+			// top bidder is released from the task
+			if let (Some(auction), Some((top_key, _))) = (&auction, &top_bid) {
+				// settlement goes ahead, the receipts left behind are reported
+				if auction.top_funded() && Self::vacate(&top_key.0, auction.deposit).is_err() {
+					Self::deposit_auction_event(
+						Self::topic(auction_key),
+						Event::<T>::ReceiptBurnFailed {
+							auction_key: auction_key.clone(),
+							worker: top_key.0.clone(),
+						},
+					);
+				}
+			}
+			let settled_block = T::BlockNumberProvider::current_block_number();
+			if let Some(auction) = auction.as_ref().filter(|_| T::EmitSettlementSnapshots::get()) {
+				Self::deposit_auction_event(
+					Self::topic(auction_key),
+					Event::<T>::SettlementSnapshot {
+						auction_key: auction_key.clone(),
+						head: auction.encode(),
+						winning_bid: top_bid,
+						worker: worker.clone(),
+						price,
+						boosted: auction.boosted,
+						outcome: outcome.clone(),
+						settled_block,
+					},
+				);
+			}
+			// keys left over by the bounded deletion are removed in on_idle
+			let (removed, complete) = Self::clear_auction(auction_key);
+			if !complete {
+				StaleAuctions::<T>::insert(auction_key, ());
+			}
+			TransferOffers::<T>::remove(auction_key);
+			ReleaseProposals::<T>::remove(auction_key);
+			ExtensionProposals::<T>::remove(auction_key);
+			CollusionReviews::<T>::remove(auction_key);
+			Requirements::<T>::remove(auction_key);
+			Milestones::<T>::remove(auction_key);
+			DeliveredItems::<T>::remove(auction_key);
+			// boosters were already refunded or paid out by the settlement call
+			let (boosts, _) = Self::tally_removals([Boosts::<T>::remove_prefix(
+				auction_key,
+				Some(T::MaxBoostersPerAuction::get()),
+			)]);
+			AuctionData::<T>::remove(auction_key);
+			Self::end_promotion(auction_key);
+			// prune attachments and return their deposits
+			for attachment in Attachments::<T>::take(auction_key).into_inner() {
+				T::Escrow::unlock(&attachment.author, attachment.deposit);
+			}
+			if matches!(outcome, Outcome::Confirmed | Outcome::Arbitrated { fulfilled: true }) {
+				BlockMetrics::<T>::mutate(|metrics| {
+					metrics.settlements += 1;
+					metrics.settled_volume = metrics.settled_volume.saturating_add(price);
+				});
+			}
+			let arbitrator = auction
+				.map(|auction| {
+					Self::close_dispute(auction_key, &auction);
+					auction.arbitrator
+				})
+				.unwrap_or_default();
+			let receipt = Receipt::<T> {
+				worker,
+				arbitrator,
+				price,
+				outcome,
+				settled_block,
+				owner_rating: None,
+				worker_rating: None,
+				verdict_hash: None,
+			};
+			child::put(&Self::receipt_child_info(auction_key), b"receipt", &receipt);
+			// a failed delivery leaves the receipt to be queried on this chain
+			if let Some(destination) = ReceiptDestinations::<T>::take(auction_key) {
+				let result = T::ReceiptSender::send(&destination, auction_key, &receipt);
+				Self::deposit_auction_event(
+					Self::topic(auction_key),
+					Event::<T>::SettlementReceiptSent {
+						auction_key: auction_key.clone(),
+						destination,
+						result,
+					},
+				);
+			}
+			removed + boosts
+		}
+
+		// delete per-bid entries of an auction, bounded by the maximum bid stack size,
+		// returning the number of keys removed and whether none are left
+		fn clear_bids(auction_key: &AuctionKey<T>) -> (u32, bool) {
+			let limit = Some(T::MaxBidsPerAuction::get() + 1);
+			Self::tally_removals::<CLEARED_BID_MAPS>([
+				Bids::<T>::remove_prefix(auction_key, limit),
+				FirmUntil::<T>::remove_prefix(auction_key, limit),
+				BidVersions::<T>::remove_prefix(auction_key, limit),
+				BidCommitments::<T>::remove_prefix(auction_key, limit),
+			])
+		}
+
+		// delete per-bid and per-bidder entries of a settled auction
+		fn clear_auction(auction_key: &AuctionKey<T>) -> (u32, bool) {
+			let (removed, complete) = Self::clear_bids(auction_key);
+			let limit = Some(T::MaxBidsPerAuction::get() + 1);
+			let (retractions, retractions_complete) =
+				Self::tally_removals::<CLEARED_BIDDER_MAPS>([
+					RetractedAt::<T>::remove_prefix(auction_key, limit),
+					RedeemedQuotes::<T>::remove_prefix(auction_key, limit),
+					AutoBids::<T>::remove_prefix(auction_key, limit),
+					Self::unlock_locations(auction_key, T::MaxBidsPerAuction::get() + 1),
+				]);
+			(removed + retractions, complete && retractions_complete)
+		}
+
+		// count a localized auction against the location of a bidder
+		fn lock_location(worker: &T::AccountId, auction_key: &AuctionKey<T>) -> DispatchResult {
+			if LocationLocks::<T>::contains_key(auction_key, worker) {
+				return Ok(())
+			}
+			LocationLockCount::<T>::try_mutate(worker, |count| {
+				ensure!(*count < T::MaxLocationLocks::get(), Error::<T>::TooManyLocationLocks);
+				*count += 1;
+				Ok::<_, DispatchError>(())
+			})?;
+			LocationLocks::<T>::insert(auction_key, worker, ());
+			Ok(())
+		}
+
+		// release the locations of the bidders of a settled auction, counting each lock and the
+		// count it is released from as removed keys
+		fn unlock_locations(auction_key: &AuctionKey<T>, limit: u32) -> KillStorageResult {
+			let mut removed = 0;
+			for (worker, ()) in LocationLocks::<T>::drain_prefix(auction_key).take(limit as usize) {
+				LocationLockCount::<T>::mutate_exists(&worker, |count| {
+					*count = count.map(|count| count.saturating_sub(1)).filter(|count| *count > 0)
+				});
+				removed += 2;
+			}
+			if LocationLocks::<T>::iter_prefix(auction_key).next().is_some() {
+				KillStorageResult::SomeRemaining(removed)
+			} else {
+				KillStorageResult::AllRemoved(removed)
+			}
+		}
+
+		fn tally_removals<const N: usize>(results: [KillStorageResult; N]) -> (u32, bool) {
+			results.into_iter().fold((0, true), |(removed, complete), result| match result {
+				KillStorageResult::AllRemoved(n) => (removed + n, complete),
+				KillStorageResult::SomeRemaining(n) => (removed + n, false),
+			})
+		}
+
+		// weight of a settlement call that deleted the given number of keys
+		pub fn settlement_weight(removed: u32) -> Weight {
+			T::WeightInfo::settle(removed)
+		}
+
+		// worst case weight of a settlement call, refunded down to the keys actually deleted
+		pub fn max_settlement_weight() -> Weight {
+			Self::settlement_weight(Self::max_cleared_keys())
+		}
+
+		fn max_cleared_keys() -> u32 {
+			// location locks also write the lock count of their worker
+			let cleared_maps = (CLEARED_BID_MAPS + CLEARED_BIDDER_MAPS + 1) as u32;
+			cleared_maps * (T::MaxBidsPerAuction::get() + 1) + T::MaxBoostersPerAuction::get()
+		}
+
+		// cancel an unassigned auction on behalf of its owner or operator
+		#[transactional]
+		fn do_cancel(
+			who: T::AccountId,
+			auction_key: AuctionKey<T>,
+			expected_penalty: Option<bool>,
+		) -> DispatchResultWithPostInfo {
+			// fetch auction and top bid
+			let auction = Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			// only owner of auction or its operator can cancel, the owner pays any penalty
+			Self::ensure_manager(&who, &auction_key, OPERATOR_CANCEL)?;
+			let owner = auction_key.0.clone();
+			let top_bid = Bids::<T>::get(&auction_key, BidKey::<T>::default());
+			// cancellation replayed after a reorg must not flip whether the owner pays the
+			// deposit of the top bidder
+			let penalty = !auction.in_grace_period() &&
+				auction.top_funded() &&
+				top_bid
+					.as_ref()
+					.map_or(false, |(_, price)| auction.to_settlement(*price) <= auction.bounty);
+			ensure!(
+				expected_penalty.map_or(true, |expected| expected == penalty),
+				Error::<T>::UnexpectedAuctionState
+			);
+			let mut walked = 0;
+			if let Some((top_key, price)) = top_bid {
+				let bidder = top_key.0.clone();
+				// only unassigned auctions can be cancelled
+				ensure!(!auction.is_assigned(&bidder, price), Error::<T>::AuctionAssigned);
+				// unreserve deposits of bidder and owner
+				if auction.top_funded() {
+					Self::unlock_in(&auction.asset, &bidder, auction.deposit)?;
+				}
+				Self::unlock_in(&auction.asset, &owner, auction.deposit + auction.owner_bounty())?;
+				Self::release_boosts(&auction_key, &auction, None)?;
+				if auction.in_grace_period() {
+					// mistakes cancelled right after creation are refunded in full
+					Self::charge_listing_fee(&owner, &auction, auction.listing_fee)?;
+				} else {
+					Self::charge_listing_fee(&owner, &auction, 0u32.into())?;
+					// owner pays bidder the deposit if bid is within range of bounty,
+					// and a consolation to the bidders it outbid
+					if penalty {
+						Self::pay_in(&auction.asset, &owner, &bidder, auction.deposit)?;
+						Self::record_penalty(&owner, &bidder, auction.deposit);
+						walked = Self::console_bidders(&auction_key, &auction, top_key)?;
+					}
+				}
+			} else {
+				// unreserve deposits of owner
+				Self::unlock_in(&auction.asset, &owner, auction.deposit + auction.owner_bounty())?;
+				Self::release_boosts(&auction_key, &auction, None)?;
+				// listing fee is partially refunded if cancelled early without bids
+				let refund = match auction.in_grace_period() {
+					true => auction.listing_fee,
+					false => auction.listing_fee_refund(),
+				};
+				Self::charge_listing_fee(&owner, &auction, refund)?;
+			}
+			// delete auction from storage
+			let removed = Self::settle(&auction_key, None, auction.bounty, Outcome::Cancelled);
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::Cancelled { auction_key },
+			);
+			Ok(Some(Self::settlement_weight(removed) + Self::consolation_weight(walked)).into())
+		}
+
+		// place a bid on behalf of bidder and return its bid key
+		#[transactional]
+		fn do_bid(
+			bidder: T::AccountId,
+			auction_key: AuctionKey<T>,
+			price: BalanceOf<T>,
+		) -> Result<BidKey<T>, DispatchError> {
+			// input checks
+			let mut auction =
+				Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
+			ensure!(!Self::acts_for(&bidder, &auction_key.0), Error::<T>::OriginProhibited);
+			ensure!(!Self::acts_for(&bidder, &auction.arbitrator), Error::<T>::OriginProhibited);
+			ensure!(price >= T::MinBidPrice::get(), Error::<T>::BidTooLow);
+			ensure!(auction.paused_at.is_none(), Error::<T>::AuctionPaused);
+			ensure!(Self::is_capable(&bidder, &auction), Error::<T>::AttestationRequired);
+			// bidders who recently retracted must wait out the cooldown
+			if let Some(retracted_at) = RetractedAt::<T>::get(&auction_key, &bidder) {
+				ensure!(
+					T::BlockNumberProvider::current_block_number() >=
+						retracted_at + T::RetractCooldown::get(),
+					Error::<T>::RetractCooldownActive
+				);
+			}
+			let top_bid = Bids::<T>::get(&auction_key, BidKey::<T>::default());
+			// workers at capacity can't take on more tasks, but may undercut themselves
+			ensure!(
+				top_bid.as_ref().map_or(false, |(top_key, _)| top_key.0 == bidder) ||
+					Self::has_capacity(&bidder, 1) ||
+					!(T::CheckCapacityAtBid::get() ||
+						(auction.assignment_confirmation_blocks.is_none() &&
+							auction.is_assigned(&bidder, price))),
+				Error::<T>::CapacityExceeded
+			);
+			// top bidder may lower their own firm bid by any amount in place
+			if let Some((top_key, top_price)) = top_bid.clone().filter(|(top_key, _)| {
+				top_key.0 == bidder && !Self::is_expired(&auction_key, &auction, top_key)
+			}) {
+				ensure!(!auction.is_assigned(&bidder, top_price), Error::<T>::AuctionAssigned);
+				ensure!(price < top_price, Error::<T>::BidImprovementRequired);
+				Bids::<T>::mutate(&auction_key, &top_key, |bid| {
+					if let Some((_, bid_price)) = bid {
+						*bid_price = price;
+					}
+				});
+				Bids::<T>::insert(&auction_key, BidKey::<T>::default(), (top_key.clone(), price));
+				let version = auction.version;
+				BidVersions::<T>::insert(&auction_key, &top_key, version);
+				BlockMetrics::<T>::mutate(|metrics| metrics.bids += 1);
+				let max_next_bid = Self::max_next_bid(&auction_key);
+				Self::deposit_auction_event(
+					Self::topic(&auction_key),
+					Event::<T>::BidImproved {
+						auction_key,
+						bid_key: top_key.clone(),
+						price,
+						version,
+						max_next_bid,
+					},
+				);
+				return Ok(top_key)
+			}
+			// check if there is a previous bid
+			let prev_key = match top_bid {
+				Some((prev_key, prev_price)) => {
+					// bid stack depth is bounded to keep its deletion bounded,
+					// bidders undercutting themselves replace their bid without growing it
+					ensure!(
+						prev_key.0 == bidder || prev_key.1 < T::MaxBidsPerAuction::get(),
+						Error::<T>::MaxBidsReached
+					);
+					// ensure auction is not assigned, expired bids are no longer binding
+					ensure!(
+						!auction.is_assigned(&prev_key.0, prev_price) ||
+							Self::is_expired(&auction_key, &auction, &prev_key),
+						Error::<T>::AuctionAssigned
+					);
+					// ties are broken in favor of the earlier bid, which holds the lower
+					// bid sequence number, so a bid matching the top price is rejected
+					ensure!(price != prev_price, Error::<T>::BidPriceTied);
+					// ensure new bid is lower than prev bid
+					ensure!(
+						prev_price * auction.min_bid_ratio().into() > price * 255u8.into(),
+						Error::<T>::MinBidRatioRequired
+					);
+					// unreserve deposit of previous bidder, unless still awaiting a top-up
+					if auction.top_funded() {
+						Self::unlock_in(&auction.asset, &prev_key.0, auction.deposit)?;
+						Self::vacate(&prev_key.0, auction.deposit)?;
+					}
+					// compact the bidder's own expired bid out of the stack, since
+					// compacting on every bid leaves at most one such entry below the top
+					if prev_key.0 == bidder {
+						let (below_key, _) = Bids::<T>::get(&auction_key, &prev_key).unwrap();
+						FirmUntil::<T>::remove(&auction_key, &prev_key);
+						BidCommitments::<T>::remove(&auction_key, &prev_key);
+						below_key
+					} else {
+						prev_key
+					}
+				},
+				_ => BidKey::<T>::default(),
+			};
+			// all checks pass, reserve deposit of new bidder
+			Self::lock_in(&auction.asset, &bidder, auction.deposit)?;
+			if auction.location.is_some() {
+				Self::lock_location(&bidder, &auction_key)?;
+			}
+			Self::occupy(&bidder, auction.deposit)?;
+			// insert new bid
+			let bid_key = BidId(bidder, prev_key.1 + 1);
+			Bids::<T>::insert(&auction_key, &bid_key, (prev_key, price));
+			Bids::<T>::insert(&auction_key, BidKey::<T>::default(), (bid_key.clone(), price));
+			// stamp bid with the auction version it was placed under
+			let version = auction.version;
+			BidVersions::<T>::insert(&auction_key, &bid_key, version);
+			BlockMetrics::<T>::mutate(|metrics| metrics.bids += 1);
+			// new top bid starts its confirmation period, or the inactivity timeout of
+			// open-ended auctions
+			let restarted = auction.assignment_confirmation_blocks.is_some() || auction.open_ended;
+			if restarted {
+				auction.top_since = T::BlockNumberProvider::current_block_number();
+			}
+			// new top bid holds its deposit
+			if auction.top_up_deadline.take().is_some() || restarted {
+				Auctions::<T>::insert(&auction_key, &auction);
+			}
+
+			let max_next_bid = Self::max_next_bid(&auction_key);
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::Bid {
+					auction_key,
+					bid_key: bid_key.clone(),
+					price,
+					version,
+					max_next_bid,
+				},
+			);
+			Ok(bid_key)
+		}
+
+		// bid lapsed past its firm_until block without being assigned
+		fn is_expired(
+			auction_key: &AuctionKey<T>,
+			auction: &Auction<T>,
+			bid_key: &BidKey<T>,
+		) -> bool {
+			match (FirmUntil::<T>::get(auction_key, bid_key), Bids::<T>::get(auction_key, bid_key))
+			{
+				(Some(firm_until), Some((_, price))) =>
+					T::BlockNumberProvider::current_block_number() > firm_until &&
+						!auction.is_assigned_at(&bid_key.0, price, firm_until),
+				_ => false,
+			}
+		}
+
+		// account is the party itself or linked to it, e.g. as its proxy
+		fn acts_for(who: &T::AccountId, party: &T::AccountId) -> bool {
+			who == party || T::AccountLinks::are_linked(who, party)
+		}
+
+		// bids placed through a one-time account are only paid once the real bidder is revealed
+		fn ensure_revealed(auction_key: &AuctionKey<T>, bid_key: &BidKey<T>) -> DispatchResult {
+			ensure!(
+				BidCommitments::<T>::get(auction_key, bid_key)
+					.map_or(true, |(_, bidder)| bidder.is_some()),
+				Error::<T>::RevealRequired
+			);
+			Ok(())
+		}
+
+		// bidder holds an unexpired attestation for the capability the auction requires
+		fn is_capable(bidder: &T::AccountId, auction: &Auction<T>) -> bool {
+			auction.required_capability.map_or(true, |capability| {
+				Attestations::<T>::get(bidder, capability)
+					.map_or(false, |expiry| T::BlockNumberProvider::current_block_number() < expiry)
+			})
+		}
+
+		// pay out directly or queue the payout for the recipient to claim
+		fn pay(from: &T::AccountId, to: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+			// queued funds stay in escrow of the payer until claimed
+			if T::DeferPayouts::get() && T::Escrow::lock(from, amount).is_ok() {
+				PendingPayouts::<T>::mutate(to, from, |pending| {
+					*pending = pending.saturating_add(amount)
+				});
+				Ok(())
+			} else {
+				T::Escrow::transfer(from, to, amount)
+			}
+		}
+
+		// escrow in the asset of an auction, or the currency of the pallet if it has none
+		fn lock_in(
+			asset: &Option<T::AssetId>,
+			who: &T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			match asset {
+				Some(asset) => T::AssetEscrow::lock(asset, who, amount),
+				None => T::Escrow::lock(who, amount),
+			}
+		}
+
+		fn unlock_in(
+			asset: &Option<T::AssetId>,
+			who: &T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			match asset {
+				Some(asset) => T::AssetEscrow::unlock(asset, who, amount),
+				None => {
+					T::Escrow::unlock(who, amount);
+					Ok(())
+				},
+			}
+		}
+
+		fn transfer_locked_in(
+			asset: &Option<T::AssetId>,
+			from: &T::AccountId,
+			to: &T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			match asset {
+				Some(asset) => T::AssetEscrow::transfer_locked(asset, from, to, amount),
+				None => T::Escrow::transfer_locked(from, to, amount),
+			}
+		}
+
+		// payouts in an asset are never queued
+		fn pay_in(
+			asset: &Option<T::AssetId>,
+			from: &T::AccountId,
+			to: &T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			match asset {
+				Some(asset) => T::AssetEscrow::transfer(asset, from, to, amount),
+				None => Self::pay(from, to, amount),
+			}
+		}
+
+		// number of items of the given weight fitting in the remaining weight of the block,
+		// any number of them if they cost no weight but some is left
+		fn fitting_items(remaining_weight: Weight, item_weight: Weight) -> Weight {
+			match (remaining_weight, item_weight) {
+				(0, _) => 0,
+				(_, 0) => Weight::max_value(),
+				_ => remaining_weight / item_weight,
+			}
+		}
+
+		// release queued payouts from where the last flush stopped, so that payouts failing to
+		// transfer stay queued behind the others rather than ahead of them
+		fn flush_payouts(limit: u32) -> u32 {
+			if limit == 0 {
+				return 0
+			}
+			let payouts: Vec<_> = match PayoutCursor::<T>::get() {
+				Some((recipient, payer)) => PendingPayouts::<T>::iter_from(
+					PendingPayouts::<T>::hashed_key_for(&recipient, &payer),
+				),
+				None => PendingPayouts::<T>::iter(),
+			}
+			.take(limit as usize)
+			.collect();
+			for (recipient, payer, amount) in payouts.iter() {
+				if Self::release_payout(recipient, payer).is_err() {
+					Self::deposit_event(Event::<T>::PayoutFailed {
+						recipient: recipient.clone(),
+						payer: payer.clone(),
+						amount: *amount,
+					});
+				}
+			}
+			// start over once the end of the queue is reached
+			match payouts.last() {
+				Some((recipient, payer, _)) if payouts.len() == limit as usize =>
+					PayoutCursor::<T>::put((recipient.clone(), payer.clone())),
+				_ => PayoutCursor::<T>::kill(),
+			}
+			payouts.len() as u32
+		}
+
+		// transfer a queued payout, keeping it queued if the transfer fails
+		fn release_payout(recipient: &T::AccountId, payer: &T::AccountId) -> DispatchResult {
+			let amount = PendingPayouts::<T>::get(recipient, payer);
+			T::Escrow::unlock(payer, amount);
+			if let Err(e) = T::Escrow::transfer(payer, recipient, amount) {
+				T::Escrow::lock(payer, amount)?;
+				return Err(e)
+			}
+			PendingPayouts::<T>::remove(recipient, payer);
+			Self::deposit_event(Event::<T>::PayoutClaimed {
+				recipient: recipient.clone(),
+				payer: payer.clone(),
+				amount,
+			});
+			Ok(())
+		}
+
+		// confirmation of an auction flagged for collusion is frozen until the review period ends
+		fn under_review(auction_key: &AuctionKey<T>) -> bool {
+			CollusionReviews::<T>::get(auction_key)
+				.flatten()
+				.map_or(false, |until| T::BlockNumberProvider::current_block_number() < until)
+		}
+
+		// release the worker for a penalty paid to the owner, drop all bids and restart the
+		// price curve for new bidders
+		fn reopen(
+			auction_key: &AuctionKey<T>,
+			auction: &mut Auction<T>,
+			worker: &T::AccountId,
+			penalty: BalanceOf<T>,
+		) -> Result<u32, DispatchError> {
+			ReleaseProposals::<T>::remove(auction_key);
+			Self::unlock_in(&auction.asset, worker, auction.deposit)?;
+			Self::vacate(worker, auction.deposit)?;
+			Self::pay_in(&auction.asset, worker, &auction_key.0, penalty)?;
+			let (removed, _) = Self::clear_bids(auction_key);
+			// leaving worker keeps the milestones paid so far, which no longer count towards
+			// the bounty
+			auction.bounty -= auction.released;
+			auction.released = Zero::zero();
+			DeliveredItems::<T>::remove(auction_key);
+			// completion deadline was agreed with the leaving worker
+			if let Some(deadline) = auction.completion_deadline.take() {
+				CompletionDeadlines::<T>::mutate(deadline, |queue| {
+					queue.retain(|key| key != auction_key)
+				});
+			}
+			let now = T::BlockNumberProvider::current_block_number();
+			auction.terminal_block = now + (auction.terminal_block - auction.initial_block);
+			auction.initial_block = now;
+			auction.top_since = now;
+			auction.owner_confirmed = false;
+			auction.worker_acknowledged = false;
+			auction.accepted = false;
+			auction.version += 1;
+			Ok(removed)
+		}
+
+		// move an auction to the deadline queue of the given block
+		fn schedule_deadline(
+			auction_key: &AuctionKey<T>,
+			auction: &mut Auction<T>,
+			deadline: T::BlockNumber,
+		) -> DispatchResult {
+			CompletionDeadlines::<T>::try_mutate(deadline, |queue| {
+				queue.try_push(auction_key.clone()).map_err(|_| Error::<T>::DeadlineQueueFull)
+			})?;
+			// drop the auction from the queue of its previous deadline
+			if let Some(prev_deadline) = auction.completion_deadline.replace(deadline) {
+				CompletionDeadlines::<T>::mutate(prev_deadline, |queue| {
+					queue.retain(|key| key != auction_key)
+				});
+			}
+			Ok(())
+		}
+
+		// replace the key of an auction in a deadline queue, keeping its position
+		fn rekey_queued(
+			queue: &mut DeadlineQueue<T>,
+			auction_key: &AuctionKey<T>,
+			new_auction_key: &AuctionKey<T>,
+		) {
+			if let Some(index) = queue.iter().position(|key| key == auction_key) {
+				queue.remove(index);
+				// can't fail as the old key was just removed
+				let _ = queue.try_insert(index, new_auction_key.clone());
+			}
+		}
+
+		// queue an auction at the first block from the given one whose queue has room,
+		// returning that block
+		fn enqueue_deadline<Q>(
+			mut block: T::BlockNumber,
+			auction_key: &AuctionKey<T>,
+		) -> T::BlockNumber
+		where
+			Q: storage::StorageMap<T::BlockNumber, DeadlineQueue<T>, Query = DeadlineQueue<T>>,
+		{
+			while Q::try_mutate(block, |queue| queue.try_push(auction_key.clone())).is_err() {
+				block += One::one();
+			}
+			block
+		}
+
+		// move an auction and all of its indexes under a new owner
+		fn rekey_auction(
+			auction_key: AuctionKey<T>,
+			new_auction_key: AuctionKey<T>,
+			mut auction: Auction<T>,
+		) -> DispatchResult {
+			// never overwrite an auction listed under the new key
+			ensure!(!Auctions::<T>::contains_key(&new_auction_key), Error::<T>::AuctionKeyExists);
+			TransferOffers::<T>::remove(&auction_key);
+			// extensions were negotiated with the previous owner
+			ExtensionProposals::<T>::remove(&auction_key);
+			// promotion stake belongs to the previous owner
+			Self::end_promotion(&auction_key);
+			// rewrite auction and bids under the new key
+			for (bid_key, bid) in Bids::<T>::drain_prefix(&auction_key) {
+				Bids::<T>::insert(&new_auction_key, bid_key, bid);
+			}
+			for (worker, ()) in LocationLocks::<T>::drain_prefix(&auction_key) {
+				LocationLocks::<T>::insert(&new_auction_key, worker, ());
+			}
+			for (bidder, retracted_at) in RetractedAt::<T>::drain_prefix(&auction_key) {
+				RetractedAt::<T>::insert(&new_auction_key, bidder, retracted_at);
+			}
+			// quotes signed over the previous key can't be verified against the new one
+			RedeemedQuotes::<T>::remove_prefix(&auction_key, None);
+			for (bid_key, firm_until) in FirmUntil::<T>::drain_prefix(&auction_key) {
+				FirmUntil::<T>::insert(&new_auction_key, bid_key, firm_until);
+			}
+			for (bid_key, version) in BidVersions::<T>::drain_prefix(&auction_key) {
+				BidVersions::<T>::insert(&new_auction_key, bid_key, version);
+			}
+			for (bid_key, commitment) in BidCommitments::<T>::drain_prefix(&auction_key) {
+				BidCommitments::<T>::insert(&new_auction_key, bid_key, commitment);
+			}
+			Attachments::<T>::insert(&new_auction_key, Attachments::<T>::take(&auction_key));
+			for (booster, amount) in Boosts::<T>::drain_prefix(&auction_key) {
+				Boosts::<T>::insert(&new_auction_key, booster, amount);
+			}
+			if let Some(holds) = DisputeHolds::<T>::take(&auction_key) {
+				DisputeHolds::<T>::insert(&new_auction_key, holds);
+			}
+			for (bidder, expected) in QueuedRetractions::<T>::drain_prefix(&auction_key) {
+				QueuedRetractions::<T>::insert(&new_auction_key, bidder, expected);
+			}
+			for (bidder, staged) in StagedBids::<T>::drain_prefix(&auction_key) {
+				StagedBids::<T>::insert(&new_auction_key, bidder, staged);
+			}
+			if let Some(destination) = ReceiptDestinations::<T>::take(&auction_key) {
+				ReceiptDestinations::<T>::insert(&new_auction_key, destination);
+			}
+			for (bidder, strategy) in AutoBids::<T>::drain_prefix(&auction_key) {
+				AutoBids::<T>::insert(&new_auction_key, bidder, strategy);
+			}
+			if let Some(requirements) = Requirements::<T>::take(&auction_key) {
+				Requirements::<T>::insert(&new_auction_key, requirements);
+			}
+			if let Some(milestones) = Milestones::<T>::take(&auction_key) {
+				Milestones::<T>::insert(&new_auction_key, milestones);
+			}
+			if let Some(items) = DeliveredItems::<T>::take(&auction_key) {
+				DeliveredItems::<T>::insert(&new_auction_key, items);
+			}
+			if let Some(until) = CollusionReviews::<T>::take(&auction_key) {
+				CollusionReviews::<T>::insert(&new_auction_key, until);
+			}
+			AuctionData::<T>::insert(&new_auction_key, AuctionData::<T>::take(&auction_key));
+			if let Some(deadline) = auction.completion_deadline {
+				CompletionDeadlines::<T>::mutate(deadline, |queue| {
+					Self::rekey_queued(queue, &auction_key, &new_auction_key)
+				});
+			}
+			if let Some(deadline) = auction.top_up_deadline {
+				TopUpDeadlines::<T>::mutate(deadline, |queue| {
+					Self::rekey_queued(queue, &auction_key, &new_auction_key)
+				});
+			}
+			// bidders agreed to work for the previous owner
+			auction.version += 1;
+			Auctions::<T>::remove(&auction_key);
+			Auctions::<T>::insert(&new_auction_key, auction);
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::OwnershipTransferred { auction_key, new_auction_key },
+			);
+			Ok(())
+		}
+
+		// settle a disputed auction by ruling of the arbitrator or the fallback origin
+		fn do_arbitrate(
+			auction_key: AuctionKey<T>,
+			auction: Auction<T>,
+			fulfilled: bool,
+			verdict_hash: Option<T::Hash>,
+		) -> DispatchResultWithPostInfo {
+			// auction must be in dispute
+			ensure!(auction.in_dispute, Error::<T>::AuctionNotDisputed);
+			// worker can only be awarded the price once revealed
+			if fulfilled {
+				let (top_key, _) = Bids::<T>::get(&auction_key, BidKey::<T>::default())
+					.ok_or(Error::<T>::AuctionNotAssigned)?;
+				Self::ensure_revealed(&auction_key, &top_key)?;
+			}
+			Self::release_dispute(&auction_key)?;
+			let (bidder, price, movements) =
+				Self::arbitration_movements(&auction_key, &auction, fulfilled);
+			// fees held back for appeal are recorded once released
+			for movement in movements.iter() {
+				if let FundsMovement::Transfer { from, to, amount } = movement {
+					if *to == auction.arbitrator {
+						Self::record_arbitration_fee(from, to, *amount);
+					} else {
+						Self::record_totals(from, |totals| {
+							totals.spent = totals.spent.saturating_add(*amount)
+						});
+						Self::record_totals(to, |totals| {
+							totals.earned = totals.earned.saturating_add(*amount)
+						});
+					}
+				}
+			}
+			Self::execute_movements(&auction_key, &auction.asset, movements)?;
+			let data_hash = T::Hashing::hash(&AuctionData::<T>::get(&auction_key));
+			// delete auction from storage
+			let removed = Self::settle(
+				&auction_key,
+				Some(bidder.clone()),
+				price,
+				Outcome::Arbitrated { fulfilled },
+			);
+			let now = T::BlockNumberProvider::current_block_number();
+			Tombstones::<T>::insert(
+				&auction_key,
+				Tombstone::<T> {
+					worker: bidder,
+					arbitrator: auction.arbitrator.clone(),
+					fulfilled,
+					verdict_hash,
+					data_hash,
+					settled_block: now,
+				},
+			);
+			Self::enqueue_deadline::<TombstoneExpiries<T>>(
+				now + T::DisputeRetention::get(),
+				&auction_key,
+			);
+			// reasoned decision is kept with the receipt for review on appeal
+			if verdict_hash.is_some() {
+				let child_info = Self::receipt_child_info(&auction_key);
+				if let Some(mut receipt) = child::get::<Receipt<T>>(&child_info, b"receipt") {
+					receipt.verdict_hash = verdict_hash;
+					child::put(&child_info, b"receipt", &receipt);
+				}
+			}
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::Arbitrated { auction_key, fulfilled, verdict_hash },
+			);
+			Ok(Some(Self::settlement_weight(removed)).into())
+		}
+
+		// count disputes and settlements of the finished block, tripping the circuit breaker
+		// once disputes outgrow their allowed share of settlements
+		fn track_dispute_rate(metrics: &MarketMetrics<BalanceOf<T>>) {
+			let window = T::DisputeRateWindow::get();
+			if window.is_zero() || (metrics.disputes == 0 && metrics.settlements == 0) {
+				return
+			}
+			let now = T::BlockNumberProvider::current_block_number();
+			let mut stats = DisputeRate::<T>::get();
+			// counts of the previous window are kept only if it ended just now
+			let elapsed = now.saturating_sub(stats.window_start);
+			if elapsed >= window {
+				let previous = match elapsed < window + window {
+					true => (stats.disputes, stats.settlements),
+					false => (0, 0),
+				};
+				stats = DisputeWindow {
+					window_start: now - elapsed % window,
+					disputes: 0,
+					settlements: 0,
+					previous_disputes: previous.0,
+					previous_settlements: previous.1,
+				};
+			}
+			stats.disputes = stats.disputes.saturating_add(metrics.disputes);
+			stats.settlements = stats.settlements.saturating_add(metrics.settlements);
+			let disputes = stats.disputes.saturating_add(stats.previous_disputes);
+			let settlements = stats.settlements.saturating_add(stats.previous_settlements);
+			DisputeRate::<T>::put(stats);
+			if metrics.disputes > 0 &&
+				!CircuitBreaker::<T>::exists() &&
+				disputes >= T::MinDisputeSample::get() &&
+				disputes > T::MaxDisputeRate::get() * settlements
+			{
+				CircuitBreaker::<T>::put(now);
+				Self::deposit_event(Event::<T>::CircuitBreakerTripped { disputes, settlements });
+			}
+		}
+
+		// disputes whose arbitrator account was reaped go to the fallback origin
+		fn route_dispute(
+			auction_key: &AuctionKey<T>,
+			auction: &mut Auction<T>,
+			disputer: &T::AccountId,
+		) -> DispatchResult {
+			// overloaded arbitrators are bypassed to keep rulings timely
+			if !frame_system::Pallet::<T>::account_exists(&auction.arbitrator) ||
+				OpenDisputes::<T>::get(&auction.arbitrator) >=
+					T::MaxOpenDisputesPerArbitrator::get()
+			{
+				Self::hold_dispute(auction_key, auction)?;
+				Self::route_to_fallback(auction_key, auction);
+				return Ok(())
+			}
+			// disputer locks the current fee of the arbitrator, which can't change mid-dispute
+			let fee = ArbitratorFees::<T>::get(&auction.arbitrator);
+			T::Escrow::lock(disputer, fee)?;
+			if let Err(e) = Self::hold_dispute(auction_key, auction) {
+				T::Escrow::unlock(disputer, fee);
+				return Err(e)
+			}
+			DisputeQuotes::<T>::insert(auction_key, (disputer.clone(), fee));
+			auction.disputed_at = Some(T::BlockNumberProvider::current_block_number());
+			auction.fallback_arbitration = false;
+			OpenDisputes::<T>::mutate(&auction.arbitrator, |open| *open += 1);
+			Self::deposit_auction_event(
+				Self::topic(auction_key),
+				Event::<T>::DisputeFeeQuoted {
+					auction_key: auction_key.clone(),
+					payer: disputer.clone(),
+					fee,
+				},
+			);
+			Ok(())
+		}
+
+		fn route_to_fallback(auction_key: &AuctionKey<T>, auction: &mut Auction<T>) {
+			auction.disputed_at = Some(T::BlockNumberProvider::current_block_number());
+			auction.fallback_arbitration = true;
+			Self::deposit_auction_event(
+				Self::topic(auction_key),
+				Event::<T>::FallbackArbitrationRequired { auction_key: auction_key.clone() },
+			);
+		}
+
+		// release the arbitrator from a dispute that is no longer awaiting their ruling,
+		// returning the quoted fee to the disputer
+		fn close_dispute(auction_key: &AuctionKey<T>, auction: &Auction<T>) {
+			if auction.in_dispute && !auction.fallback_arbitration {
+				OpenDisputes::<T>::mutate_exists(&auction.arbitrator, |open| {
+					*open = open.map(|open| open.saturating_sub(1)).filter(|open| *open > 0)
+				});
+			}
+			if let Some((payer, fee)) = DisputeQuotes::<T>::take(auction_key) {
+				T::Escrow::unlock(&payer, fee);
+			}
+		}
+
+		// list a new auction for owner with the terms set in the create options
+		#[transactional]
+		fn do_create_with(
+			owner: T::AccountId,
+			arbitrator: T::AccountId,
+			bounty: BalanceOf<T>,
+			deposit: BalanceOf<T>,
+			terminal_block: T::BlockNumber,
+			data: Vec<u8>,
+			options: CreateOptionsOf<T>,
+		) -> Result<AuctionKey<T>, DispatchError> {
+			let CreateOptions {
+				source,
+				asset,
+				size_class,
+				open_ended,
+				fixed_bounty,
+				quote_asset,
+				requirements,
+				milestones,
+				quote,
+			} = options;
+			let now = T::BlockNumberProvider::current_block_number();
+			let (arbitrator, bounty, deposit, terminal_block, data, project) = match source {
+				TermsSource::Given => (arbitrator, bounty, deposit, terminal_block, data, None),
+				TermsSource::Template { template_id, overrides } => {
+					let template =
+						Templates::<T>::get(template_id).ok_or(Error::<T>::TemplateNotFound)?;
+					(
+						arbitrator,
+						overrides.bounty.unwrap_or(template.bounty),
+						overrides.deposit.unwrap_or(template.deposit),
+						now + overrides.duration.unwrap_or(template.duration),
+						template.data.into_inner(),
+						None,
+					)
+				},
+				TermsSource::Project { project_id } => {
+					let project =
+						Projects::<T>::get(project_id).ok_or(Error::<T>::ProjectNotFound)?;
+					ensure!(owner == project.owner, Error::<T>::OwnerRequired);
+					ensure!(!project.cancelled, Error::<T>::ProjectCancelled);
+					ensure!(
+						project.auctions.len() < T::MaxAuctionsPerProject::get() as usize,
+						Error::<T>::MaxAuctionsPerProjectReached
+					);
+					(
+						project.arbitrator.clone(),
+						bounty,
+						project.deposit,
+						now + project.duration,
+						data,
+						Some((project_id, project)),
+					)
+				},
+			};
+			let terminal_block = if open_ended { now } else { terminal_block };
+			let quote_rate = match &quote_asset {
+				Some(quote_asset) =>
+					Some(T::PriceOracle::rate(quote_asset).ok_or(Error::<T>::UnknownQuoteAsset)?),
+				None => None,
+			};
+			if let Some(requirements) = &requirements {
+				// deliverables can only be due after the auction is assigned
+				ensure!(
+					requirements.quantity > 0 && requirements.deadline > terminal_block,
+					Error::<T>::InvalidRequirements
+				);
+			}
+			let milestones = if milestones.is_empty() {
+				None
+			} else {
+				let total: u64 = milestones.iter().map(|share| share.deconstruct() as u64).sum();
+				ensure!(
+					total == Perbill::one().deconstruct() as u64,
+					Error::<T>::InvalidMilestones
+				);
+				let milestones: BoundedVec<(Perbill, bool), T::MaxMilestones> = milestones
+					.into_iter()
+					.map(|share| (share, false))
+					.collect::<Vec<_>>()
+					.try_into()
+					.map_err(|_| Error::<T>::InvalidMilestones)?;
+				Some(milestones)
+			};
+			// worker signs the quote over the key the auction is created under
+			let nonce = frame_system::Pallet::<T>::account_nonce(&owner);
+			let auction_key = AuctionId(owner.clone(), nonce);
+			let quote = match quote {
+				Some(SignedQuote { quote, signature }) => {
+					ensure!(now <= quote.expiry, Error::<T>::QuoteExpired);
+					ensure!(
+						signature.verify(&(&auction_key, &quote).encode()[..], &quote.worker),
+						Error::<T>::InvalidQuoteSignature
+					);
+					// quote can't be redeemed again on the auction it creates
+					let quote_hash = T::Hashing::hash_of(&(&auction_key, &quote));
+					ensure!(
+						!RedeemedQuotes::<T>::contains_key(&auction_key, &quote_hash),
+						Error::<T>::QuoteAlreadyRedeemed
+					);
+					ensure!(quote.price <= bounty, Error::<T>::BidExceedsBounty);
+					Some((quote, quote_hash))
+				},
+				None => None,
+			};
+			let size_class = size_class
+				.unwrap_or_else(|| DataSizeClass::fitting::<T>(data.len().saturated_into()));
+			let auction_key = Self::do_create_in(
+				asset.clone(),
+				owner,
+				arbitrator,
+				bounty,
+				deposit,
+				terminal_block,
+				data,
+				size_class,
+			)?;
+			Auctions::<T>::mutate(&auction_key, |auction| {
+				if let Some(auction) = auction {
+					auction.open_ended = open_ended;
+					auction.fixed_bounty = fixed_bounty;
+					if let (Some(quote_asset), Some(rate)) = (&quote_asset, quote_rate) {
+						auction.quote_asset = Some(quote_asset.clone());
+						auction.quote_rate = rate;
+					}
+					if let Some(requirements) = &requirements {
+						auction.required_capability = requirements.capability;
+					}
+				}
+			});
+			if let Some(asset_id) = asset {
+				Self::deposit_auction_event(
+					Self::topic(&auction_key),
+					Event::<T>::BountyAssetSet { auction_key: auction_key.clone(), asset_id },
+				);
+			}
+			if let (Some(quote_asset), Some(rate)) = (quote_asset, quote_rate) {
+				Self::deposit_auction_event(
+					Self::topic(&auction_key),
+					Event::<T>::QuoteAssetSet {
+						auction_key: auction_key.clone(),
+						quote_asset,
+						rate,
+					},
+				);
+			}
+			if let Some(requirements) = requirements {
+				Requirements::<T>::insert(&auction_key, requirements);
+			}
+			if let Some(milestones) = milestones {
+				let count = milestones.len() as u32;
+				Milestones::<T>::insert(&auction_key, milestones);
+				Self::deposit_auction_event(
+					Self::topic(&auction_key),
+					Event::<T>::MilestonesSet { auction_key: auction_key.clone(), count },
+				);
+			}
+			// auction is not created if the bid fails
+			if let Some((quote, quote_hash)) = quote {
+				let bid_key = Self::do_bid(quote.worker, auction_key.clone(), quote.price)?;
+				RedeemedQuotes::<T>::insert(&auction_key, quote_hash, ());
+				Self::deposit_auction_event(
+					Self::topic(&auction_key),
+					Event::<T>::QuoteRedeemed {
+						auction_key: auction_key.clone(),
+						bid_key,
+						price: quote.price,
+					},
+				);
+			}
+			if let Some((project_id, mut project)) = project {
+				project
+					.auctions
+					.try_push(auction_key.clone())
+					.map_err(|_| Error::<T>::MaxAuctionsPerProjectReached)?;
+				Projects::<T>::insert(project_id, project);
+				Self::deposit_event(Event::<T>::ProjectAuctionCreated {
+					project_id,
+					auction_key: auction_key.clone(),
+				});
+			}
+			Ok(auction_key)
+		}
+
+		// list a new auction for owner with bounty and deposit in the given asset
+		fn do_create_in(
+			asset: Option<T::AssetId>,
+			owner: T::AccountId,
+			arbitrator: T::AccountId,
+			bounty: BalanceOf<T>,
+			deposit: BalanceOf<T>,
+			terminal_block: T::BlockNumber,
+			data: Vec<u8>,
+			size_class: DataSizeClass,
+		) -> Result<AuctionKey<T>, DispatchError> {
+			// input checks
+			let initial_block = T::BlockNumberProvider::current_block_number();
+			ensure!(!CircuitBreaker::<T>::exists(), Error::<T>::CreationSuspended);
+			// owners cannot rule on disputes over their own auctions
+			ensure!(arbitrator != owner, Error::<T>::SelfArbitrationProhibited);
+			ensure!(T::ArbitratorSet::contains(&arbitrator), Error::<T>::ArbitratorNotApproved);
+			ensure!(
+				OpenDisputes::<T>::get(&arbitrator) < T::MaxOpenDisputesPerArbitrator::get(),
+				Error::<T>::ArbitratorOverloaded
+			);
+			ensure!(bounty >= T::MinBounty::get(), Error::<T>::MinBountyRequired);
+			ensure!(bounty <= T::MaxBounty::get(), Error::<T>::MaxBountyExceeded);
+			ensure!(deposit >= T::MinDeposit::get(), Error::<T>::MinDepositRequired);
+			let data_len: u32 = data.len().saturated_into();
+			ensure!(data_len <= size_class.max_size::<T>(), Error::<T>::MaxDataSizeExceeded);
+			let data: BoundedVec<u8, T::MaxDataSize> =
+				data.try_into().map_err(|_| Error::<T>::MaxDataSizeExceeded)?;
+
+			// generate auction key, which a transferred auction may already hold
+			let nonce = frame_system::Pallet::<T>::account_nonce(&owner);
+			let auction_key = AuctionId(owner.clone(), nonce);
+			ensure!(!Auctions::<T>::contains_key(&auction_key), Error::<T>::AuctionKeyExists);
+
+			// listing fee is held in reserve until the refund period is over
+			let listing_fee = Self::listing_fee(data_len, size_class);
+			Self::hold_listing_fee(&owner, listing_fee)?;
+
+			// reserve balance for bounty and deposit, in the asset of the auction if any
+			let reserve = bounty.checked_add(&deposit).ok_or(ArithmeticError::Overflow)?;
+			if let Err(error) = Self::lock_in(&asset, &owner, reserve) {
+				T::Escrow::unlock(&owner, listing_fee);
+				return Err(error)
+			}
 
 			// create and insert new auction
 			let auction = Auction::<T> {
 				arbitrator,
 				bounty,
 				deposit,
+				listing_fee,
 				initial_block,
 				terminal_block,
-				data,
 				in_dispute: false,
+				escalated: false,
+				joint_confirmation: false,
+				owner_confirmed: false,
+				worker_acknowledged: false,
+				location: None,
+				distance_premium_per_unit: None,
+				settlement_mode: SettlementMode::default(),
+				paused_at: None,
+				required_capability: None,
+				version: 0,
+				fallback_arbitration: false,
+				min_bid_ratio: None,
+				assignment_confirmation_blocks: None,
+				top_since: initial_block,
+				completion_deadline: None,
+				vesting_period: None,
+				open_ended: false,
+				accepted: false,
+				disputed_at: None,
+				data_size_class: size_class,
+				boosted: Zero::zero(),
+				boosters: 0,
+				batched_bids: false,
+				savings_share: Permill::zero(),
+				quote_asset: None,
+				quote_rate: FixedU128::one(),
+				top_up_deadline: None,
+				fixed_bounty: false,
+				asset,
+				released: Zero::zero(),
 			};
 			Auctions::<T>::insert(&auction_key, auction);
+			AuctionData::<T>::insert(&auction_key, data);
 
-			Self::deposit_event(Event::<T>::Created { auction_key, bounty, terminal_block });
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::Created { auction_key: auction_key.clone(), bounty, terminal_block },
+			);
+			Ok(auction_key)
+		}
+
+		// add a template to the bounded registry, holding a deposit from its author
+		fn do_publish_template(template: Template<T>) -> DispatchResult {
+			ensure!(template.bounty >= T::MinBounty::get(), Error::<T>::MinBountyRequired);
+			ensure!(template.deposit >= T::MinDeposit::get(), Error::<T>::MinDepositRequired);
+			ensure!(
+				TemplateCount::<T>::get() < T::MaxTemplates::get(),
+				Error::<T>::MaxTemplatesReached
+			);
+			T::Escrow::lock(&template.author, template.template_deposit)?;
+			let template_id = NextTemplateId::<T>::mutate(|id| {
+				*id += 1;
+				*id - 1
+			});
+			TemplateCount::<T>::mutate(|count| *count += 1);
+			let author = template.author.clone();
+			Templates::<T>::insert(template_id, template);
+			Self::deposit_event(Event::<T>::TemplatePublished { template_id, author });
 			Ok(())
 		}
 
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
-		pub fn extend(
-			origin: OriginFor<T>,
-			auction_key: Key<T>,
-			bounty: BalanceOf<T>,
-			terminal_block: T::BlockNumber,
-		) -> DispatchResult {
-			let owner = ensure_signed(origin)?;
-			let mut auction =
-				Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
-			// only owner of auction can extend
-			ensure!(owner == auction_key.0, Error::<T>::OwnerRequired);
-			// ensure auction is not assigned
-			if let Some((_, price)) = Bids::<T>::get(&auction_key, Key::<T>::default()) {
-				ensure!(!auction.is_assigned(price), Error::<T>::AuctionAssigned);
+		// estimated unix time in milliseconds at which a block is produced
+		pub fn estimate_timestamp(block: T::BlockNumber) -> u64 {
+			let now = T::UnixTime::now().as_millis().saturated_into::<u64>();
+			let current_block = T::BlockNumberProvider::current_block_number();
+			let block_time = T::ExpectedBlockTime::get();
+			if block >= current_block {
+				let blocks = (block - current_block).saturated_into::<u64>();
+				now.saturating_add(blocks.saturating_mul(block_time))
+			} else {
+				let blocks = (current_block - block).saturated_into::<u64>();
+				now.saturating_sub(blocks.saturating_mul(block_time))
 			}
-			// bounty must be higher than previous by MinBounty ammount
-			ensure!(bounty > auction.bounty + T::MinBounty::get(), Error::<T>::MinBountyRequired);
-			// reserve the difference in bounty
-			T::Currency::reserve(&owner, bounty - auction.bounty)?;
-			// update auction
-			auction.bounty = bounty;
-			auction.terminal_block = terminal_block;
-			Auctions::<T>::insert(&auction_key, auction);
+		}
 
-			Self::deposit_event(Event::<T>::Extended { auction_key, bounty, terminal_block });
-			Ok(())
+		pub fn auction_status(
+			auction_key: &AuctionKey<T>,
+		) -> Option<AuctionStatus<BalanceOf<T>, T::BlockNumber>> {
+			let auction = Auctions::<T>::get(auction_key)?;
+			let top_bid = Bids::<T>::get(auction_key, BidKey::<T>::default());
+			Some(AuctionStatus {
+				terminal_block: auction.terminal_block,
+				terminal_timestamp: Self::estimate_timestamp(auction.terminal_block),
+				top_price: top_bid.as_ref().map(|(_, price)| *price),
+				assigned: top_bid
+					.map_or(false, |(top_key, price)| auction.is_assigned(&top_key.0, price)),
+				display: AssetDisplay::native(T::Decimals::get()),
+			})
 		}
 
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
-		pub fn bid(
-			origin: OriginFor<T>,
-			auction_key: Key<T>,
-			price: BalanceOf<T>,
+		// whether a worker can hold additional top bids within their declared capacity
+		fn has_capacity(worker: &T::AccountId, additional: u32) -> bool {
+			match Workers::<T>::get(worker).and_then(|profile| profile.max_concurrent_assignments) {
+				Some(max) => ActiveAssignments::<T>::get(worker).saturating_add(additional) <= max,
+				None => true,
+			}
+		}
+
+		// reserve the deposit of a bidder taking the top bid together with its receipts
+		#[transactional]
+		fn fund_top(
+			asset: &Option<T::AssetId>,
+			bidder: &T::AccountId,
+			deposit: BalanceOf<T>,
 		) -> DispatchResult {
-			// input checks
-			let bidder = ensure_signed(origin)?;
-			let auction = Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
-			ensure!(bidder != auction_key.0, Error::<T>::OriginProhibited);
-			ensure!(bidder != auction.arbitrator, Error::<T>::OriginProhibited);
-			// check if there is a previous bid
-			let prev_key = match Bids::<T>::get(&auction_key, Key::<T>::default()) {
-				Some((prev_key, prev_price)) => {
-					// ensure auction is not assigned
-					ensure!(!auction.is_assigned(prev_price), Error::<T>::AuctionAssigned);
-					// ensure new bid is lower than prev bid
-					ensure!(
-						prev_price * T::MinBidRatio::get().into() > price * 255u8.into(),
-						Error::<T>::MinBidRatioRequired
-					);
-					// unreserve deposit of previous bidder
-					T::Currency::unreserve(&prev_key.0, auction.deposit);
-					prev_key
-				},
-				_ => Key::<T>::default(),
-			};
-			// all checks pass, reserve deposit of new bidder
-			T::Currency::reserve(&bidder, auction.deposit)?;
-			// insert new bid
-			let bid_key = (bidder, prev_key.1 + 1u8.into());
-			Bids::<T>::insert(&auction_key, &bid_key, (prev_key, price));
-			Bids::<T>::insert(&auction_key, Key::<T>::default(), (bid_key.clone(), price));
+			Self::lock_in(asset, bidder, deposit)?;
+			Self::occupy(bidder, deposit)
+		}
 
-			Self::deposit_event(Event::<T>::Bid { auction_key, bid_key, price });
+		// worker takes the top bid of an auction and receives a receipt for its deposit
+		fn occupy(worker: &T::AccountId, deposit: BalanceOf<T>) -> DispatchResult {
+			T::DepositReceipts::mint(worker, deposit)?;
+			ActiveAssignments::<T>::mutate(worker, |active| *active = active.saturating_add(1));
 			Ok(())
 		}
 
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
-		pub fn retract(origin: OriginFor<T>, auction_key: Key<T>) -> DispatchResult {
-			let bidder = ensure_signed(origin)?;
+		// worker loses the top bid of an auction and its deposit receipt is burned
+		fn vacate(worker: &T::AccountId, deposit: BalanceOf<T>) -> DispatchResult {
+			T::DepositReceipts::burn(worker, deposit)?;
+			ActiveAssignments::<T>::mutate_exists(worker, |active| {
+				*active = active.map(|active| active.saturating_sub(1)).filter(|active| *active > 0)
+			});
+			Ok(())
+		}
+
+		// put an assigned auction into dispute if the worker missed the completion deadline
+		fn expire_completion(auction_key: &AuctionKey<T>, now: T::BlockNumber) {
+			let mut auction = match Auctions::<T>::get(auction_key) {
+				Some(auction) if auction.completion_deadline == Some(now) => auction,
+				_ => return,
+			};
+			if auction.in_dispute || auction.worker_acknowledged {
+				return
+			}
+			match Bids::<T>::get(auction_key, BidKey::<T>::default()) {
+				Some((top_key, price)) if auction.is_assigned(&top_key.0, price) => {},
+				_ => return,
+			}
+			// worker's deposit is now at stake in arbitration, and the fallback origin
+			// rules if the owner can't cover the arbitrator's fee
+			if Self::route_dispute(auction_key, &mut auction, &auction_key.0).is_err() {
+				Self::route_to_fallback(auction_key, &mut auction);
+			}
+			auction.in_dispute = true;
+			Auctions::<T>::insert(auction_key, auction);
+			BlockMetrics::<T>::mutate(|metrics| metrics.disputes += 1);
+			Self::deposit_auction_event(
+				Self::topic(auction_key),
+				Event::<T>::CompletionDeadlineMissed { auction_key: auction_key.clone() },
+			);
+			Self::deposit_auction_event(
+				Self::topic(auction_key),
+				Event::<T>::Disputed { auction_key: auction_key.clone() },
+			);
+		}
+
+		// refund the promotion stake of an auction leaving the promoted list
+		fn end_promotion(auction_key: &AuctionKey<T>) {
+			let mut promoted = PromotedAuctions::<T>::get();
+			if let Some(index) = promoted.iter().position(|(key, _)| key == auction_key) {
+				let (_, stake) = promoted.remove(index);
+				T::Escrow::unlock(&auction_key.0, stake);
+				PromotedAuctions::<T>::put(promoted);
+				Self::deposit_auction_event(
+					Self::topic(auction_key),
+					Event::<T>::PromotionEnded { auction_key: auction_key.clone() },
+				);
+			}
+		}
+
+		// ruling record of an arbitrator with the average time taken to rule
+		pub fn arbitrator_scorecard(
+			arbitrator: &T::AccountId,
+		) -> ArbitratorScorecard<T::BlockNumber> {
+			let stats = ArbitratorStats::<T>::get(arbitrator);
+			ArbitratorScorecard {
+				rulings: stats.rulings,
+				average_resolution_blocks: stats
+					.resolution_blocks
+					.checked_div(&stats.rulings.into())
+					.unwrap_or_default(),
+				sla_breaches: stats.sla_breaches,
+				appealed: stats.appealed,
+				overturned: stats.overturned,
+			}
+		}
+
+		// state of all listed auctions, iterating every auction so only meant for off-chain calls
+		// status of the member auctions of a project
+		pub fn project_summary(project_id: u32) -> Option<ProjectSummary<BalanceOf<T>>> {
+			let project = Projects::<T>::get(project_id)?;
+			let mut summary = ProjectSummary::default();
+			for auction_key in project.auctions.iter() {
+				let auction = match Auctions::<T>::get(auction_key) {
+					Some(auction) => auction,
+					None => {
+						summary.settled += 1;
+						continue
+					},
+				};
+				summary.total_bounty = summary.total_bounty.saturating_add(auction.bounty);
+				let assigned = Bids::<T>::get(auction_key, BidKey::<T>::default())
+					.map_or(false, |(top_key, price)| auction.is_assigned(&top_key.0, price));
+				if auction.in_dispute {
+					summary.disputed += 1;
+				} else if assigned {
+					summary.assigned += 1;
+				} else {
+					summary.open += 1;
+				}
+			}
+			Some(summary)
+		}
+
+		pub fn marketplace_health() -> HealthSnapshot<T::BlockNumber> {
+			let now = T::BlockNumberProvider::current_block_number();
+			let mut health = HealthSnapshot::default();
+			for (auction_key, auction) in Auctions::<T>::iter() {
+				health.open_auctions += 1;
+				if let Some((top_key, top_price)) =
+					Bids::<T>::get(&auction_key, BidKey::<T>::default())
+				{
+					health.assigned += auction.is_assigned(&top_key.0, top_price) as u32;
+				}
+				if auction.in_dispute {
+					health.disputed += 1;
+					let age = now.saturating_sub(auction.disputed_at.unwrap_or(now));
+					health.oldest_dispute_age =
+						Some(health.oldest_dispute_age.map_or(age, |oldest| oldest.max(age)));
+				}
+			}
+			health.expiry_queue_depth =
+				CompletionDeadlines::<T>::iter_values().map(|queue| queue.len() as u32).sum();
+			health
+		}
+
+		// part of the price paid by a booster, in proportion to their contribution
+		fn boost_share(
+			auction: &Auction<T>,
+			amount: BalanceOf<T>,
+			price: BalanceOf<T>,
+		) -> BalanceOf<T> {
+			// never more than the boost escrowed, even if the price exceeds the bounty
+			helpers_128bit::multiply_by_rational(
+				amount.saturated_into(),
+				price.saturated_into(),
+				auction.bounty.saturated_into(),
+			)
+			.map_or(amount, |share| share.saturated_into::<BalanceOf<T>>())
+			.min(amount)
+		}
+
+		// refund boosters of a settled auction, less their share of the price paid to the
+		// worker if any, returning the total paid by boosters
+		fn release_boosts(
+			auction_key: &AuctionKey<T>,
+			auction: &Auction<T>,
+			payout: Option<(&T::AccountId, BalanceOf<T>)>,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			let mut boosted: BalanceOf<T> = Zero::zero();
+			for (booster, amount) in Boosts::<T>::iter_prefix(auction_key) {
+				T::Escrow::unlock(&booster, amount);
+				if let Some((worker, price)) = payout {
+					let share = Self::boost_share(auction, amount, price);
+					Self::pay(&booster, worker, share)?;
+					Self::record_totals(&booster, |totals| {
+						totals.spent = totals.spent.saturating_add(share)
+					});
+					boosted += share;
+				}
+			}
+			Ok(boosted)
+		}
+
+		// retract the top bid of bidder and return the number of keys removed
+		#[transactional]
+		fn do_retract(
+			bidder: T::AccountId,
+			auction_key: AuctionKey<T>,
+			expected_assigned: Option<bool>,
+		) -> Result<u32, DispatchError> {
 			// fetch auction and previous bid
 			let mut auction =
 				Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
-			let (mut top_key, top_price) = Bids::<T>::get(&auction_key, Key::<T>::default())
+			let (top_key, top_price) = Bids::<T>::get(&auction_key, BidKey::<T>::default())
 				.ok_or(Error::<T>::TopBidRequired)?;
 			// only the top bid can be retracted
 			ensure!(bidder == top_key.0, Error::<T>::TopBidRequired);
-			// bidder loses deposit to owner if auction is assigned
-			T::Currency::unreserve(&bidder, auction.deposit);
-			if auction.is_assigned(top_price) {
-				T::Currency::transfer(
-					&bidder,
-					&auction_key.0,
-					auction.deposit,
-					ExistenceRequirement::AllowDeath,
-				)
-				.unwrap();
+			// retraction replayed after a reorg must not flip whether the deposit is lost
+			ensure!(
+				expected_assigned
+					.map_or(true, |expected| expected == auction.is_assigned(&bidder, top_price)),
+				Error::<T>::UnexpectedAuctionState
+			);
+			Self::release_dispute(&auction_key)?;
+			// start cooldown before bidder can bid again
+			RetractedAt::<T>::insert(
+				&auction_key,
+				&bidder,
+				T::BlockNumberProvider::current_block_number(),
+			);
+			// bidder loses deposit to owner if auction is assigned,
+			// unless the auction was amended after the bid was placed
+			if auction.top_funded() {
+				Self::unlock_in(&auction.asset, &bidder, auction.deposit)?;
+				Self::vacate(&bidder, auction.deposit)?;
+			}
+			if auction.is_assigned(&bidder, top_price) &&
+				BidVersions::<T>::get(&auction_key, &top_key) == auction.version
+			{
+				Self::pay_in(&auction.asset, &bidder, &auction_key.0, auction.deposit)?;
 			}
+			let (removed, bid_key, price) = Self::promote_next(&auction_key, &mut auction, top_key);
+			// clear dispute and confirmations after bid assignment is retracted,
+			// and restart the confirmation period of the new top bid
+			if auction.in_dispute ||
+				auction.owner_confirmed ||
+				auction.worker_acknowledged ||
+				auction.assignment_confirmation_blocks.is_some() ||
+				auction.open_ended
+			{
+				auction.top_since = T::BlockNumberProvider::current_block_number();
+				Self::close_dispute(&auction_key, &auction);
+				auction.in_dispute = false;
+				auction.escalated = false;
+				auction.owner_confirmed = false;
+				auction.worker_acknowledged = false;
+				auction.accepted = false;
+			}
+			Auctions::<T>::insert(&auction_key, auction);
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::Retracted { auction_key, bid_key, price },
+			);
+			Ok(removed)
+		}
 
-			let (bid_key, price) = loop {
+		// remove the top bid and promote the next firm bid of the stack, returning the number of
+		// keys removed and the new top bid. A bidder short of the deposit keeps the top bid for
+		// the top-up period, or is skipped without one.
+		fn promote_next(
+			auction_key: &AuctionKey<T>,
+			auction: &mut Auction<T>,
+			mut top_key: BidKey<T>,
+		) -> (u32, BidKey<T>, BalanceOf<T>) {
+			auction.top_up_deadline = None;
+			let mut removed = 0;
+			loop {
 				// remove top bid
-				let (prev_key, _) = Bids::<T>::take(&auction_key, &top_key).unwrap();
+				let (prev_key, _) = Bids::<T>::take(auction_key, &top_key).unwrap();
+				FirmUntil::<T>::remove(auction_key, &top_key);
+				BidVersions::<T>::remove(auction_key, &top_key);
+				BidCommitments::<T>::remove(auction_key, &top_key);
+				removed += 4;
 				// if there is no previous bid, reset bid vector
-				if prev_key == Key::<T>::default() {
-					Bids::<T>::remove_prefix(&auction_key, None);
-					break (prev_key, auction.bounty)
+				if prev_key == BidKey::<T>::default() {
+					removed += Self::clear_bids(auction_key).0;
+					break (removed, prev_key, auction.bounty)
 				}
-				// use previous bid as top bid if funds can be reserved
-				else if T::Currency::reserve(&prev_key.0, auction.deposit).is_ok() {
-					let (_, prev_price) = Bids::<T>::get(&auction_key, &prev_key).unwrap();
-					Bids::<T>::insert(
-						&auction_key,
-						Key::<T>::default(),
-						(prev_key.clone(), prev_price),
-					);
-					break (prev_key, prev_price)
+				// use previous bid as top bid if it is still firm and funds can be reserved,
+				// or can still be reserved within the top-up period
+				if !Self::is_expired(auction_key, auction, &prev_key) {
+					let funded =
+						Self::fund_top(&auction.asset, &prev_key.0, auction.deposit).is_ok();
+					let period = T::TopUpPeriod::get();
+					if funded || !period.is_zero() {
+						let (_, prev_price) = Bids::<T>::get(auction_key, &prev_key).unwrap();
+						Bids::<T>::insert(
+							auction_key,
+							BidKey::<T>::default(),
+							(prev_key.clone(), prev_price),
+						);
+						if !funded {
+							let deadline = Self::enqueue_deadline::<TopUpDeadlines<T>>(
+								T::BlockNumberProvider::current_block_number() + period,
+								auction_key,
+							);
+							auction.top_up_deadline = Some(deadline);
+							Self::deposit_auction_event(
+								Self::topic(auction_key),
+								Event::<T>::TopUpRequested {
+									auction_key: auction_key.clone(),
+									bid_key: prev_key.clone(),
+									deadline,
+								},
+							);
+						}
+						break (removed, prev_key, prev_price)
+					}
 				}
 				// otherwise continue down the stack
 				top_key = prev_key;
+			}
+		}
+
+		// drop a top bid whose bidder missed the top-up period, returning the keys removed
+		fn expire_top_up(auction_key: &AuctionKey<T>, now: T::BlockNumber) -> u32 {
+			let mut auction = match Auctions::<T>::get(auction_key) {
+				Some(auction) if auction.top_up_deadline == Some(now) => auction,
+				_ => return 0,
 			};
-			// clear dispute after disputed bid assignment is retracted
-			if auction.in_dispute {
-				auction.in_dispute = false;
-				Auctions::<T>::insert(&auction_key, auction);
+			let (top_key, _) = match Bids::<T>::get(auction_key, BidKey::<T>::default()) {
+				Some(top_bid) => top_bid,
+				None => return 0,
+			};
+			let (removed, bid_key, price) = Self::promote_next(auction_key, &mut auction, top_key);
+			auction.top_since = now;
+			Auctions::<T>::insert(auction_key, auction);
+			Self::deposit_auction_event(
+				Self::topic(auction_key),
+				Event::<T>::TopUpExpired { auction_key: auction_key.clone(), bid_key, price },
+			);
+			removed
+		}
+
+		// weight of walking the given number of bids to pay consolations
+		pub fn consolation_weight(walked: u32) -> Weight {
+			T::DbWeight::get().reads_writes(2, 2) * walked as Weight
+		}
+
+		// owner pays distinct outbid bidders with firm bids a consolation, within the bounty
+		// the owner escrowed, returning the number of bids walked
+		fn console_bidders(
+			auction_key: &AuctionKey<T>,
+			auction: &Auction<T>,
+			top_key: BidKey<T>,
+		) -> Result<u32, DispatchError> {
+			let amount =
+				(T::CancelConsolation::get() * auction.deposit).min(T::MaxConsolation::get());
+			if amount.is_zero() {
+				return Ok(0)
 			}
-			Self::deposit_event(Event::<T>::Retracted { auction_key, bid_key, price });
-			Ok(())
+			let mut budget = auction.owner_bounty();
+			let mut consoled = Vec::new();
+			let mut walked = 0;
+			let mut bid_key = top_key.clone();
+			while consoled.len() < T::MaxConsoledBidders::get() as usize && budget >= amount {
+				let prev_key = match Bids::<T>::get(auction_key, &bid_key) {
+					Some((prev_key, _)) if prev_key != BidKey::<T>::default() => prev_key,
+					_ => break,
+				};
+				walked += 1;
+				if prev_key.0 != top_key.0 &&
+					!consoled.contains(&prev_key.0) &&
+					!Self::is_expired(auction_key, auction, &prev_key)
+				{
+					Self::pay_in(&auction.asset, &auction_key.0, &prev_key.0, amount)?;
+					Self::record_penalty(&auction_key.0, &prev_key.0, amount);
+					budget -= amount;
+					consoled.push(prev_key.0.clone());
+					Self::deposit_auction_event(
+						Self::topic(auction_key),
+						Event::<T>::ConsolationPaid {
+							auction_key: auction_key.clone(),
+							bidder: prev_key.0.clone(),
+							amount,
+						},
+					);
+				}
+				bid_key = prev_key;
+			}
+			Ok(walked)
 		}
 
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
-		pub fn confirm(origin: OriginFor<T>, auction_key: Key<T>) -> DispatchResult {
-			let owner = ensure_signed(origin)?;
-			// fetch auction
-			let auction = Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
-			// only owner of auction can confirm
-			ensure!(owner == auction_key.0, Error::<T>::OwnerRequired);
-			// fetch to bid
-			let ((bidder, _), price) = Bids::<T>::get(&auction_key, Key::<T>::default())
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account()
+		}
+
+		// move the escrow of owner and worker of an auction going into dispute to the pallet
+		// account, so that other pallets slashing reserves can't break the payout
+		fn hold_dispute(auction_key: &AuctionKey<T>, auction: &Auction<T>) -> DispatchResult {
+			// escrow in an asset is already held in escrow accounts of the pallet
+			if auction.asset.is_some() {
+				return Ok(())
+			}
+			let (top_key, _) = Bids::<T>::get(auction_key, BidKey::<T>::default())
 				.ok_or(Error::<T>::AuctionNotAssigned)?;
-			// only assigned auctions can be confirmed
-			ensure!(auction.is_assigned(price), Error::<T>::AuctionNotAssigned);
-			// unreserve deposits of bidder and owner
-			T::Currency::unreserve(&bidder, auction.deposit);
-			T::Currency::unreserve(&owner, auction.deposit + auction.bounty);
-			// owner pays bidder the agreed price
-			T::Currency::transfer(&owner, &bidder, price, ExistenceRequirement::AllowDeath)
-				.unwrap();
-			// delete auction from storage
-			Bids::<T>::remove_prefix(&auction_key, None);
-			Auctions::<T>::remove(&auction_key);
-			Self::deposit_event(Event::<T>::Confirmed { auction_key });
+			let owner_hold = auction.deposit + auction.owner_bounty();
+			Self::hold(&auction_key.0, owner_hold)?;
+			if let Err(e) = Self::hold(&top_key.0, auction.deposit) {
+				Self::release_hold(&auction_key.0, owner_hold)?;
+				return Err(e)
+			}
+			DisputeHolds::<T>::insert(auction_key, (top_key.0, owner_hold, auction.deposit));
 			Ok(())
 		}
 
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
-		pub fn cancel(origin: OriginFor<T>, auction_key: Key<T>) -> DispatchResult {
-			let owner = ensure_signed(origin)?;
-			// fetch auction and top bid
-			let auction = Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
-			// only owner of auction can cancel
-			ensure!(owner == auction_key.0, Error::<T>::OwnerRequired);
-			if let Some(((bidder, _), price)) = Bids::<T>::get(&auction_key, Key::<T>::default()) {
-				// only unassigned auctions can be cancelled
-				ensure!(!auction.is_assigned(price), Error::<T>::AuctionAssigned);
-				// unreserve deposits of bidder and owner
-				T::Currency::unreserve(&bidder, auction.deposit);
-				T::Currency::unreserve(&owner, auction.deposit + auction.bounty);
-				// owner pays bidder the deposit if bid is within range of bounty
-				if price <= auction.bounty {
-					T::Currency::transfer(
-						&owner,
-						&bidder,
-						auction.deposit,
-						ExistenceRequirement::AllowDeath,
-					)
-					.unwrap();
-				}
-			} else {
-				// unreserve deposits of owner
-				T::Currency::unreserve(&owner, auction.deposit + auction.bounty);
+		// return the escrow held for a disputed auction to the reserves of owner and worker
+		fn release_dispute(auction_key: &AuctionKey<T>) -> DispatchResult {
+			if let Some((worker, owner_hold, worker_hold)) = DisputeHolds::<T>::take(auction_key) {
+				Self::release_hold(&auction_key.0, owner_hold)?;
+				Self::release_hold(&worker, worker_hold)?;
 			}
-			// delete auction from storage
-			Bids::<T>::remove_prefix(&auction_key, None);
-			Auctions::<T>::remove(&auction_key);
-			Self::deposit_event(Event::<T>::Cancelled { auction_key });
 			Ok(())
 		}
 
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
-		pub fn dispute(origin: OriginFor<T>, auction_key: Key<T>) -> DispatchResult {
-			let origin = ensure_signed(origin)?;
-			// fetch auction
-			let mut auction =
-				Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
-			// auction is already in dispute
-			ensure!(!auction.in_dispute, Error::<T>::AuctionDisputed);
-			// fetch top bid
-			let ((bidder, _), price) = Bids::<T>::get(&auction_key, Key::<T>::default())
-				.ok_or(Error::<T>::AuctionNotAssigned)?;
-			// only owner or bidder can dispute
-			ensure!(origin == bidder || origin == auction_key.0, Error::<T>::OriginProhibited);
-			// only assigned auctions can be disputed
-			ensure!(auction.is_assigned(price), Error::<T>::AuctionNotAssigned);
-			auction.in_dispute = true;
-			Auctions::<T>::insert(&auction_key, auction);
-			Self::deposit_event(Event::<T>::Disputed { auction_key });
+		fn hold(who: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+			T::Escrow::unlock(who, amount);
+			if let Err(e) = T::Escrow::transfer(who, &Self::account_id(), amount) {
+				T::Escrow::lock(who, amount)?;
+				return Err(e)
+			}
 			Ok(())
 		}
 
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
-		pub fn arbitrate(
-			origin: OriginFor<T>,
-			auction_key: Key<T>,
-			fulfilled: bool,
+		fn release_hold(who: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+			T::Escrow::transfer(&Self::account_id(), who, amount)?;
+			T::Escrow::lock(who, amount)
+		}
+
+		// reserve the deposit of a bid on a batched auction until the end of the block,
+		// bidders may lower their staged bid within the block
+		fn stage_bid(
+			bidder: T::AccountId,
+			auction_key: AuctionKey<T>,
+			price: BalanceOf<T>,
 		) -> DispatchResult {
-			let arbitrator = ensure_signed(origin)?;
 			let auction = Auctions::<T>::get(&auction_key).ok_or(Error::<T>::AuctionKeyNotFound)?;
-			// only the arbitrator is allowed
-			ensure!(arbitrator == auction.arbitrator, Error::<T>::OriginProhibited);
-			// auction must be in dispute
-			ensure!(auction.in_dispute, Error::<T>::AuctionNotDisputed);
-			// fetch bidder
-			let ((bidder, _), price) = Bids::<T>::get(&auction_key, Key::<T>::default()).unwrap();
-			// unreserve funds
-			T::Currency::unreserve(&auction_key.0, auction.deposit + auction.bounty);
-			T::Currency::unreserve(&bidder, auction.deposit);
-			// pay bidder if task is fulfilled
-			let loser = if fulfilled {
-				T::Currency::transfer(
-					&auction_key.0,
-					&bidder,
-					price,
-					ExistenceRequirement::AllowDeath,
-				)
-				.unwrap();
-				&auction_key.0
-			} else {
-				&bidder
+			ensure!(!Self::acts_for(&bidder, &auction_key.0), Error::<T>::OriginProhibited);
+			ensure!(!Self::acts_for(&bidder, &auction.arbitrator), Error::<T>::OriginProhibited);
+			ensure!(price >= T::MinBidPrice::get(), Error::<T>::BidTooLow);
+			ensure!(auction.paused_at.is_none(), Error::<T>::AuctionPaused);
+			let deposit = match StagedBids::<T>::get(&auction_key, &bidder) {
+				Some((staged_price, deposit)) => {
+					ensure!(price < staged_price, Error::<T>::BidImprovementRequired);
+					deposit
+				},
+				None => {
+					Self::lock_in(&auction.asset, &bidder, auction.deposit)?;
+					auction.deposit
+				},
 			};
-			// losing side pays arbitrator their deposit
-			T::Currency::transfer(
-				loser,
-				&arbitrator,
-				auction.deposit,
-				ExistenceRequirement::AllowDeath,
-			)
-			.unwrap();
-			// delete auction from storage
-			Bids::<T>::remove_prefix(&auction_key, None);
-			Auctions::<T>::remove(&auction_key);
-			Self::deposit_event(Event::<T>::Arbitrated { auction_key, fulfilled });
+			StagedBids::<T>::insert(&auction_key, &bidder, (price, deposit));
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::BidStaged { auction_key, bidder, price },
+			);
 			Ok(())
 		}
-	}
 
-	// helper functions
-	impl<T: Config> Auction<T> {
-		pub fn get_base_price(&self) -> BalanceOf<T> {
-			match frame_system::Pallet::<T>::block_number() {
-				now if now < self.terminal_block =>
-					self.bounty * (now - self.initial_block).saturated_into::<u32>().into() /
-						(self.terminal_block - self.initial_block).saturated_into::<u32>().into(),
-				_ => self.bounty,
+		// place the lowest staged bid that passes the bid checks, refunding the others
+		fn commit_staged_bids(
+			auction_key: AuctionKey<T>,
+			mut bids: Vec<(T::AccountId, BalanceOf<T>, BalanceOf<T>)>,
+		) {
+			bids.sort_by_key(|(_, price, _)| *price);
+			let asset = Auctions::<T>::get(&auction_key).and_then(|auction| auction.asset);
+			let mut committed = false;
+			for (bidder, price, deposit) in bids {
+				// do_bid reserves the deposit again under the current auction terms, a deposit that
+				// can't be returned is reported and left in escrow
+				if Self::unlock_in(&asset, &bidder, deposit).is_err() {
+					Self::deposit_auction_event(
+						Self::topic(&auction_key),
+						Event::<T>::StagedBidUnlockFailed {
+							auction_key: auction_key.clone(),
+							bidder,
+							deposit,
+						},
+					);
+					continue
+				}
+				if !committed {
+					committed = Self::do_bid(bidder.clone(), auction_key.clone(), price).is_ok();
+					if committed {
+						continue
+					}
+				}
+				Self::deposit_auction_event(
+					Self::topic(&auction_key),
+					Event::<T>::StagedBidRefunded {
+						auction_key: auction_key.clone(),
+						bidder,
+						price,
+					},
+				);
+			}
+		}
+
+		// reporting period a block falls into
+		pub fn stats_period(block: T::BlockNumber) -> u32 {
+			block.checked_div(&T::StatsPeriod::get()).unwrap_or_default().saturated_into()
+		}
+
+		// update the totals of an account in the current reporting period
+		fn record_totals(who: &T::AccountId, update: impl FnOnce(&mut PeriodTotals<BalanceOf<T>>)) {
+			let period = Self::stats_period(T::BlockNumberProvider::current_block_number());
+			AccountTotals::<T>::mutate(who, period, update);
+		}
+
+		// deposit or consolation an account was made to pay to another as a penalty
+		fn record_penalty(from: &T::AccountId, to: &T::AccountId, amount: BalanceOf<T>) {
+			Self::record_totals(from, |totals| {
+				totals.slashed = totals.slashed.saturating_add(amount)
+			});
+			Self::record_totals(to, |totals| totals.earned = totals.earned.saturating_add(amount));
+		}
+
+		fn record_arbitration_fee(
+			payer: &T::AccountId,
+			arbitrator: &T::AccountId,
+			amount: BalanceOf<T>,
+		) {
+			Self::record_totals(payer, |totals| {
+				totals.slashed = totals.slashed.saturating_add(amount)
+			});
+			Self::record_totals(arbitrator, |totals| {
+				totals.arbitration_income = totals.arbitration_income.saturating_add(amount)
+			});
+		}
+
+		// totals of an account over a reporting period with the blocks it spans
+		pub fn account_statement(
+			who: &T::AccountId,
+			period: u32,
+		) -> AccountStatement<BalanceOf<T>, T::BlockNumber> {
+			let totals = AccountTotals::<T>::get(who, period);
+			let start_block = T::StatsPeriod::get().saturating_mul(period.into());
+			AccountStatement {
+				start_block,
+				end_block: start_block.saturating_add(T::StatsPeriod::get()),
+				earned: totals.earned,
+				spent: totals.spent,
+				slashed: totals.slashed,
+				arbitration_income: totals.arbitration_income,
+			}
+		}
+
+		// place the counter-bid of an outbid bidder's strategy below the new top price,
+		// removing the strategy once the floor or the bid checks stop it
+		fn counter_bid(bidder: T::AccountId, auction_key: AuctionKey<T>, price: BalanceOf<T>) {
+			let (floor, decrement) = match AutoBids::<T>::get(&auction_key, &bidder) {
+				Some(strategy) => strategy,
+				None => return,
+			};
+			let counter = Self::max_next_bid(&auction_key)
+				.map(|max_price| price.saturating_sub(decrement).min(max_price))
+				.filter(|counter| *counter >= floor);
+			let placed = counter.map_or(false, |counter| {
+				Self::try_bid(bidder.clone(), auction_key.clone(), counter).is_ok()
+			});
+			if !placed {
+				AutoBids::<T>::remove(&auction_key, &bidder);
+				Self::deposit_auction_event(
+					Self::topic(&auction_key),
+					Event::<T>::AutoBidExhausted { auction_key, bidder },
+				);
+			}
+		}
+
+		// bid that leaves no trace if it fails, for bids placed on behalf of a bidder
+		#[transactional]
+		fn try_bid(
+			bidder: T::AccountId,
+			auction_key: AuctionKey<T>,
+			price: BalanceOf<T>,
+		) -> Result<BidKey<T>, DispatchError> {
+			Self::do_bid(bidder, auction_key, price)
+		}
+
+		// caller is the owner of the auction or an operator permitted the call for the auction
+		// or all auctions of the owner
+		fn ensure_manager(
+			who: &T::AccountId,
+			auction_key: &AuctionKey<T>,
+			permission: u8,
+		) -> DispatchResult {
+			let owner = &auction_key.0;
+			let permitted = *who == *owner ||
+				[Some(auction_key.clone()), None].into_iter().any(|scope| {
+					Operators::<T>::get(owner, (who.clone(), scope)) & permission != 0
+				});
+			ensure!(permitted, Error::<T>::OwnerRequired);
+			Ok(())
+		}
+
+		// rescale up to limit auctions of the pending rescale, returning the number visited
+		fn continue_rescale(limit: u32) -> u32 {
+			let (factor, start, cursor) = match PendingRescale::<T>::get() {
+				Some(pending) if limit > 0 => pending,
+				_ => return 0,
+			};
+			let mut auctions = match cursor {
+				Some(ref last_key) =>
+					Auctions::<T>::iter_from(Auctions::<T>::hashed_key_for(last_key)),
+				None => Auctions::<T>::iter(),
+			};
+			let mut cursor = cursor;
+			for visited in 0..limit {
+				match auctions.next() {
+					Some((auction_key, auction)) => {
+						cursor = Some(auction_key.clone());
+						// auctions listed since the rescale started are already at the new scale
+						if auction.initial_block < start {
+							Self::rescale_auction(auction_key, auction, factor)
+						}
+					},
+					None => {
+						PendingRescale::<T>::kill();
+						Self::deposit_event(Event::<T>::RescaleCompleted { factor });
+						return visited
+					},
+				}
+			}
+			PendingRescale::<T>::put((factor, start, cursor));
+			limit
+		}
+
+		// scale the part of the bounty escrowed by the owner of an unassigned auction
+		fn rescale_auction(auction_key: AuctionKey<T>, mut auction: Auction<T>, factor: FixedU128) {
+			let assigned = Bids::<T>::get(&auction_key, BidKey::<T>::default())
+				.map_or(false, |(top_key, price)| auction.is_assigned(&top_key.0, price));
+			// bounties in an asset are not priced in the currency being rescaled
+			if auction.fixed_bounty || auction.asset.is_some() || assigned || auction.in_dispute {
+				return
+			}
+			let owner = &auction_key.0;
+			let owner_bounty = auction.owner_bounty();
+			let scaled: BalanceOf<T> = factor
+				.saturating_mul_int(owner_bounty.saturated_into::<u128>())
+				.saturated_into();
+			let bounty = auction.boosted.saturating_add(scaled);
+			if bounty < T::MinBounty::get() || bounty > T::MaxBounty::get() {
+				return
+			}
+			if scaled > owner_bounty {
+				if T::Escrow::lock(owner, scaled - owner_bounty).is_err() {
+					return
+				}
+			} else {
+				T::Escrow::unlock(owner, owner_bounty - scaled);
 			}
+			// existing bids agreed to the previous bounty and may retract without penalty
+			auction.bounty = bounty;
+			auction.version += 1;
+			Auctions::<T>::insert(&auction_key, auction);
+			Self::deposit_auction_event(
+				Self::topic(&auction_key),
+				Event::<T>::BountyRescaled { auction_key, bounty },
+			);
 		}
 
-		pub fn is_assigned(&self, top_bid: BalanceOf<T>) -> bool {
-			top_bid <= self.get_base_price()
+		// settle the deadlines and expiries falling on a provider block
+		fn process_deadlines(n: T::BlockNumber) -> Weight {
+			// dispute assignments whose completion deadline passed without acknowledgement
+			let expired = CompletionDeadlines::<T>::take(n);
+			for auction_key in expired.iter() {
+				Self::expire_completion(auction_key, n);
+			}
+			// tombstones past the dispute retention period
+			let pruned = TombstoneExpiries::<T>::take(n);
+			for auction_key in pruned.iter() {
+				Tombstones::<T>::remove(auction_key);
+			}
+			// top bids whose bidder missed the top-up period
+			let unfunded = TopUpDeadlines::<T>::take(n);
+			let mut dropped = 0;
+			for auction_key in unfunded.iter() {
+				dropped += Self::expire_top_up(auction_key, n);
+			}
+			T::DbWeight::get().reads_writes(3, 3) +
+				T::DbWeight::get().reads_writes(2, 1) * expired.len() as Weight +
+				T::DbWeight::get().writes(pruned.len() as Weight) +
+				T::DbWeight::get().reads_writes(3, 2) * unfunded.len() as Weight +
+				T::DbWeight::get().writes(dropped.into())
 		}
 	}
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::generate_storage_info]
 	pub struct Pallet<T>(_);
 }
+
+// Signed extension that waives fees of settlement calls the signer is entitled to make,
+// and otherwise defers to the wrapped fee charging extension.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct WaiveSettlementFees<T, S>(pub S, PhantomData<T>);
+
+impl<T, S> WaiveSettlementFees<T, S> {
+	pub fn from(inner: S) -> Self {
+		Self(inner, PhantomData)
+	}
+}
+
+impl<T, S: fmt::Debug> fmt::Debug for WaiveSettlementFees<T, S> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "WaiveSettlementFees({:?})", self.0)
+	}
+}
+
+impl<T, S> SignedExtension for WaiveSettlementFees<T, S>
+where
+	T: Config + Send + Sync,
+	<T as frame_system::Config>::Call: IsSubType<Call<T>>,
+	S: SignedExtension<AccountId = T::AccountId, Call = <T as frame_system::Config>::Call>,
+{
+	// keep the wrapped identifier so clients encode the tip as usual
+	const IDENTIFIER: &'static str = S::IDENTIFIER;
+	type AccountId = T::AccountId;
+	type Call = <T as frame_system::Config>::Call;
+	type AdditionalSigned = S::AdditionalSigned;
+	type Pre = Option<S::Pre>;
+
+	fn additional_signed(&self) -> Result<Self::AdditionalSigned, TransactionValidityError> {
+		self.0.additional_signed()
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> TransactionValidity {
+		match call.is_sub_type() {
+			Some(call) if Pallet::<T>::is_fee_waived(who, call) => Ok(ValidTransaction::default()),
+			_ => self.0.validate(who, call, info, len),
+		}
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		match call.is_sub_type() {
+			Some(call) if Pallet::<T>::is_fee_waived(who, call) => Ok(None),
+			_ => self.0.pre_dispatch(who, call, info, len).map(Some),
+		}
+	}
+
+	fn post_dispatch(
+		pre: Self::Pre,
+		info: &DispatchInfoOf<Self::Call>,
+		post_info: &PostDispatchInfoOf<Self::Call>,
+		len: usize,
+		result: &DispatchResult,
+	) -> Result<(), TransactionValidityError> {
+		match pre {
+			Some(pre) => S::post_dispatch(pre, info, post_info, len, result),
+			None => Ok(()),
+		}
+	}
+}
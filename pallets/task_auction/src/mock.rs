@@ -1,15 +1,22 @@
 use crate as pallet_task_auction;
-use frame_support::parameter_types;
+use frame_support::{
+	assert_ok, parameter_types,
+	traits::{OnFinalize, OnIdle, OnInitialize},
+	weights::Weight,
+};
 use frame_system as system;
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
+	FixedU128,
 };
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
 type Balance = u128;
+pub type SentReceipt =
+	(u32, pallet_task_auction::AuctionId<u64, u64>, pallet_task_auction::Outcome, Balance);
 
 // Configure a mock runtime to test the pallet.
 frame_support::construct_runtime!(
@@ -76,7 +83,276 @@ impl pallet_balances::Config for Test {
 
 parameter_types! {
 	pub const MinBidRatio: u8 = 250;
+	pub const MinBidPrice: u128 = 10;
+	pub static MaxBounty: u128 = u128::MAX;
 	pub const MaxDataSize: u32 = 1024;
+	pub const ListingRefundPeriod: u64 = 10;
+	pub static ListingFeeBase: u128 = 0;
+	pub static ListingFeePerByte: u128 = 0;
+	pub static RetractCooldown: u64 = 0;
+	pub const OwnerInactivity: u64 = 10;
+	pub const MaxAttachments: u32 = 2;
+	pub const MaxAttachmentSize: u32 = 64;
+	pub const AttachmentDepositPerByte: u128 = 10;
+	pub const MaxBundleSize: u32 = 4;
+	pub static DeferPayouts: bool = false;
+	pub static MaxBidsPerAuction: u32 = 16;
+	pub static MaxLocationLocks: u32 = 4;
+	pub const RatingPeriod: u64 = 10;
+	pub static AppealPeriod: u64 = 0;
+	pub const MaxTemplates: u32 = 2;
+	pub const MaxTemplateNameLength: u32 = 32;
+	pub static TemplateDeposit: u128 = 100;
+	pub const MaxProjects: u32 = 2;
+	pub const MaxProjectNameLength: u32 = 32;
+	pub const MaxAuctionsPerProject: u32 = 3;
+	pub const MaxMilestones: u32 = 4;
+	pub const ProjectDeposit: u128 = 100;
+	pub const ExpectedBlockTime: u64 = 6000;
+	pub static Now: u64 = 0;
+	pub static ClockOverride: Option<u64> = None;
+	pub static CreationGracePeriod: u64 = 0;
+	pub static CheckCapacityAtBid: bool = true;
+	pub static EmitSettlementSnapshots: bool = false;
+	pub static ApprovedArbitrators: Option<Vec<u64>> = None;
+	pub static MaxOpenDisputesPerArbitrator: u32 = 16;
+	pub const MaxDeadlinesPerBlock: u32 = 2;
+	pub const MaxPromoted: u32 = 2;
+	pub const OpenAuctionTimeout: u64 = 20;
+	pub const AbandonmentWindow: u64 = 10;
+	pub const AbandonmentPenalty: sp_runtime::Perbill = sp_runtime::Perbill::from_percent(20);
+	pub const CollusionReviewPeriod: u64 = 10;
+	pub static MaxBoostersPerAuction: u32 = 4;
+	pub static CancelConsolation: sp_runtime::Perbill = sp_runtime::Perbill::zero();
+	pub const MaxConsolation: Balance = 100;
+	pub const MaxConsoledBidders: u32 = 2;
+	pub static ArbitrationPeriod: u64 = 20;
+	pub const DefaultJudgmentShare: sp_runtime::Perbill = sp_runtime::Perbill::from_percent(50);
+	pub static DisputeRateWindow: u64 = 0;
+	pub const MaxDisputeRate: sp_runtime::Perbill = sp_runtime::Perbill::from_percent(50);
+	pub const MinDisputeSample: u32 = 2;
+	pub const DisputeRetention: u64 = 10;
+	pub const Decimals: u8 = 3;
+	pub const StatsPeriod: u64 = 100;
+	pub static TopUpPeriod: u64 = 0;
+	pub const TaskAuctionPalletId: frame_support::PalletId = frame_support::PalletId(*b"py/tauct");
+	pub static VestingSchedules: Vec<(u64, Balance, Balance, u64)> = Vec::new();
+	pub static Receipts: std::collections::BTreeMap<u64, Balance> = Default::default();
+	pub static RemoteChains: std::collections::BTreeMap<u64, u32> = Default::default();
+	pub static SentReceipts: Vec<SentReceipt> = Vec::new();
+	pub static ProxyPairs: Vec<(u64, u64)> = Vec::new();
+	pub static QuoteRates: std::collections::BTreeMap<u32, FixedU128> = Default::default();
+	pub static AssetBalances: std::collections::BTreeMap<(u32, u64), Balance> = Default::default();
+	pub static LockedAssets: std::collections::BTreeMap<(u32, u64), Balance> = Default::default();
+}
+
+/// Wall clock driven by the `Now` static, in milliseconds.
+pub struct MockTime;
+impl frame_support::traits::UnixTime for MockTime {
+	fn now() -> core::time::Duration {
+		core::time::Duration::from_millis(Now::get())
+	}
+}
+
+/// Arbitrators listed in `ApprovedArbitrators`, or anyone if unset.
+pub struct MockArbitrators;
+impl frame_support::traits::Contains<u64> for MockArbitrators {
+	fn contains(who: &u64) -> bool {
+		ApprovedArbitrators::get().map_or(true, |arbitrators| arbitrators.contains(who))
+	}
+}
+
+/// Vesting schedules recorded in `VestingSchedules`, at most one per account.
+pub struct MockVesting;
+impl frame_support::traits::VestingSchedule<u64> for MockVesting {
+	type Moment = u64;
+	type Currency = Balances;
+
+	fn vesting_balance(who: &u64) -> Option<Balance> {
+		VestingSchedules::get()
+			.iter()
+			.find(|schedule| schedule.0 == *who)
+			.map(|schedule| schedule.1)
+	}
+
+	fn add_vesting_schedule(
+		who: &u64,
+		locked: Balance,
+		per_block: Balance,
+		starting_block: u64,
+	) -> sp_runtime::DispatchResult {
+		Self::can_add_vesting_schedule(who, locked, per_block, starting_block)?;
+		let mut schedules = VestingSchedules::get();
+		schedules.push((*who, locked, per_block, starting_block));
+		VestingSchedules::set(schedules);
+		Ok(())
+	}
+
+	fn can_add_vesting_schedule(
+		who: &u64,
+		_locked: Balance,
+		_per_block: Balance,
+		_starting_block: u64,
+	) -> sp_runtime::DispatchResult {
+		match Self::vesting_balance(who) {
+			Some(_) => Err("vesting schedule exists".into()),
+			None => Ok(()),
+		}
+	}
+
+	fn remove_vesting_schedule(who: &u64, _schedule_index: u32) -> sp_runtime::DispatchResult {
+		let mut schedules = VestingSchedules::get();
+		schedules.retain(|schedule| schedule.0 != *who);
+		VestingSchedules::set(schedules);
+		Ok(())
+	}
+}
+
+/// Deposit receipts held in `Receipts`.
+pub struct MockReceipts;
+impl pallet_task_auction::DepositReceipts<u64, Balance> for MockReceipts {
+	fn mint(who: &u64, amount: Balance) -> sp_runtime::DispatchResult {
+		let mut receipts = Receipts::get();
+		*receipts.entry(*who).or_default() += amount;
+		Receipts::set(receipts);
+		Ok(())
+	}
+
+	fn burn(who: &u64, amount: Balance) -> sp_runtime::DispatchResult {
+		let mut receipts = Receipts::get();
+		let held = receipts.entry(*who).or_default();
+		*held = held.checked_sub(amount).ok_or("deposit receipts missing")?;
+		receipts.retain(|_, held| *held > 0);
+		Receipts::set(receipts);
+		Ok(())
+	}
+}
+
+/// Signed origins of accounts in `RemoteChains`, standing in for owners on other chains.
+pub struct EnsureRemote;
+impl frame_support::traits::EnsureOrigin<Origin> for EnsureRemote {
+	type Success = (u64, u32);
+
+	fn try_origin(o: Origin) -> Result<Self::Success, Origin> {
+		match o.clone().into() {
+			Ok(system::RawOrigin::Signed(who)) =>
+				RemoteChains::get().get(&who).map(|chain| (who, *chain)).ok_or(o),
+			_ => Err(o),
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn successful_origin() -> Origin {
+		let mut chains = RemoteChains::get();
+		chains.insert(0, 0);
+		RemoteChains::set(chains);
+		Origin::signed(0)
+	}
+}
+
+/// Receipts sent with their destination chain, outcome and price held in `SentReceipts`.
+pub struct MockReceiptSender;
+impl
+	pallet_task_auction::ReceiptSender<
+		u32,
+		pallet_task_auction::AuctionId<u64, u64>,
+		pallet_task_auction::Receipt<Test>,
+	> for MockReceiptSender
+{
+	fn send(
+		destination: &u32,
+		auction_key: &pallet_task_auction::AuctionId<u64, u64>,
+		receipt: &pallet_task_auction::Receipt<Test>,
+	) -> sp_runtime::DispatchResult {
+		let mut sent = SentReceipts::get();
+		sent.push((*destination, *auction_key, receipt.outcome.clone(), receipt.price));
+		SentReceipts::set(sent);
+		Ok(())
+	}
+}
+
+/// Block number of the system, unless overridden by `ClockOverride`.
+pub struct MockClock;
+impl sp_runtime::traits::BlockNumberProvider for MockClock {
+	type BlockNumber = u64;
+	fn current_block_number() -> u64 {
+		ClockOverride::get().unwrap_or_else(System::block_number)
+	}
+}
+
+/// Rates of quote assets set in `QuoteRates`.
+pub struct MockOracle;
+impl pallet_task_auction::PriceOracle<u32> for MockOracle {
+	fn rate(asset: &u32) -> Option<FixedU128> {
+		QuoteRates::get().get(asset).copied()
+	}
+}
+
+/// Escrow of assets with free balances in `AssetBalances` and locked ones in `LockedAssets`.
+pub struct MockAssetEscrow;
+impl MockAssetEscrow {
+	fn debit(
+		balances: &mut std::collections::BTreeMap<(u32, u64), Balance>,
+		key: (u32, u64),
+		amount: Balance,
+	) -> sp_runtime::DispatchResult {
+		let balance = balances.entry(key).or_default();
+		*balance = balance
+			.checked_sub(amount)
+			.ok_or(sp_runtime::DispatchError::Other("insufficient asset balance"))?;
+		Ok(())
+	}
+}
+
+impl pallet_task_auction::AssetEscrowHandler<u64, u32, Balance> for MockAssetEscrow {
+	fn lock(asset: &u32, who: &u64, amount: Balance) -> sp_runtime::DispatchResult {
+		let mut free = AssetBalances::get();
+		Self::debit(&mut free, (*asset, *who), amount)?;
+		AssetBalances::set(free);
+		let mut locked = LockedAssets::get();
+		*locked.entry((*asset, *who)).or_default() += amount;
+		LockedAssets::set(locked);
+		Ok(())
+	}
+
+	fn unlock(asset: &u32, who: &u64, amount: Balance) -> sp_runtime::DispatchResult {
+		let mut locked = LockedAssets::get();
+		Self::debit(&mut locked, (*asset, *who), amount)?;
+		LockedAssets::set(locked);
+		let mut free = AssetBalances::get();
+		*free.entry((*asset, *who)).or_default() += amount;
+		AssetBalances::set(free);
+		Ok(())
+	}
+
+	fn transfer(asset: &u32, from: &u64, to: &u64, amount: Balance) -> sp_runtime::DispatchResult {
+		let mut free = AssetBalances::get();
+		Self::debit(&mut free, (*asset, *from), amount)?;
+		*free.entry((*asset, *to)).or_default() += amount;
+		AssetBalances::set(free);
+		Ok(())
+	}
+
+	fn transfer_locked(
+		asset: &u32,
+		from: &u64,
+		to: &u64,
+		amount: Balance,
+	) -> sp_runtime::DispatchResult {
+		let mut locked = LockedAssets::get();
+		Self::debit(&mut locked, (*asset, *from), amount)?;
+		*locked.entry((*asset, *to)).or_default() += amount;
+		LockedAssets::set(locked);
+		Ok(())
+	}
+}
+
+/// Accounts paired in `ProxyPairs` as delegator and proxy.
+pub struct MockLinks;
+impl pallet_task_auction::AccountLinks<u64> for MockLinks {
+	fn are_linked(a: &u64, b: &u64) -> bool {
+		ProxyPairs::get().iter().any(|pair| *pair == (*a, *b) || *pair == (*b, *a))
+	}
 }
 
 /// Configure the task auction pallet.
@@ -84,9 +360,94 @@ impl pallet_task_auction::Config for Test {
 	type Event = Event;
 	type Currency = Balances;
 	type MinBounty = ExistentialDeposit;
+	type MaxBounty = MaxBounty;
 	type MinDeposit = ExistentialDeposit;
 	type MinBidRatio = MinBidRatio;
+	type MinBidPrice = MinBidPrice;
 	type MaxDataSize = MaxDataSize;
+	type ListingFeeBase = ListingFeeBase;
+	type ListingFeePerByte = ListingFeePerByte;
+	type ListingRefundPeriod = ListingRefundPeriod;
+	type ListingFeeDestination = ();
+	type RetractCooldown = RetractCooldown;
+	type OwnerInactivity = OwnerInactivity;
+	type MaxAttachments = MaxAttachments;
+	type MaxAttachmentSize = MaxAttachmentSize;
+	type AttachmentDepositPerByte = AttachmentDepositPerByte;
+	type MaxBundleSize = MaxBundleSize;
+	type DeferPayouts = DeferPayouts;
+	type MaxBidsPerAuction = MaxBidsPerAuction;
+	type MaxLocationLocks = MaxLocationLocks;
+	type RatingPeriod = RatingPeriod;
+	type AppealPeriod = AppealPeriod;
+	type MaxTemplates = MaxTemplates;
+	type MaxTemplateNameLength = MaxTemplateNameLength;
+	type TemplateDeposit = TemplateDeposit;
+	type MaxProjects = MaxProjects;
+	type MaxProjectNameLength = MaxProjectNameLength;
+	type MaxAuctionsPerProject = MaxAuctionsPerProject;
+	type MaxMilestones = MaxMilestones;
+	type ProjectDeposit = ProjectDeposit;
+	type ExpectedBlockTime = ExpectedBlockTime;
+	type CreationGracePeriod = CreationGracePeriod;
+	type CheckCapacityAtBid = CheckCapacityAtBid;
+	type EmitSettlementSnapshots = EmitSettlementSnapshots;
+	type MaxOpenDisputesPerArbitrator = MaxOpenDisputesPerArbitrator;
+	type MaxDeadlinesPerBlock = MaxDeadlinesPerBlock;
+	type MaxPromoted = MaxPromoted;
+	type OpenAuctionTimeout = OpenAuctionTimeout;
+	type AbandonmentWindow = AbandonmentWindow;
+	type AbandonmentPenalty = AbandonmentPenalty;
+	type CollusionReviewPeriod = CollusionReviewPeriod;
+	type MaxBoostersPerAuction = MaxBoostersPerAuction;
+	type CancelConsolation = CancelConsolation;
+	type MaxConsolation = MaxConsolation;
+	type MaxConsoledBidders = MaxConsoledBidders;
+	type ArbitrationPeriod = ArbitrationPeriod;
+	type DefaultJudgmentShare = DefaultJudgmentShare;
+	type DisputeRateWindow = DisputeRateWindow;
+	type MaxDisputeRate = MaxDisputeRate;
+	type MinDisputeSample = MinDisputeSample;
+	type PalletId = TaskAuctionPalletId;
+	type DisputeRetention = DisputeRetention;
+	type Decimals = Decimals;
+	type StatsPeriod = StatsPeriod;
+	type TopUpPeriod = TopUpPeriod;
+	type PriceModel = pallet_task_auction::DistancePrice;
+	type DecrementSchedule = pallet_task_auction::ConstantDecrement;
+	type Escrow = pallet_task_auction::ReserveEscrow<Balances>;
+	type Telemetry = ();
+	type DepositReceipts = MockReceipts;
+	type UnixTime = MockTime;
+	type BlockNumberProvider = MockClock;
+	type ArbitratorSet = MockArbitrators;
+	type Vesting = MockVesting;
+	type ForceOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type GovernanceOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type ArbitrationOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type AttestationOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type QuoteSignature = sp_runtime::testing::TestSignature;
+	type QuoteSigner = sp_runtime::testing::UintAuthorityId;
+	type ReceiptDestination = u32;
+	type RemoteOrigin = EnsureRemote;
+	type ReceiptSender = MockReceiptSender;
+	type AccountLinks = MockLinks;
+	type QuoteAsset = u32;
+	type PriceOracle = MockOracle;
+	type AssetId = u32;
+	type AssetEscrow = MockAssetEscrow;
+	type WeightInfo = ();
+}
+
+// Stand-in for another pallet slashing reserved balances, such as staking.
+pub struct CompetingSlasher;
+impl CompetingSlasher {
+	// slash up to amount from the reserves of who, returning the amount slashed
+	pub fn slash(who: &u64, amount: Balance) -> Balance {
+		use frame_support::traits::ReservableCurrency;
+		let (_, unslashed) = Balances::slash_reserved(who, amount);
+		amount - unslashed
+	}
 }
 
 // Build genesis storage according to the mock runtime.
@@ -102,3 +463,75 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 	ext.execute_with(|| System::set_block_number(1));
 	ext
 }
+
+/// Assert the free and reserved balance of each account.
+macro_rules! assert_balances {
+	($($who:expr => ($free:expr, $reserved:expr)),+ $(,)?) => {
+		$(
+			assert_eq!(
+				(
+					$crate::mock::Balances::free_balance(&$who),
+					$crate::mock::Balances::reserved_balance(&$who)
+				),
+				($free, $reserved),
+				"free and reserved balance of {:#x}",
+				$who
+			);
+		)+
+	};
+}
+
+/// Advance to block `n`, running the hooks of every block on the way.
+pub fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		let block = System::block_number();
+		TaskAuction::on_idle(block, Weight::max_value());
+		TaskAuction::on_finalize(block);
+		System::on_finalize(block);
+		System::set_block_number(block + 1);
+		System::on_initialize(block + 1);
+		TaskAuction::on_initialize(block + 1);
+	}
+}
+
+/// Key of the auction created last.
+pub fn created_key() -> pallet_task_auction::AuctionId<u64, u64> {
+	System::events()
+		.into_iter()
+		.rev()
+		.find_map(|record| match record.event {
+			Event::TaskAuction(pallet_task_auction::Event::Created { auction_key, .. }) =>
+				Some(auction_key),
+			_ => None,
+		})
+		.unwrap()
+}
+
+/// Create an auction of `owner` arbitrated by 0xB, leaving the nonce of `owner` as is.
+pub fn create_auction(
+	owner: u64,
+	bounty: Balance,
+	deposit: Balance,
+	terminal_block: u64,
+) -> pallet_task_auction::AuctionId<u64, u64> {
+	assert_ok!(TaskAuction::create(
+		Origin::signed(owner),
+		0xB,
+		bounty,
+		deposit,
+		terminal_block,
+		vec![0; 8],
+		Default::default()
+	));
+	created_key()
+}
+
+/// Create an auction of 0xA arbitrated by 0xB and advance until the bid of `bidder` is assigned.
+pub fn assign_auction(bidder: u64, price: Balance) -> pallet_task_auction::AuctionId<u64, u64> {
+	let terminal_block = System::block_number() + 4;
+	let auction_key = create_auction(0xA, 1000, 500, terminal_block);
+	System::inc_account_nonce(&0xA);
+	assert_ok!(TaskAuction::bid(Origin::signed(bidder), auction_key, price));
+	run_to_block(terminal_block);
+	auction_key
+}
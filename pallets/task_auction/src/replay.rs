@@ -0,0 +1,80 @@
+//! Decision traces of settlements, exported as fixtures so that reported fund
+//! discrepancies can be replayed against the pallet.
+
+use crate::pallet::{AuctionId, BidId, Bids, Config, Pallet, PriceModel};
+use frame_support::{sp_runtime::traits::BlockNumberProvider, traits::Currency};
+use serde::{Deserialize, Serialize};
+
+type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+type AuctionKey<T> =
+	AuctionId<<T as frame_system::Config>::AccountId, <T as frame_system::Config>::Index>;
+type BidKey<T> = BidId<<T as frame_system::Config>::AccountId>;
+
+// Funds paid from one account to another as part of a settlement.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct Transfer<AccountId, Balance> {
+	pub from: AccountId,
+	pub to: AccountId,
+	pub amount: Balance,
+}
+
+// Inputs, intermediate values and resulting transfers of confirming an auction.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct SettlementFixture<AccountId, Balance, BlockNumber> {
+	pub owner: AccountId,
+	pub arbitrator: AccountId,
+	pub bounty: Balance,
+	pub deposit: Balance,
+	pub initial_block: BlockNumber,
+	pub terminal_block: BlockNumber,
+	pub settled_block: BlockNumber,
+	// bids in the order they were placed, ending with the assigned worker
+	pub bids: Vec<(AccountId, Balance)>,
+	pub base_price: Balance,
+	pub settlement_price: Balance,
+	pub listing_fee: Balance,
+	pub transfers: Vec<Transfer<AccountId, Balance>>,
+}
+
+// trace of confirming an auction at the current block, None if it isn't assigned
+pub fn trace_confirmation<T: Config>(
+	auction_key: &AuctionKey<T>,
+) -> Option<SettlementFixture<T::AccountId, BalanceOf<T>, T::BlockNumber>> {
+	let auction = Pallet::<T>::auctions(auction_key)?;
+	let (top_key, top_price) = Bids::<T>::get(auction_key, BidKey::<T>::default())?;
+	if !auction.is_assigned(&top_key.0, top_price) {
+		return None
+	}
+	let settled_block = T::BlockNumberProvider::current_block_number();
+	let base_price = T::PriceModel::base_price(&auction, &top_key.0, settled_block);
+	let settlement_price =
+		Pallet::<T>::settlement_price(auction_key, &auction, &top_key, top_price);
+	// walk the bid stack down from the top bid
+	let mut bids = Vec::new();
+	let mut bid_key = top_key.clone();
+	while bid_key != BidKey::<T>::default() {
+		let (prev_key, price) = Bids::<T>::get(auction_key, &bid_key)?;
+		bids.push((bid_key.0, price));
+		bid_key = prev_key;
+	}
+	bids.reverse();
+	Some(SettlementFixture {
+		owner: auction_key.0.clone(),
+		arbitrator: auction.arbitrator,
+		bounty: auction.bounty,
+		deposit: auction.deposit,
+		initial_block: auction.initial_block,
+		terminal_block: auction.terminal_block,
+		settled_block,
+		bids,
+		base_price,
+		settlement_price,
+		listing_fee: auction.listing_fee,
+		transfers: vec![Transfer {
+			from: auction_key.0.clone(),
+			to: top_key.0,
+			amount: settlement_price,
+		}],
+	})
+}
@@ -0,0 +1,51 @@
+//! Size bounds and proof size estimates of the pallet's storage items, for parachain teams
+//! budgeting block space.
+
+use crate::pallet::{Config, Pallet};
+use frame_support::traits::StorageInfoTrait;
+use serde::Serialize;
+
+// Size of a full branch node of the base-16 state trie, with 16 child hashes and a header.
+const BRANCH_NODE_SIZE: u32 = 16 * 32 + 3;
+// Entries assumed to share the trie with an item whose number of values is unbounded.
+const UNBOUNDED_VALUES: u32 = u32::MAX;
+
+// Bounds of one storage item, None where the item has no encoded length bound.
+#[derive(Serialize, Clone, PartialEq, Eq, Debug)]
+pub struct StorageEstimate {
+	pub pallet: String,
+	pub storage: String,
+	pub max_values: Option<u32>,
+	// largest encoded key and value of one entry
+	pub max_size: Option<u32>,
+	// proof size of reading one entry, including the branch nodes above it
+	pub pov_per_read: Option<u32>,
+}
+
+// bounds and proof size estimates of all storage items of the pallet
+pub fn storage_estimates<T: Config>() -> Vec<StorageEstimate> {
+	Pallet::<T>::storage_info()
+		.into_iter()
+		.map(|info| StorageEstimate {
+			pallet: String::from_utf8_lossy(&info.pallet_name).into_owned(),
+			storage: String::from_utf8_lossy(&info.storage_name).into_owned(),
+			max_values: info.max_values,
+			max_size: info.max_size,
+			pov_per_read: info.max_size.map(|max_size| {
+				max_size +
+					trie_depth(info.max_values.unwrap_or(UNBOUNDED_VALUES)) * BRANCH_NODE_SIZE
+			}),
+		})
+		.collect()
+}
+
+// branch nodes on the path to an entry of a balanced trie holding the given number of values
+fn trie_depth(values: u32) -> u32 {
+	let mut depth = 0;
+	let mut capacity = 1u64;
+	while capacity < values as u64 {
+		capacity *= 16;
+		depth += 1;
+	}
+	depth
+}
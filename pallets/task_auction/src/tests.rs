@@ -1,5 +1,12 @@
-use crate::{mock::*, Error};
+use crate::{
+	mock::*, AuctionId, BidId, CreateOptions, DataSizeClass, Deliverable, Error, Location, Outcome,
+	Quote, SettlementMode, SignedQuote, TaskRequirements, TermsSource, OPERATOR_CANCEL,
+	OPERATOR_EXTEND,
+};
+use codec::Encode;
 use frame_support::{assert_err, assert_ok};
+use pallet_task_auction_runtime_api::AssetDisplay;
+use sp_runtime::{testing::TestSignature, FixedPointNumber};
 
 type AuctionEvent = crate::Event<Test>;
 
@@ -15,28 +22,88 @@ fn create() {
 	new_test_ext().execute_with(|| {
 		let test_data = vec![1, 2, 3];
 		assert_err!(
-			TaskAuction::create(Origin::signed(0xA), 0xB, 1000, 500, 5, vec![0; 2000]),
+			TaskAuction::create(
+				Origin::signed(0xA),
+				0xA,
+				1000,
+				500,
+				5,
+				test_data.clone(),
+				Default::default()
+			),
+			Error::<Test>::SelfArbitrationProhibited
+		);
+		assert_err!(
+			TaskAuction::create(
+				Origin::signed(0xA),
+				0xB,
+				1000,
+				500,
+				5,
+				vec![0; 2000],
+				Default::default()
+			),
 			Error::<Test>::MaxDataSizeExceeded
 		);
 		assert_err!(
-			TaskAuction::create(Origin::signed(0xA), 0xB, 100, 500, 5, test_data.clone()),
+			TaskAuction::create(
+				Origin::signed(0xA),
+				0xB,
+				100,
+				500,
+				5,
+				test_data.clone(),
+				Default::default()
+			),
 			Error::<Test>::MinBountyRequired
 		);
 		assert_err!(
-			TaskAuction::create(Origin::signed(0xA), 0xB, 1000, 50, 5, test_data.clone()),
+			TaskAuction::create(
+				Origin::signed(0xA),
+				0xB,
+				1000,
+				50,
+				5,
+				test_data.clone(),
+				Default::default()
+			),
 			Error::<Test>::MinDepositRequired
 		);
 		assert_err!(
-			TaskAuction::create(Origin::signed(0xA), 0xB, 20000, 500, 5, test_data.clone()),
+			TaskAuction::create(
+				Origin::signed(0xA),
+				0xB,
+				20000,
+				500,
+				5,
+				test_data.clone(),
+				Default::default()
+			),
 			pallet_balances::Error::<Test>::InsufficientBalance
 		);
 		assert_err!(
-			TaskAuction::create(Origin::signed(0xA), 0xB, 500, 20000, 5, test_data.clone()),
+			TaskAuction::create(
+				Origin::signed(0xA),
+				0xB,
+				500,
+				20000,
+				5,
+				test_data.clone(),
+				Default::default()
+			),
 			pallet_balances::Error::<Test>::InsufficientBalance
 		);
 
 		// check successful creation
-		assert_ok!(TaskAuction::create(Origin::signed(0xA), 0xB, 1000, 500, 5, test_data.clone()));
+		assert_ok!(TaskAuction::create(
+			Origin::signed(0xA),
+			0xB,
+			1000,
+			500,
+			5,
+			test_data.clone(),
+			Default::default()
+		));
 
 		if let AuctionEvent::Created { auction_key, bounty, terminal_block } =
 			get_auction_event().unwrap()
@@ -51,8 +118,8 @@ fn create() {
 			assert_eq!(auction.bounty, 1000);
 			assert_eq!(auction.deposit, 500);
 			assert_eq!(auction.terminal_block, 5);
-			assert_eq!(auction.data, vec![1, 2, 3]);
-			assert!(TaskAuction::bids(auction_key, (0, 0)).is_none());
+			assert_eq!(TaskAuction::auction_data(auction_key).into_inner(), vec![1, 2, 3]);
+			assert!(TaskAuction::bids(auction_key, BidId(0, 0)).is_none());
 		} else {
 			panic!("wrong event type")
 		}
@@ -62,12 +129,7 @@ fn create() {
 #[test]
 fn extend() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(TaskAuction::create(Origin::signed(0xA), 0xB, 1000, 500, 5, vec![0; 8]));
-
-		let auction_key = match get_auction_event().unwrap() {
-			AuctionEvent::Created { auction_key, .. } => auction_key,
-			_ => panic!("wrong event"),
-		};
+		let auction_key = create_auction(0xA, 1000, 500, 5);
 
 		// input checks
 		assert_err!(
@@ -76,14 +138,14 @@ fn extend() {
 		);
 		assert_eq!(Balances::reserved_balance(&0xA), 1500);
 		assert_err!(
-			TaskAuction::extend(Origin::signed(0xA), (0, 0), 2000, 6),
+			TaskAuction::extend(Origin::signed(0xA), AuctionId(0, 0), 2000, 6),
 			Error::<Test>::AuctionKeyNotFound
 		);
 		assert_eq!(Balances::reserved_balance(&0xA), 1500);
 
 		assert_err!(
 			TaskAuction::extend(Origin::signed(0xA), auction_key.clone(), 500, 6),
-			Error::<Test>::MinBountyRequired
+			Error::<Test>::BountyIncrementRequired
 		);
 		assert_eq!(Balances::reserved_balance(&0xA), 1500);
 
@@ -113,14 +175,19 @@ fn bid() {
 	new_test_ext().execute_with(|| {
 		let test_data = vec![1, 2, 3];
 		assert_err!(
-			TaskAuction::bid(Origin::signed(0xA), (1, 1), 100),
+			TaskAuction::bid(Origin::signed(0xA), AuctionId(1, 1), 100),
 			Error::<Test>::AuctionKeyNotFound
 		);
-		assert_ok!(TaskAuction::create(Origin::signed(0xA), 0xB, 1000, 500, 5, test_data));
-		let auction_key = match get_auction_event().unwrap() {
-			AuctionEvent::Created { auction_key, .. } => auction_key,
-			_ => panic!("wrong event"),
-		};
+		assert_ok!(TaskAuction::create(
+			Origin::signed(0xA),
+			0xB,
+			1000,
+			500,
+			5,
+			test_data,
+			Default::default()
+		));
+		let auction_key = created_key();
 		assert_err!(
 			TaskAuction::bid(Origin::signed(0xA), auction_key, 100),
 			Error::<Test>::OriginProhibited
@@ -130,30 +197,38 @@ fn bid() {
 			Error::<Test>::OriginProhibited
 		);
 
+		// reject dust bids
+		assert_err!(
+			TaskAuction::bid(Origin::signed(0xC), auction_key, 0),
+			Error::<Test>::BidTooLow
+		);
 		// allow bids that are higher than bounty
-		assert!(TaskAuction::bids(auction_key, (0, 0)).is_none());
+		assert!(TaskAuction::bids(auction_key, BidId(0, 0)).is_none());
 		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 1100));
 		// first bid within bounty
 		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 300));
 		assert_eq!(Balances::reserved_balance(&0xC), 500);
-		assert!(TaskAuction::bids(auction_key, (0, 0)).is_some());
+		assert!(TaskAuction::bids(auction_key, BidId(0, 0)).is_some());
 		// reject bids higher than previous bid
 		assert_err!(
 			TaskAuction::bid(Origin::signed(0xD), auction_key, 400),
 			Error::<Test>::MinBidRatioRequired
 		);
-		assert!(TaskAuction::bids(auction_key, (0, 0)).is_some());
+		assert!(TaskAuction::bids(auction_key, BidId(0, 0)).is_some());
 
 		for i in 1..10 {
 			let price = (300 - (i * 6)) as u128;
 			assert_ok!(TaskAuction::bid(Origin::signed(0xD), auction_key, price));
-			assert_eq!(TaskAuction::bids(auction_key, (0, 0)).unwrap().1, price);
-			if let AuctionEvent::Bid { auction_key: _, bid_key, price: _ } =
-				get_auction_event().unwrap()
-			{
-				assert_eq!(bid_key, (0xD, i + 2));
+			assert_eq!(TaskAuction::bids(auction_key, BidId(0, 0)).unwrap().1, price);
+			// bids undercutting the bidder's own bid update it in place
+			match get_auction_event().unwrap() {
+				AuctionEvent::Bid { bid_key, .. } | AuctionEvent::BidImproved { bid_key, .. } =>
+					assert_eq!(bid_key, BidId(0xD, 2)),
+				_ => panic!("wrong event"),
 			}
 		}
+		assert_eq!(TaskAuction::bids(auction_key, BidId(0xD, 2)).unwrap().0, BidId(0xC, 1));
+		assert!(TaskAuction::bids(auction_key, BidId(0xD, 3)).is_none());
 		assert_eq!(Balances::reserved_balance(&0xC), 0);
 		assert_eq!(Balances::reserved_balance(&0xD), 500);
 		System::set_block_number(3);
@@ -169,56 +244,39 @@ fn retract() {
 	new_test_ext().execute_with(|| {
 		// no auction yet
 		assert_err!(
-			TaskAuction::retract(Origin::signed(0xC), (0, 0)),
+			TaskAuction::retract(Origin::signed(0xC), AuctionId(0, 0), None),
 			Error::<Test>::AuctionKeyNotFound
 		);
 		// create auction
 		let deposit = 500;
-		assert_ok!(TaskAuction::create(Origin::signed(0xA), 0xB, 1000, deposit, 5, vec![0; 8]));
-		let auction_key = match get_auction_event().unwrap() {
-			AuctionEvent::Created { auction_key, .. } => auction_key,
-			_ => panic!("wrong event"),
-		};
-		// insert 10 bids from C
-		for i in 0..10 {
+		let auction_key = create_auction(0xA, 1000, deposit, 5);
+		// insert 20 bids alternating between C and D
+		let bidder = |i: u64| if i % 2 == 0 { 0xC } else { 0xD };
+		for i in 0..20 {
 			let price = (500 - (i * 10)) as u128;
-			assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, price));
-			assert_eq!(Balances::reserved_balance(&0xC), deposit);
-		}
-		// insert 10 bids from D
-		for i in 10..20 {
-			let price = (500 - (i * 10)) as u128;
-			assert_ok!(TaskAuction::bid(Origin::signed(0xD), auction_key, price));
-			assert_eq!(Balances::reserved_balance(&0xD), deposit);
-			assert_eq!(Balances::reserved_balance(&0xC), 0);
+			assert_ok!(TaskAuction::bid(Origin::signed(bidder(i)), auction_key, price));
+			assert_eq!(Balances::reserved_balance(&bidder(i)), deposit);
+			assert_eq!(Balances::reserved_balance(&bidder(i + 1)), 0);
 		}
 		// C can't retract because top bid is from D
 		assert_err!(
-			TaskAuction::retract(Origin::signed(0xC), auction_key),
+			TaskAuction::retract(Origin::signed(0xC), auction_key, None),
 			Error::<Test>::TopBidRequired
 		);
 
-		// retract 10 bids from D
-		assert_eq!(Balances::reserved_balance(&0xD), deposit);
-		assert_eq!(Balances::reserved_balance(&0xC), 0);
-		for _ in 0..10 {
-			assert_ok!(TaskAuction::retract(Origin::signed(0xD), auction_key));
-		}
-
-		// retract 10 bids from C
-		assert_eq!(Balances::reserved_balance(&0xC), deposit);
-		assert_eq!(Balances::reserved_balance(&0xD), 0);
-		for _ in 0..10 {
-			assert_ok!(TaskAuction::retract(Origin::signed(0xC), auction_key));
+		// retract all 20 bids, each returning the top to the previous bid
+		for i in (0..20).rev() {
+			assert_ok!(TaskAuction::retract(Origin::signed(bidder(i)), auction_key, None));
+			assert_eq!(Balances::reserved_balance(&bidder(i)), 0);
+			if i > 0 {
+				assert_eq!(Balances::reserved_balance(&bidder(i - 1)), deposit);
+			}
 		}
-		assert_eq!(Balances::reserved_balance(&0xC), 0);
-		assert_eq!(Balances::reserved_balance(&0xD), 0);
-		assert_eq!(Balances::free_balance(&0xC), 10000);
-		assert_eq!(Balances::free_balance(&0xD), 10000);
+		assert_balances!(0xC => (10000, 0), 0xD => (10000, 0));
 
 		// auction has no bids left to retract
 		assert_err!(
-			TaskAuction::retract(Origin::signed(0xB), auction_key),
+			TaskAuction::retract(Origin::signed(0xB), auction_key, None),
 			Error::<Test>::TopBidRequired
 		);
 
@@ -228,15 +286,13 @@ fn retract() {
 		System::set_block_number(10);
 
 		// retracting bid from assigned auction results in losing deposit
-		assert_ok!(TaskAuction::retract(Origin::signed(0xD), auction_key));
-		assert_eq!(Balances::reserved_balance(&0xD), 0);
-		assert_eq!(Balances::free_balance(&0xD), 10000 - deposit);
+		assert_ok!(TaskAuction::retract(Origin::signed(0xD), auction_key, None));
+		assert_balances!(0xD => (10000 - deposit, 0));
 
 		// retracting a disputed auction also results in losing deposit
 		assert_ok!(TaskAuction::dispute(Origin::signed(0xC), auction_key));
-		assert_ok!(TaskAuction::retract(Origin::signed(0xC), auction_key));
-		assert_eq!(Balances::reserved_balance(&0xC), 0);
-		assert_eq!(Balances::free_balance(&0xC), 10000 - deposit);
+		assert_ok!(TaskAuction::retract(Origin::signed(0xC), auction_key, None));
+		assert_balances!(0xC => (10000 - deposit, 0));
 	})
 }
 
@@ -245,16 +301,12 @@ fn confirm() {
 	new_test_ext().execute_with(|| {
 		// non existing auction
 		assert_err!(
-			TaskAuction::confirm(Origin::signed(0xC), (0, 0)),
+			TaskAuction::confirm(Origin::signed(0xC), AuctionId(0, 0)),
 			Error::<Test>::AuctionKeyNotFound
 		);
 		// create an auction
 		let deposit = 500;
-		assert_ok!(TaskAuction::create(Origin::signed(0xA), 0xB, 1000, deposit, 5, vec![0; 8]));
-		let auction_key = match get_auction_event().unwrap() {
-			AuctionEvent::Created { auction_key, .. } => auction_key,
-			_ => panic!("wrong event"),
-		};
+		let auction_key = create_auction(0xA, 1000, deposit, 5);
 		// only own of the auction can confirm
 		assert_err!(
 			TaskAuction::confirm(Origin::signed(0xC), auction_key),
@@ -280,13 +332,16 @@ fn confirm() {
 		// expect success
 		assert_ok!(TaskAuction::confirm(Origin::signed(0xA), auction_key));
 		// check payements
-		assert_eq!(Balances::reserved_balance(&0xA), 0);
-		assert_eq!(Balances::reserved_balance(&0xC), 0);
-		assert_eq!(Balances::free_balance(&0xA), 10000 - pay);
-		assert_eq!(Balances::free_balance(&0xC), 10000 + pay);
+		assert_balances!(0xA => (10000 - pay, 0), 0xC => (10000 + pay, 0));
 		// auction should be deleted after transaction
 		assert!(TaskAuction::auctions(auction_key).is_none());
-		assert!(TaskAuction::bids(auction_key, (0, 0)).is_none());
+		assert!(TaskAuction::bids(auction_key, BidId(0, 0)).is_none());
+		// settlement receipt is kept in child trie
+		let receipt = TaskAuction::receipt(&auction_key).unwrap();
+		assert_eq!(receipt.worker, Some(0xC));
+		assert_eq!(receipt.price, pay);
+		assert_eq!(receipt.outcome, Outcome::Confirmed);
+		assert_eq!(receipt.settled_block, 10);
 	})
 }
 
@@ -295,53 +350,37 @@ fn cancel() {
 	new_test_ext().execute_with(|| {
 		// non existing auction
 		assert_err!(
-			TaskAuction::cancel(Origin::signed(0xC), (0, 0)),
+			TaskAuction::cancel(Origin::signed(0xC), AuctionId(0, 0), None),
 			Error::<Test>::AuctionKeyNotFound
 		);
 		let deposit = 500;
-		assert_ok!(TaskAuction::create(Origin::signed(0xA), 0xB, 1000, deposit, 5, vec![0; 8]));
-		let auction_key = match get_auction_event().unwrap() {
-			AuctionEvent::Created { auction_key, .. } => auction_key,
-			_ => panic!("wrong event"),
-		};
+		let auction_key = create_auction(0xA, 1000, deposit, 5);
 		// only own of the auction can cancel
 		assert_err!(
-			TaskAuction::cancel(Origin::signed(0xC), auction_key),
+			TaskAuction::cancel(Origin::signed(0xC), auction_key, None),
 			Error::<Test>::OwnerRequired
 		);
 		// successful cancel with no bids
-		assert_ok!(TaskAuction::cancel(Origin::signed(0xA), auction_key));
-		assert_eq!(Balances::reserved_balance(&0xA), 0);
-		assert_eq!(Balances::free_balance(&0xA), 10000);
+		assert_ok!(TaskAuction::cancel(Origin::signed(0xA), auction_key, None));
+		assert_balances!(0xA => (10000, 0));
 		assert!(TaskAuction::auctions(auction_key).is_none());
-		assert!(TaskAuction::bids(auction_key, (0, 0)).is_none());
+		assert!(TaskAuction::bids(auction_key, BidId(0, 0)).is_none());
 
 		// make new auction
-		assert_ok!(TaskAuction::create(Origin::signed(0xA), 0xB, 1000, deposit, 5, vec![0; 8]));
-		let auction_key = match get_auction_event().unwrap() {
-			AuctionEvent::Created { auction_key, .. } => auction_key,
-			_ => panic!("wrong event"),
-		};
+		let auction_key = create_auction(0xA, 1000, deposit, 5);
 
 		// bid above bounty
 		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 1500));
 		assert_eq!(Balances::reserved_balance(&0xC), deposit);
 
 		// canceling auction with bids above bounty is okay, won't lose deposit
-		assert_ok!(TaskAuction::cancel(Origin::signed(0xA), auction_key));
-		assert_eq!(Balances::reserved_balance(&0xA), 0);
-		assert_eq!(Balances::reserved_balance(&0xC), 0);
-		assert_eq!(Balances::free_balance(&0xA), 10000);
-		assert_eq!(Balances::free_balance(&0xC), 10000);
+		assert_ok!(TaskAuction::cancel(Origin::signed(0xA), auction_key, None));
+		assert_balances!(0xA => (10000, 0), 0xC => (10000, 0));
 		assert!(TaskAuction::auctions(auction_key).is_none());
-		assert!(TaskAuction::bids(auction_key, (0, 0)).is_none());
+		assert!(TaskAuction::bids(auction_key, BidId(0, 0)).is_none());
 
 		// make new auction
-		assert_ok!(TaskAuction::create(Origin::signed(0xA), 0xB, 1000, deposit, 5, vec![0; 8]));
-		let auction_key = match get_auction_event().unwrap() {
-			AuctionEvent::Created { auction_key, .. } => auction_key,
-			_ => panic!("wrong event"),
-		};
+		let auction_key = create_auction(0xA, 1000, deposit, 5);
 
 		// query created auction
 		assert!(TaskAuction::auctions(auction_key).is_some());
@@ -353,19 +392,16 @@ fn cancel() {
 		// cannot cancel auction that has been assigned
 		System::set_block_number(10);
 		assert_err!(
-			TaskAuction::cancel(Origin::signed(0xA), auction_key),
+			TaskAuction::cancel(Origin::signed(0xA), auction_key, None),
 			Error::<Test>::AuctionAssigned
 		);
 		System::set_block_number(1);
 
 		// canceling auction with unassigned bids result in lost of deposit
-		assert_ok!(TaskAuction::cancel(Origin::signed(0xA), auction_key));
-		assert_eq!(Balances::reserved_balance(&0xA), 0);
-		assert_eq!(Balances::reserved_balance(&0xC), 0);
-		assert_eq!(Balances::free_balance(&0xA), 10000 - deposit);
-		assert_eq!(Balances::free_balance(&0xC), 10000 + deposit);
+		assert_ok!(TaskAuction::cancel(Origin::signed(0xA), auction_key, None));
+		assert_balances!(0xA => (10000 - deposit, 0), 0xC => (10000 + deposit, 0));
 		assert!(TaskAuction::auctions(auction_key).is_none());
-		assert!(TaskAuction::bids(auction_key, (0, 0)).is_none());
+		assert!(TaskAuction::bids(auction_key, BidId(0, 0)).is_none());
 	})
 }
 
@@ -374,20 +410,16 @@ fn dispute_arbitrate() {
 	new_test_ext().execute_with(|| {
 		// non existing auction
 		assert_err!(
-			TaskAuction::dispute(Origin::signed(0xC), (0, 0)),
+			TaskAuction::dispute(Origin::signed(0xC), AuctionId(0, 0)),
 			Error::<Test>::AuctionKeyNotFound
 		);
 		assert_err!(
-			TaskAuction::arbitrate(Origin::signed(0xC), (0, 0), false),
+			TaskAuction::arbitrate(Origin::signed(0xC), AuctionId(0, 0), false, None),
 			Error::<Test>::AuctionKeyNotFound
 		);
 		let deposit = 500;
 		let pay = 800;
-		assert_ok!(TaskAuction::create(Origin::signed(0xA), 0xB, 1000, deposit, 5, vec![0; 8]));
-		let auction_key = match get_auction_event().unwrap() {
-			AuctionEvent::Created { auction_key, .. } => auction_key,
-			_ => panic!("wrong event"),
-		};
+		let auction_key = create_auction(0xA, 1000, deposit, 5);
 		// cannot dispute auction with no bids
 		assert_err!(
 			TaskAuction::dispute(Origin::signed(0xA), auction_key),
@@ -395,7 +427,7 @@ fn dispute_arbitrate() {
 		);
 		// cannot arbitrate auction that is not in dispute
 		assert_err!(
-			TaskAuction::arbitrate(Origin::signed(0xB), auction_key, false),
+			TaskAuction::arbitrate(Origin::signed(0xB), auction_key, false, None),
 			Error::<Test>::AuctionNotDisputed
 		);
 		// make a bid
@@ -408,7 +440,7 @@ fn dispute_arbitrate() {
 		);
 		// only arbitrator can arbitrate
 		assert_err!(
-			TaskAuction::arbitrate(Origin::signed(0xC), auction_key, false),
+			TaskAuction::arbitrate(Origin::signed(0xC), auction_key, false, None),
 			Error::<Test>::OriginProhibited
 		);
 		// cannot dispute auction that has not been assigned
@@ -421,7 +453,7 @@ fn dispute_arbitrate() {
 
 		// cannot arbitrate auction that is not in dispute
 		assert_err!(
-			TaskAuction::arbitrate(Origin::signed(0xB), auction_key, false),
+			TaskAuction::arbitrate(Origin::signed(0xB), auction_key, false, None),
 			Error::<Test>::AuctionNotDisputed
 		);
 		// dispute auction
@@ -435,15 +467,14 @@ fn dispute_arbitrate() {
 
 		// successful arbitration task fulfilled
 		// owner pays bidder and loses deposit to arbitrator
-		assert_ok!(TaskAuction::arbitrate(Origin::signed(0xB), auction_key, true));
-		assert_eq!(Balances::reserved_balance(&0xA), 0);
-		assert_eq!(Balances::reserved_balance(&0xB), 0);
-		assert_eq!(Balances::reserved_balance(&0xC), 0);
-		assert_eq!(Balances::free_balance(&0xA), 10000 - deposit - pay);
-		assert_eq!(Balances::free_balance(&0xB), 10000 + deposit);
-		assert_eq!(Balances::free_balance(&0xC), 10000 + pay);
+		assert_ok!(TaskAuction::arbitrate(Origin::signed(0xB), auction_key, true, None));
+		assert_balances!(
+			0xA => (10000 - deposit - pay, 0),
+			0xB => (10000 + deposit, 0),
+			0xC => (10000 + pay, 0),
+		);
 		assert!(TaskAuction::auctions(auction_key).is_none());
-		assert!(TaskAuction::bids(auction_key, (0, 0)).is_none());
+		assert!(TaskAuction::bids(auction_key, BidId(0, 0)).is_none());
 	})
 }
 
@@ -451,11 +482,7 @@ fn dispute_arbitrate() {
 fn dispute_arbitrate_veto() {
 	new_test_ext().execute_with(|| {
 		let deposit = 500;
-		assert_ok!(TaskAuction::create(Origin::signed(0xA), 0xB, 1000, deposit, 5, vec![0; 8]));
-		let auction_key = match get_auction_event().unwrap() {
-			AuctionEvent::Created { auction_key, .. } => auction_key,
-			_ => panic!("wrong event"),
-		};
+		let auction_key = create_auction(0xA, 1000, deposit, 5);
 		// make a bid
 		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 800));
 		// wait until auction is assigned
@@ -464,14 +491,3290 @@ fn dispute_arbitrate_veto() {
 		assert_ok!(TaskAuction::dispute(Origin::signed(0xA), auction_key));
 		// successful arbitration task is not fulfilled
 		// owner doesn't pays bidder and bidder loses deposit to arbitrator
-		assert_ok!(TaskAuction::arbitrate(Origin::signed(0xB), auction_key, false));
+		assert_ok!(TaskAuction::arbitrate(Origin::signed(0xB), auction_key, false, None));
+		assert_balances!(
+			0xA => (10000, 0),
+			0xB => (10000 + deposit, 0),
+			0xC => (10000 - deposit, 0),
+		);
+		assert!(TaskAuction::auctions(auction_key).is_none());
+		assert!(TaskAuction::bids(auction_key, BidId(0, 0)).is_none());
+		let receipt = TaskAuction::receipt(&auction_key).unwrap();
+		assert_eq!(receipt.outcome, Outcome::Arbitrated { fulfilled: false });
+	})
+}
+
+#[test]
+fn localize() {
+	new_test_ext().execute_with(|| {
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		let location = Location { x: 0, y: 0 };
+		// only owner can localize
+		assert_err!(
+			TaskAuction::localize(Origin::signed(0xC), auction_key, location, 100),
+			Error::<Test>::OwnerRequired
+		);
+		assert_ok!(TaskAuction::localize(Origin::signed(0xA), auction_key, location, 100));
+		assert_eq!(TaskAuction::auctions(auction_key).unwrap().location, Some(location));
+		// worker 7 units away gets a premium of 700 on top of base price
+		assert_ok!(TaskAuction::register_worker(
+			Origin::signed(0xC),
+			Some(Location { x: 3, y: -4 })
+		));
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 800));
+		assert_ok!(TaskAuction::extend(Origin::signed(0xA), auction_key, 2000, 5));
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 700));
+		// bid within premium is immediately assigned
+		assert_err!(
+			TaskAuction::extend(Origin::signed(0xA), auction_key, 3000, 5),
+			Error::<Test>::AuctionAssigned
+		);
+		// worker can't move away to raise the premium of their bids
+		assert_err!(
+			TaskAuction::register_worker(Origin::signed(0xC), Some(Location { x: 9, y: 9 })),
+			Error::<Test>::WorkerLocationLocked
+		);
+		// workers can only bid on a bounded number of unsettled localized auctions
+		MaxLocationLocks::set(1);
+		let other_key = create_auction(0xD, 1000, 500, 5);
+		assert_ok!(TaskAuction::localize(Origin::signed(0xD), other_key, location, 100));
+		assert_err!(
+			TaskAuction::bid(Origin::signed(0xC), other_key, 800),
+			Error::<Test>::TooManyLocationLocks
+		);
+		assert_ok!(TaskAuction::dispute(Origin::signed(0xA), auction_key));
+		// can't relocate auction with existing bids
+		assert_err!(
+			TaskAuction::localize(Origin::signed(0xA), auction_key, location, 50),
+			Error::<Test>::AuctionHasBids
+		);
+		// location is unlocked once the auction is settled
+		assert_ok!(TaskAuction::arbitrate(Origin::signed(0xB), auction_key, true));
+		assert_ok!(TaskAuction::register_worker(
+			Origin::signed(0xC),
+			Some(Location { x: 9, y: 9 })
+		));
+		assert_eq!(TaskAuction::location_locks(auction_key, 0xC), None);
+		assert_eq!(TaskAuction::location_lock_count(0xC), 0);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), other_key, 800));
+	})
+}
+
+#[test]
+fn force_transfer_ownership() {
+	new_test_ext().execute_with(|| {
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 800));
+		// only force origin can transfer ownership
+		assert_err!(
+			TaskAuction::force_transfer_ownership(Origin::signed(0xA), auction_key, 0xD),
+			sp_runtime::traits::BadOrigin
+		);
+		assert_err!(
+			TaskAuction::force_transfer_ownership(Origin::root(), auction_key, 0xB),
+			Error::<Test>::SelfArbitrationProhibited
+		);
+		// top bidder can't become the owner
+		assert_err!(
+			TaskAuction::force_transfer_ownership(Origin::root(), auction_key, 0xC),
+			Error::<Test>::OriginProhibited
+		);
+		assert_ok!(TaskAuction::force_transfer_ownership(Origin::root(), auction_key, 0xD));
+		let new_auction_key = AuctionId(0xD, auction_key.1);
+		// reserves and bids move to the new owner
 		assert_eq!(Balances::reserved_balance(&0xA), 0);
-		assert_eq!(Balances::reserved_balance(&0xB), 0);
-		assert_eq!(Balances::reserved_balance(&0xC), 0);
-		assert_eq!(Balances::free_balance(&0xA), 10000);
+		assert_eq!(Balances::reserved_balance(&0xD), 1500);
+		assert!(TaskAuction::auctions(auction_key).is_none());
+		assert!(TaskAuction::bids(auction_key, BidId(0, 0)).is_none());
+		assert_eq!(TaskAuction::bids(new_auction_key, BidId(0, 0)).unwrap().1, 800);
+		// new owner settles the auction
+		System::set_block_number(10);
+		assert_err!(
+			TaskAuction::confirm(Origin::signed(0xA), new_auction_key),
+			Error::<Test>::OwnerRequired
+		);
+		assert_ok!(TaskAuction::confirm(Origin::signed(0xD), new_auction_key));
+		assert_eq!(Balances::free_balance(&0xA), 10000 - 1500);
+		assert_eq!(Balances::free_balance(&0xD), 10000 + 1500 - 800);
+		assert_eq!(Balances::free_balance(&0xC), 10000 + 800);
+	})
+}
+
+#[test]
+fn listing_fee() {
+	use frame_support::traits::{LockableCurrency, WithdrawReasons};
+	new_test_ext().execute_with(|| {
+		ListingFeeBase::set(100);
+		ListingFeePerByte::set(10);
+		// listing fee is reserved along with bounty and deposit
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		assert_eq!(TaskAuction::auctions(auction_key).unwrap().listing_fee, 180);
+		assert_eq!(Balances::reserved_balance(&0xA), 1680);
+		// cancel halfway through refund period refunds half the fee
+		System::set_block_number(6);
+		assert_ok!(TaskAuction::cancel(Origin::signed(0xA), auction_key, None));
+		assert_balances!(0xA => (10000 - 90, 0));
+
+		// settled auctions pay the full fee
+		let auction_key = create_auction(0xA, 1000, 500, 10);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 800));
+		System::set_block_number(20);
+		assert_ok!(TaskAuction::confirm(Origin::signed(0xA), auction_key));
+		assert_balances!(0xA => (10000 - 90 - 180 - 800, 0));
+
+		// owners whose balance can't pay fees can't list
+		Balances::set_lock(*b"fee lock", &0xD, 10000, WithdrawReasons::FEE);
+		assert_err!(
+			TaskAuction::create(
+				Origin::signed(0xD),
+				0xB,
+				1000,
+				500,
+				5,
+				vec![0; 8],
+				Default::default()
+			),
+			pallet_balances::Error::<Test>::LiquidityRestrictions
+		);
+	})
+}
+
+#[test]
+fn retract_cooldown() {
+	new_test_ext().execute_with(|| {
+		RetractCooldown::set(3);
+		let auction_key = create_auction(0xA, 1000, 500, 10);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 900));
+		assert_ok!(TaskAuction::retract(Origin::signed(0xC), auction_key, None));
+		assert_eq!(TaskAuction::retracted_at(auction_key, 0xC), Some(1));
+		// retracted bidder must wait for cooldown, others may bid
+		assert_err!(
+			TaskAuction::bid(Origin::signed(0xC), auction_key, 900),
+			Error::<Test>::RetractCooldownActive
+		);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xD), auction_key, 900));
+		System::set_block_number(4);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 800));
+		// cooldowns are cleared on settlement
+		assert_ok!(TaskAuction::cancel(Origin::signed(0xA), auction_key, None));
+		assert!(TaskAuction::retracted_at(auction_key, 0xC).is_none());
+	})
+}
+
+#[test]
+fn max_next_bid() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(TaskAuction::max_next_bid(&AuctionId(0xA, 0)), None);
+		assert_eq!(TaskAuction::min_create_params(), (500, 500));
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		// any price is accepted before the first bid
+		assert_eq!(TaskAuction::max_next_bid(&auction_key), Some(u128::MAX));
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 900));
+		// 900 * 250 / 255 = 882.35
+		assert_eq!(TaskAuction::max_next_bid(&auction_key), Some(882));
+		assert_err!(
+			TaskAuction::bid(Origin::signed(0xD), auction_key, 883),
+			Error::<Test>::MinBidRatioRequired
+		);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xD), auction_key, 882));
+		// bid events carry the bound for the next bid
+		match get_auction_event().unwrap() {
+			AuctionEvent::Bid { max_next_bid, .. } => assert_eq!(max_next_bid, Some(864)),
+			_ => panic!("wrong event"),
+		}
+		// assigned auctions take no more bids
+		System::set_block_number(10);
+		assert_eq!(TaskAuction::max_next_bid(&auction_key), None);
+	})
+}
+
+#[test]
+fn second_price_settlement() {
+	new_test_ext().execute_with(|| {
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		assert_ok!(TaskAuction::set_settlement_mode(
+			Origin::signed(0xA),
+			auction_key,
+			SettlementMode::SecondPrice
+		));
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 900));
+		assert_ok!(TaskAuction::bid(Origin::signed(0xD), auction_key, 700));
+		// mode can't change after bids are made
+		assert_err!(
+			TaskAuction::set_settlement_mode(
+				Origin::signed(0xA),
+				auction_key,
+				SettlementMode::FirstPrice
+			),
+			Error::<Test>::AuctionHasBids
+		);
+		// lowest bidder gets paid the second lowest price
+		System::set_block_number(10);
+		assert_ok!(TaskAuction::confirm(Origin::signed(0xA), auction_key));
+		assert_eq!(Balances::free_balance(&0xA), 10000 - 900);
+		assert_eq!(Balances::free_balance(&0xD), 10000 + 900);
+
+		// single bid is paid its own price
+		create_auction(0xA, 1000, 500, 15);
+		assert_ok!(TaskAuction::set_settlement_mode(
+			Origin::signed(0xA),
+			auction_key,
+			SettlementMode::SecondPrice
+		));
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 700));
+		System::set_block_number(20);
+		assert_ok!(TaskAuction::confirm(Origin::signed(0xA), auction_key));
+		assert_eq!(Balances::free_balance(&0xC), 10000 + 700);
+	})
+}
+
+#[test]
+fn second_price_ignores_own_bids() {
+	new_test_ext().execute_with(|| {
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		assert_ok!(TaskAuction::set_settlement_mode(
+			Origin::signed(0xA),
+			auction_key,
+			SettlementMode::SecondPrice
+		));
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 950));
+		assert_ok!(TaskAuction::bid(Origin::signed(0xD), auction_key, 900));
+		// accepted bundle of D lands on top of its own earlier bid
+		assert_ok!(TaskAuction::bundle_bid(Origin::signed(0xD), vec![auction_key], 800));
+		assert_ok!(TaskAuction::accept_bundle(Origin::signed(0xA), 0xD));
+		assert_eq!(TaskAuction::bids(auction_key, BidId(0xD, 3)).unwrap().0, BidId(0xD, 2));
+		// the own bid of D doesn't compete, C has the best competing bid
+		assert_ok!(TaskAuction::confirm(Origin::signed(0xA), auction_key));
+		assert_balances!(0xA => (10000 - 950, 0), 0xC => (10000, 0), 0xD => (10000 + 950, 0));
+	})
+}
+
+#[test]
+fn escalate() {
+	new_test_ext().execute_with(|| {
+		let deposit = 500;
+		let auction_key = create_auction(0xA, 1000, deposit, 5);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 800));
+		// only assigned worker can escalate after owner inactivity window
+		System::set_block_number(10);
+		assert_err!(
+			TaskAuction::escalate(Origin::signed(0xA), auction_key),
+			Error::<Test>::TopBidRequired
+		);
+		assert_err!(
+			TaskAuction::escalate(Origin::signed(0xC), auction_key),
+			Error::<Test>::OwnerInactivityRequired
+		);
+		System::set_block_number(15);
+		assert_ok!(TaskAuction::escalate(Origin::signed(0xC), auction_key));
+		assert_err!(
+			TaskAuction::dispute(Origin::signed(0xA), auction_key),
+			Error::<Test>::AuctionDisputed
+		);
+		// owner pays arbitrator even if task is not fulfilled
+		assert_ok!(TaskAuction::arbitrate(Origin::signed(0xB), auction_key, false, None));
+		assert_eq!(Balances::free_balance(&0xA), 10000 - deposit);
 		assert_eq!(Balances::free_balance(&0xB), 10000 + deposit);
-		assert_eq!(Balances::free_balance(&0xC), 10000 - deposit);
+		assert_eq!(Balances::free_balance(&0xC), 10000);
+	})
+}
+
+#[test]
+fn base_price_rounding() {
+	new_test_ext().execute_with(|| {
+		let auction_key = create_auction(0xA, 1000, 500, 4);
+		let auction = TaskAuction::auctions(auction_key).unwrap();
+		// base price rounds down
+		let base_prices: Vec<u128> = (1..6)
+			.map(|block| {
+				System::set_block_number(block);
+				auction.get_base_price()
+			})
+			.collect();
+		assert_eq!(base_prices, vec![0, 333, 666, 1000, 1000]);
+
+		// assignment is monotone over blocks
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 334));
+		let mut was_assigned = false;
+		for block in 1..1000 {
+			System::set_block_number(block);
+			let assigned = auction.is_assigned(&0xC, 334);
+			assert!(assigned || !was_assigned);
+			was_assigned = assigned;
+		}
+		assert!(was_assigned);
+	})
+}
+
+#[test]
+fn joint_confirmation() {
+	new_test_ext().execute_with(|| {
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		assert_ok!(TaskAuction::require_joint_confirmation(Origin::signed(0xA), auction_key));
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 800));
+		System::set_block_number(10);
+		// owner confirmation alone doesn't settle
+		assert_ok!(TaskAuction::confirm(Origin::signed(0xA), auction_key));
+		assert!(TaskAuction::auctions(auction_key).unwrap().owner_confirmed);
+		assert_eq!(Balances::reserved_balance(&0xA), 1500);
+		// only the assigned worker can acknowledge
+		assert_err!(
+			TaskAuction::acknowledge_completion(Origin::signed(0xD), auction_key),
+			Error::<Test>::TopBidRequired
+		);
+		assert_ok!(TaskAuction::acknowledge_completion(Origin::signed(0xC), auction_key));
 		assert!(TaskAuction::auctions(auction_key).is_none());
-		assert!(TaskAuction::bids(auction_key, (0, 0)).is_none());
+		assert_eq!(Balances::free_balance(&0xA), 10000 - 800);
+		assert_eq!(Balances::free_balance(&0xC), 10000 + 800);
+
+		// worker may acknowledge first, then either side can still dispute
+		create_auction(0xA, 1000, 500, 15);
+		assert_ok!(TaskAuction::require_joint_confirmation(Origin::signed(0xA), auction_key));
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 800));
+		System::set_block_number(20);
+		assert_ok!(TaskAuction::acknowledge_completion(Origin::signed(0xC), auction_key));
+		assert!(TaskAuction::auctions(auction_key).unwrap().worker_acknowledged);
+		assert_ok!(TaskAuction::dispute(Origin::signed(0xA), auction_key));
+	})
+}
+
+#[test]
+fn indexed_events() {
+	new_test_ext().execute_with(|| {
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 800));
+		// auction events can be filtered by auction key topic
+		let topic = TaskAuction::topic(&auction_key);
+		assert_eq!(System::events().pop().unwrap().topics, vec![topic]);
+		assert_eq!(System::event_topics(topic).len(), 2);
 	})
 }
+
+#[test]
+fn estimate_create() {
+	new_test_ext().execute_with(|| {
+		ListingFeeBase::set(100);
+		ListingFeePerByte::set(10);
+		let estimate = TaskAuction::estimate_create(1000, 500, 8);
+		assert_eq!(estimate.listing_fee_base, 100);
+		assert_eq!(estimate.listing_fee_bytes, 80);
+		assert_eq!(estimate.reserve_total, 1680);
+		assert_eq!(estimate.weight, TaskAuction::create_weight());
+		// amounts are rendered with the decimals of the currency
+		assert_eq!(estimate.display, AssetDisplay::native(3));
+		assert_eq!(estimate.display.format(estimate.reserve_total), "1.68");
+		assert_eq!(estimate.display.format(2000), "2");
+		// estimate matches what create reserves
+		create_auction(0xA, 1000, 500, 5);
+		assert_eq!(Balances::reserved_balance(&0xA), estimate.reserve_total);
+	})
+}
+
+#[test]
+fn report_arbitrator() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TaskAuction::stake_arbitrator(Origin::signed(0xB), 2000));
+		assert_eq!(TaskAuction::arbitrator_stakes(0xB), 2000);
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		// only arbitrated auctions can be reported
+		assert_err!(
+			TaskAuction::report_arbitrator(Origin::signed(0xA), auction_key),
+			Error::<Test>::AuctionKeyNotFound
+		);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 800));
+		System::set_block_number(10);
+		assert_ok!(TaskAuction::dispute(Origin::signed(0xA), auction_key));
+		assert_ok!(TaskAuction::arbitrate(Origin::signed(0xB), auction_key, false, None));
+		// only parties of the auction can report
+		assert_err!(
+			TaskAuction::report_arbitrator(Origin::signed(0xD), auction_key),
+			Error::<Test>::OriginProhibited
+		);
+		assert_ok!(TaskAuction::report_arbitrator(Origin::signed(0xC), auction_key));
+		assert_err!(
+			TaskAuction::report_arbitrator(Origin::signed(0xA), auction_key),
+			Error::<Test>::ReportExists
+		);
+		// stake is locked pending governance decision
+		assert_err!(
+			TaskAuction::unstake_arbitrator(Origin::signed(0xB)),
+			Error::<Test>::ArbitratorReported
+		);
+		assert_err!(
+			TaskAuction::resolve_report(Origin::signed(0xA), 0xB, auction_key, true),
+			sp_runtime::traits::BadOrigin
+		);
+		assert_ok!(TaskAuction::resolve_report(Origin::root(), 0xB, auction_key, true));
+		assert_eq!(TaskAuction::arbitrator_stakes(0xB), 0);
+		assert_eq!(Balances::reserved_balance(&0xB), 0);
+		assert_eq!(Balances::free_balance(&0xB), 10000 + 500 - 2000);
+		assert_eq!(Balances::free_balance(&0xC), 10000 - 500 + 2000);
+	})
+}
+
+#[test]
+fn equal_price_bids() {
+	new_test_ext().execute_with(|| {
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		// earlier of two equal bids in the same block wins
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 800));
+		assert_err!(
+			TaskAuction::bid(Origin::signed(0xD), auction_key, 800),
+			Error::<Test>::BidPriceTied
+		);
+		assert_err!(
+			TaskAuction::bid(Origin::signed(0xC), auction_key, 800),
+			Error::<Test>::BidPriceTied
+		);
+		assert_eq!(TaskAuction::bids(auction_key, BidId(0, 0)).unwrap(), (BidId(0xC, 1), 800));
+		assert_eq!(Balances::reserved_balance(&0xC), 500);
+		assert_eq!(Balances::reserved_balance(&0xD), 0);
+	})
+}
+
+#[test]
+fn attach() {
+	new_test_ext().execute_with(|| {
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 800));
+		// only assigned auctions take attachments
+		assert_err!(
+			TaskAuction::attach(Origin::signed(0xA), auction_key, vec![1; 32]),
+			Error::<Test>::AuctionNotAssigned
+		);
+		System::set_block_number(10);
+		assert_err!(
+			TaskAuction::attach(Origin::signed(0xD), auction_key, vec![1; 32]),
+			Error::<Test>::OriginProhibited
+		);
+		assert_err!(
+			TaskAuction::attach(Origin::signed(0xA), auction_key, vec![1; 65]),
+			Error::<Test>::MaxDataSizeExceeded
+		);
+		// authors lock a deposit per byte
+		assert_ok!(TaskAuction::attach(Origin::signed(0xA), auction_key, vec![1; 32]));
+		assert_ok!(TaskAuction::attach(Origin::signed(0xC), auction_key, vec![2; 16]));
+		assert_eq!(Balances::reserved_balance(&0xA), 1500 + 320);
+		assert_eq!(Balances::reserved_balance(&0xC), 500 + 160);
+		assert_err!(
+			TaskAuction::attach(Origin::signed(0xA), auction_key, vec![3; 8]),
+			Error::<Test>::MaxAttachmentsExceeded
+		);
+		assert_eq!(
+			TaskAuction::attachments(auction_key)[1].reference.clone().into_inner(),
+			vec![2; 16]
+		);
+		// attachments are pruned and deposits returned on settlement
+		assert_ok!(TaskAuction::confirm(Origin::signed(0xA), auction_key));
+		assert!(TaskAuction::attachments(auction_key).is_empty());
+		assert_eq!(Balances::reserved_balance(&0xA), 0);
+		assert_eq!(Balances::reserved_balance(&0xC), 0);
+		assert_eq!(Balances::free_balance(&0xC), 10000 + 800);
+	})
+}
+
+#[test]
+fn bundle_bid() {
+	new_test_ext().execute_with(|| {
+		let mut auction_keys = vec![];
+		for nonce in 0..3 {
+			create_auction(0xA, 1000, 500, 5);
+			auction_keys.push(AuctionId(0xA, nonce));
+			System::inc_account_nonce(&0xA);
+		}
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_keys[0], 900));
+		// bundle can't exceed combined bounty
+		assert_err!(
+			TaskAuction::bundle_bid(Origin::signed(0xD), auction_keys.clone(), 3001),
+			Error::<Test>::BundlePriceExceedsBounty
+		);
+		assert_err!(
+			TaskAuction::bundle_bid(Origin::signed(0xA), auction_keys.clone(), 2400),
+			Error::<Test>::OriginProhibited
+		);
+		assert_err!(
+			TaskAuction::bundle_bid(
+				Origin::signed(0xD),
+				vec![auction_keys[0], auction_keys[0]],
+				1800
+			),
+			Error::<Test>::DuplicateBundleAuction
+		);
+		// single deposit covers all auctions in the bundle
+		assert_ok!(TaskAuction::bundle_bid(Origin::signed(0xD), auction_keys.clone(), 2400));
+		assert_eq!(Balances::reserved_balance(&0xD), 1500);
+		assert_err!(
+			TaskAuction::bundle_bid(Origin::signed(0xD), auction_keys.clone(), 2400),
+			Error::<Test>::BundleExists
+		);
+		assert_err!(
+			TaskAuction::accept_bundle(Origin::signed(0xA), 0xE),
+			Error::<Test>::BundleNotFound
+		);
+		// owner accepts bundle, all auctions are assigned to the bidder
+		assert_ok!(TaskAuction::accept_bundle(Origin::signed(0xA), 0xD));
+		assert_eq!(Balances::reserved_balance(&0xC), 0);
+		for auction_key in auction_keys.iter() {
+			assert_eq!(TaskAuction::bids(auction_key, BidId(0, 0)).unwrap().1, 800);
+			assert_ok!(TaskAuction::confirm(Origin::signed(0xA), *auction_key));
+		}
+		assert_balances!(0xD => (10000 + 2400, 0));
+
+		// withdrawn bundle returns the deposit
+		create_auction(0xA, 1000, 500, 5);
+		assert_ok!(TaskAuction::bundle_bid(Origin::signed(0xC), vec![AuctionId(0xA, 3)], 700));
+		assert_ok!(TaskAuction::withdraw_bundle(Origin::signed(0xC), 0xA));
+		assert_eq!(Balances::reserved_balance(&0xC), 0);
+		assert!(TaskAuction::bundles(0xA, 0xC).is_none());
+	})
+}
+
+#[test]
+fn pause_bidding() {
+	new_test_ext().execute_with(|| {
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 800));
+		System::set_block_number(2);
+		assert_err!(
+			TaskAuction::pause_bidding(Origin::signed(0xC), auction_key),
+			Error::<Test>::OwnerRequired
+		);
+		assert_err!(
+			TaskAuction::resume_bidding(Origin::signed(0xA), auction_key),
+			Error::<Test>::AuctionNotPaused
+		);
+		assert_ok!(TaskAuction::pause_bidding(Origin::signed(0xA), auction_key));
+		assert_err!(
+			TaskAuction::bid(Origin::signed(0xD), auction_key, 700),
+			Error::<Test>::AuctionPaused
+		);
+		// nor can a bundle be accepted
+		assert_ok!(TaskAuction::bundle_bid(Origin::signed(0xE), vec![auction_key], 700));
+		assert_err!(
+			TaskAuction::accept_bundle(Origin::signed(0xA), 0xE),
+			Error::<Test>::AuctionPaused
+		);
+		// base price is frozen while paused
+		System::set_block_number(10);
+		assert_eq!(TaskAuction::auctions(auction_key).unwrap().get_base_price(), 250);
+		assert_err!(
+			TaskAuction::confirm(Origin::signed(0xA), auction_key),
+			Error::<Test>::AuctionNotAssigned
+		);
+		// curve resumes where it left off
+		assert_ok!(TaskAuction::resume_bidding(Origin::signed(0xA), auction_key));
+		let auction = TaskAuction::auctions(auction_key).unwrap();
+		assert_eq!((auction.initial_block, auction.terminal_block), (9, 13));
+		assert_eq!(auction.get_base_price(), 250);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xD), auction_key, 700));
+	})
+}
+
+#[test]
+fn withdraw_expired_bid() {
+	new_test_ext().execute_with(|| {
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xD), auction_key, 900));
+		assert_ok!(TaskAuction::bid_firm_until(Origin::signed(0xC), auction_key, 800, 2));
+		// bid is still firm
+		System::set_block_number(2);
+		assert_err!(
+			TaskAuction::withdraw_expired_bid(Origin::signed(0xC), auction_key),
+			Error::<Test>::BidNotExpired
+		);
+		// bid lapsed without being assigned
+		System::set_block_number(3);
+		assert_err!(
+			TaskAuction::withdraw_expired_bid(Origin::signed(0xD), auction_key),
+			Error::<Test>::TopBidRequired
+		);
+		assert_ok!(TaskAuction::withdraw_expired_bid(Origin::signed(0xC), auction_key));
+		assert_eq!(Balances::reserved_balance(&0xC), 0);
+		assert_eq!(Balances::free_balance(&0xC), 10000);
+		assert_eq!(Balances::reserved_balance(&0xD), 500);
+		match get_auction_event().unwrap() {
+			AuctionEvent::ExpiredBidWithdrawn { bid_key, price, .. } => {
+				assert_eq!((bid_key.0, price), (0xD, 900));
+			},
+			_ => panic!("wrong event"),
+		};
+
+		// lapsed bids further down the stack are skipped for the next firm bid
+		System::inc_account_nonce(&0xA);
+		let auction_key = create_auction(0xA, 1000, 500, 10);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xE), auction_key, 950));
+		assert_ok!(TaskAuction::bid_firm_until(Origin::signed(0xD), auction_key, 900, 4));
+		assert_ok!(TaskAuction::bid_firm_until(Origin::signed(0xC), auction_key, 800, 4));
+		System::set_block_number(5);
+		assert_ok!(TaskAuction::withdraw_expired_bid(Origin::signed(0xC), auction_key));
+		assert_balances!(0xC => (10000, 0), 0xE => (10000 - 500, 500));
+		match get_auction_event().unwrap() {
+			AuctionEvent::ExpiredBidWithdrawn { bid_key, price, .. } => {
+				assert_eq!((bid_key.0, price), (0xE, 950));
+			},
+			_ => panic!("wrong event"),
+		};
+	})
+}
+
+#[test]
+fn settlement_fee_waiver() {
+	new_test_ext().execute_with(|| {
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 800));
+		let call = crate::Call::<Test>::acknowledge_completion { auction_key };
+		// fees are only waived once the auction is assigned to the worker
+		assert!(!TaskAuction::is_fee_waived(&0xC, &call));
+		System::set_block_number(5);
+		assert!(TaskAuction::is_fee_waived(&0xC, &call));
+		assert!(!TaskAuction::is_fee_waived(&0xD, &call));
+		assert!(!TaskAuction::is_fee_waived(
+			&0xC,
+			&crate::Call::<Test>::retract { auction_key, expected_assigned: None }
+		));
+		assert_ok!(TaskAuction::acknowledge_completion(Origin::signed(0xC), auction_key));
+		assert!(!TaskAuction::is_fee_waived(&0xC, &call));
+	})
+}
+
+#[test]
+fn capability_attestation() {
+	new_test_ext().execute_with(|| {
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		assert_err!(
+			TaskAuction::require_capability(Origin::signed(0xC), auction_key, 7),
+			Error::<Test>::OwnerRequired
+		);
+		assert_ok!(TaskAuction::require_capability(Origin::signed(0xA), auction_key, 7));
+		assert_err!(
+			TaskAuction::bid(Origin::signed(0xC), auction_key, 800),
+			Error::<Test>::AttestationRequired
+		);
+		// only the attestation origin can issue credentials
+		assert_err!(
+			TaskAuction::attest(Origin::signed(0xC), 0xC, 7, 3),
+			sp_runtime::traits::BadOrigin
+		);
+		assert_ok!(TaskAuction::attest(Origin::root(), 0xC, 7, 3));
+		assert_ok!(TaskAuction::attest(Origin::root(), 0xD, 7, 10));
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 800));
+		// expired and revoked attestations no longer qualify
+		System::set_block_number(3);
+		assert_err!(
+			TaskAuction::bid(Origin::signed(0xC), auction_key, 700),
+			Error::<Test>::AttestationRequired
+		);
+		assert_ok!(TaskAuction::revoke_attestation(Origin::root(), 0xD, 7));
+		assert_err!(
+			TaskAuction::bid(Origin::signed(0xD), auction_key, 700),
+			Error::<Test>::AttestationRequired
+		);
+		assert_err!(
+			TaskAuction::require_capability(Origin::signed(0xA), auction_key, 8),
+			Error::<Test>::AuctionHasBids
+		);
+	})
+}
+
+#[test]
+fn auction_versioning() {
+	new_test_ext().execute_with(|| {
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 800));
+		assert_ok!(TaskAuction::extend(Origin::signed(0xA), auction_key, 2000, 5));
+		assert_eq!(TaskAuction::auctions(auction_key).unwrap().version, 1);
+		// new bids are stamped with the amended version
+		System::set_block_number(2);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xD), auction_key, 700));
+		match get_auction_event().unwrap() {
+			AuctionEvent::Bid { version, .. } => assert_eq!(version, 1),
+			_ => panic!("wrong event"),
+		};
+		// bid placed under the current version pays the retraction penalty
+		System::set_block_number(5);
+		assert_ok!(TaskAuction::retract(Origin::signed(0xD), auction_key, None));
+		assert_eq!(Balances::free_balance(&0xD), 9500);
+		// bid placed before the amendment retracts penalty-free
+		assert_ok!(TaskAuction::retract(Origin::signed(0xC), auction_key, None));
+		assert_balances!(0xC => (10000, 0));
+	})
+}
+
+#[test]
+fn block_metrics() {
+	new_test_ext().execute_with(|| {
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 800));
+		assert_ok!(TaskAuction::bid(Origin::signed(0xD), auction_key, 700));
+		System::set_block_number(5);
+		assert_ok!(TaskAuction::confirm(Origin::signed(0xA), auction_key));
+		let metrics = TaskAuction::block_metrics();
+		assert_eq!((metrics.bids, metrics.disputes), (2, 0));
+		assert_eq!((metrics.settlements, metrics.settled_volume), (1, 700));
+		// counters are published and reset at the end of the block
+		<TaskAuction as frame_support::traits::OnFinalize<u64>>::on_finalize(5);
+		match get_auction_event().unwrap() {
+			AuctionEvent::BlockMetrics { metrics: published } => assert_eq!(published, metrics),
+			_ => panic!("wrong event"),
+		};
+		assert_eq!(TaskAuction::block_metrics(), Default::default());
+	})
+}
+
+#[test]
+fn deferred_payouts() {
+	use frame_support::traits::ReservableCurrency;
+	new_test_ext().execute_with(|| {
+		DeferPayouts::set(true);
+		let auction_key = assign_auction(0xC, 800);
+		assert_ok!(TaskAuction::confirm(Origin::signed(0xA), auction_key));
+		// price stays in escrow of the owner until claimed
+		assert_eq!(TaskAuction::pending_payouts(0xC, 0xA), 800);
+		assert_eq!(Balances::reserved_balance(&0xA), 800);
+		assert_eq!(Balances::free_balance(&0xC), 10000);
+		// nothing is flushed without spare weight
+		<TaskAuction as frame_support::traits::OnIdle<u64>>::on_idle(5, 0);
+		assert_eq!(TaskAuction::pending_payouts(0xC, 0xA), 800);
+		assert_err!(TaskAuction::claim(Origin::signed(0xD), 0xA), Error::<Test>::PayoutNotFound);
+		assert_ok!(TaskAuction::claim(Origin::signed(0xC), 0xA));
+		assert_eq!(TaskAuction::pending_payouts(0xC, 0xA), 0);
+		assert_eq!(Balances::reserved_balance(&0xA), 0);
+		assert_eq!(Balances::free_balance(&0xC), 10800);
+		// payouts that fail to transfer are reported and stay queued behind the others
+		let other_key = create_auction(0xD, 1000, 500, 9);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xE), other_key, 700));
+		let auction_key = assign_auction(0xC, 800);
+		assert_ok!(TaskAuction::confirm(Origin::signed(0xA), auction_key));
+		assert_ok!(TaskAuction::confirm(Origin::signed(0xD), other_key));
+		let _ = Balances::slash_reserved(&0xA, 800);
+		Balances::make_free_balance_be(&0xA, 100);
+		<TaskAuction as frame_support::traits::OnIdle<u64>>::on_idle(9, u64::MAX);
+		assert!(System::events().iter().any(|record| record.event ==
+			Event::TaskAuction(AuctionEvent::PayoutFailed {
+				recipient: 0xC,
+				payer: 0xA,
+				amount: 800
+			})));
+		assert_eq!(TaskAuction::pending_payouts(0xC, 0xA), 800);
+		assert_eq!(TaskAuction::pending_payouts(0xE, 0xD), 0);
+		assert_balances!(0xD => (10000 - 700, 0), 0xE => (10000 + 700, 0));
+	})
+}
+
+#[test]
+fn transfer_auction() {
+	new_test_ext().execute_with(|| {
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 800));
+		assert_err!(
+			TaskAuction::transfer_auction(Origin::signed(0xA), auction_key, 0xB),
+			Error::<Test>::SelfArbitrationProhibited
+		);
+		assert_err!(
+			TaskAuction::accept_auction_transfer(Origin::signed(0xD), auction_key),
+			Error::<Test>::TransferOfferRequired
+		);
+		assert_ok!(TaskAuction::transfer_auction(Origin::signed(0xA), auction_key, 0xD));
+		assert_err!(
+			TaskAuction::accept_auction_transfer(Origin::signed(0xE), auction_key),
+			Error::<Test>::TransferOfferRequired
+		);
+		assert_ok!(TaskAuction::accept_auction_transfer(Origin::signed(0xD), auction_key));
+		// reserves move from the original owner to the new owner
+		let new_auction_key = AuctionId(0xD, auction_key.1);
+		assert_eq!(Balances::reserved_balance(&0xA), 0);
+		assert_eq!(Balances::reserved_balance(&0xD), 1500);
+		assert!(TaskAuction::auctions(auction_key).is_none());
+		assert!(TaskAuction::transfer_offers(new_auction_key).is_none());
+		assert_eq!(TaskAuction::bids(new_auction_key, BidId(0, 0)).unwrap().1, 800);
+		// new owner can't list over the transferred auction once its nonce gets there
+		assert_err!(
+			TaskAuction::create(
+				Origin::signed(0xD),
+				0xB,
+				1000,
+				500,
+				5,
+				vec![0; 8],
+				Default::default()
+			),
+			Error::<Test>::AuctionKeyExists
+		);
+		assert_balances!(0xD => (10000 - 1500, 1500));
+		System::inc_account_nonce(&0xD);
+		create_auction(0xD, 1000, 500, 5);
+	})
+}
+
+#[test]
+fn max_bids_per_auction() {
+	new_test_ext().execute_with(|| {
+		MaxBidsPerAuction::set(2);
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 900));
+		assert_ok!(TaskAuction::bid(Origin::signed(0xD), auction_key, 800));
+		assert_err!(
+			TaskAuction::bid(Origin::signed(0xC), auction_key, 700),
+			Error::<Test>::MaxBidsReached
+		);
+		// bounded deletion clears the whole stack when settling
+		assert_ok!(TaskAuction::cancel(Origin::signed(0xA), auction_key, None));
+		assert!(TaskAuction::bids(auction_key, BidId(0xC, 1)).is_none());
+		assert!(TaskAuction::bids(auction_key, BidId(0, 0)).is_none());
+		assert!(TaskAuction::stale_auctions(auction_key).is_none());
+	})
+}
+
+#[test]
+fn rate() {
+	new_test_ext().execute_with(|| {
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		assert_err!(
+			TaskAuction::rate(Origin::signed(0xA), auction_key, 5),
+			Error::<Test>::ReceiptNotFound
+		);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 800));
+		System::set_block_number(5);
+		assert_ok!(TaskAuction::confirm(Origin::signed(0xA), auction_key));
+		assert_err!(
+			TaskAuction::rate(Origin::signed(0xA), auction_key, 6),
+			Error::<Test>::RatingOutOfRange
+		);
+		assert_err!(
+			TaskAuction::rate(Origin::signed(0xB), auction_key, 3),
+			Error::<Test>::OriginProhibited
+		);
+		assert_ok!(TaskAuction::rate(Origin::signed(0xA), auction_key, 4));
+		assert_err!(
+			TaskAuction::rate(Origin::signed(0xA), auction_key, 5),
+			Error::<Test>::AlreadyRated
+		);
+		assert_eq!(TaskAuction::ratings(0xC), crate::RatingSummary { sum: 4, count: 1 });
+		// ratings are only accepted for a while after settlement
+		System::set_block_number(16);
+		assert_err!(
+			TaskAuction::rate(Origin::signed(0xC), auction_key, 2),
+			Error::<Test>::RatingPeriodExpired
+		);
+		System::set_block_number(15);
+		assert_ok!(TaskAuction::rate(Origin::signed(0xC), auction_key, 2));
+		assert_eq!(TaskAuction::ratings(0xA), crate::RatingSummary { sum: 2, count: 1 });
+	})
+}
+
+#[test]
+fn arbitration_preview() {
+	use pallet_task_auction_runtime_api::FundsMovement;
+	new_test_ext().execute_with(|| {
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 800));
+		System::set_block_number(5);
+		// only disputed auctions can be previewed
+		assert!(TaskAuction::arbitration_preview(&auction_key).is_none());
+		assert_ok!(TaskAuction::dispute(Origin::signed(0xA), auction_key));
+		let (if_fulfilled, if_not_fulfilled) =
+			TaskAuction::arbitration_preview(&auction_key).unwrap();
+		assert_eq!(
+			if_fulfilled[3..],
+			[
+				FundsMovement::Transfer { from: 0xA, to: 0xC, amount: 800 },
+				FundsMovement::Transfer { from: 0xA, to: 0xB, amount: 500 },
+			]
+		);
+		assert_eq!(
+			if_not_fulfilled[3..],
+			[FundsMovement::Transfer { from: 0xC, to: 0xB, amount: 500 }]
+		);
+		// arbitration moves exactly the previewed funds
+		assert_ok!(TaskAuction::arbitrate(Origin::signed(0xB), auction_key, true, None));
+		assert_eq!(Balances::free_balance(&0xA), 10000 - 800 - 500);
+		assert_eq!(Balances::free_balance(&0xB), 10000 + 500);
+		assert_eq!(Balances::free_balance(&0xC), 10000 + 800);
+	})
+}
+
+#[test]
+fn arbitration_preview_with_quote() {
+	use pallet_task_auction_runtime_api::FundsMovement;
+	new_test_ext().execute_with(|| {
+		assert_ok!(TaskAuction::set_arbitrator_fee(Origin::signed(0xB), 100));
+		let auction_key = assign_auction(0xC, 800);
+		assert_ok!(TaskAuction::dispute(Origin::signed(0xC), auction_key));
+		let (if_fulfilled, _) = TaskAuction::arbitration_preview(&auction_key).unwrap();
+		assert_eq!(
+			if_fulfilled.last(),
+			Some(&FundsMovement::QuotedArbitratorFee { payer: 0xC, arbitrator: 0xB, amount: 100 })
+		);
+		// net change of the total balance of an account by the previewed movements
+		let net = |who: u64| {
+			if_fulfilled.iter().fold(0i128, |net, movement| match movement {
+				FundsMovement::Transfer { from, to, amount } |
+				FundsMovement::QuotedArbitratorFee { payer: from, arbitrator: to, amount } =>
+					net + (*to == who) as i128 * *amount as i128 -
+						(*from == who) as i128 * *amount as i128,
+				FundsMovement::ListingFee { owner, amount } =>
+					net - (*owner == who) as i128 * *amount as i128,
+				_ => net,
+			})
+		};
+		let total =
+			|who: u64| (Balances::free_balance(&who) + Balances::reserved_balance(&who)) as i128;
+		let before = [0xA, 0xB, 0xC].map(total);
+		assert_ok!(TaskAuction::arbitrate(Origin::signed(0xB), auction_key, true, None));
+		// arbitration moves exactly the previewed funds, quoted fee included
+		for (who, before) in [0xA, 0xB, 0xC].iter().zip(before) {
+			assert_eq!(total(*who) - before, net(*who));
+		}
+		assert_balances!(0xA => (10000 - 1300, 0), 0xB => (10000 + 600, 0), 0xC => (10700, 0));
+	})
+}
+
+#[test]
+fn appeal_arbitration() {
+	new_test_ext().execute_with(|| {
+		AppealPeriod::set(5);
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 800));
+		System::set_block_number(5);
+		assert_ok!(TaskAuction::dispute(Origin::signed(0xA), auction_key));
+		assert_ok!(TaskAuction::arbitrate(Origin::signed(0xB), auction_key, true, None));
+		// arbitration fee stays in escrow of the losing owner
+		assert_eq!(Balances::reserved_balance(&0xA), 500);
+		assert_eq!(Balances::free_balance(&0xB), 10000);
+		assert_err!(
+			TaskAuction::appeal(Origin::signed(0xC), auction_key),
+			Error::<Test>::OriginProhibited
+		);
+		assert_err!(
+			TaskAuction::release_arbitration_fee(Origin::signed(0xB), auction_key),
+			Error::<Test>::AppealPeriodActive
+		);
+		assert_ok!(TaskAuction::appeal(Origin::signed(0xA), auction_key));
+		System::set_block_number(11);
+		assert_err!(
+			TaskAuction::release_arbitration_fee(Origin::signed(0xB), auction_key),
+			Error::<Test>::AppealPeriodActive
+		);
+		// overturned ruling returns the fee to the appellant
+		assert_ok!(TaskAuction::resolve_appeal(Origin::root(), auction_key, true));
+		assert_eq!(Balances::reserved_balance(&0xA), 0);
+		assert_eq!(Balances::free_balance(&0xA), 10000 - 800);
+		assert_eq!(Balances::free_balance(&0xB), 10000);
+		assert!(TaskAuction::arbitration_escrows(auction_key).is_none());
+	})
+}
+
+#[test]
+fn force_arbitrate() {
+	new_test_ext().execute_with(|| {
+		// arbitrator account doesn't exist
+		assert_ok!(TaskAuction::create(
+			Origin::signed(0xA),
+			0xF,
+			1000,
+			500,
+			5,
+			vec![0; 8],
+			Default::default()
+		));
+		let auction_key = created_key();
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 800));
+		System::set_block_number(5);
+		assert_err!(
+			TaskAuction::force_arbitrate(Origin::root(), auction_key, true, None),
+			Error::<Test>::AuctionNotDisputed
+		);
+		assert_ok!(TaskAuction::dispute(Origin::signed(0xA), auction_key));
+		assert!(System::events().iter().any(|record| matches!(
+			&record.event,
+			Event::TaskAuction(AuctionEvent::FallbackArbitrationRequired { .. })
+		)));
+		assert!(TaskAuction::auctions(auction_key).unwrap().fallback_arbitration);
+		assert_err!(
+			TaskAuction::force_arbitrate(Origin::signed(0xA), auction_key, true, None),
+			sp_runtime::traits::BadOrigin
+		);
+		// fallback ruling settles without paying the missing arbitrator
+		assert_ok!(TaskAuction::force_arbitrate(Origin::root(), auction_key, true, None));
+		assert_eq!(Balances::free_balance(&0xA), 10000 - 800);
+		assert_eq!(Balances::free_balance(&0xC), 10000 + 800);
+		assert_eq!(Balances::free_balance(&0xF), 0);
+	})
+}
+
+#[test]
+fn public_templates() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TaskAuction::publish_template(
+			Origin::signed(0xA),
+			b"parcel delivery".to_vec(),
+			1000,
+			500,
+			4,
+			vec![0; 8]
+		));
+		assert_eq!(Balances::reserved_balance(&0xA), 100);
+		// authors get back the deposit they paid when the deposit changes
+		TemplateDeposit::set(300);
+		assert_ok!(TaskAuction::fork_template(
+			Origin::signed(0xD),
+			0,
+			b"parcel delivery v2".to_vec(),
+			crate::TemplateOverrides { bounty: Some(2000), ..Default::default() }
+		));
+		// registry is bounded
+		assert_err!(
+			TaskAuction::publish_template(Origin::signed(0xE), vec![], 1000, 500, 4, vec![]),
+			Error::<Test>::MaxTemplatesReached
+		);
+		assert_ok!(TaskAuction::create(
+			Origin::signed(0xC),
+			0xB,
+			0,
+			0,
+			0,
+			vec![],
+			CreateOptions {
+				source: TermsSource::Template { template_id: 1, overrides: Default::default() },
+				..Default::default()
+			}
+		));
+		match get_auction_event().unwrap() {
+			AuctionEvent::Created { auction_key, bounty, terminal_block } => {
+				assert_eq!((auction_key.0, bounty, terminal_block), (0xC, 2000, 5));
+			},
+			_ => panic!("wrong event"),
+		};
+		assert_err!(
+			TaskAuction::unpublish_template(Origin::signed(0xC), 0),
+			Error::<Test>::OriginProhibited
+		);
+		assert_ok!(TaskAuction::unpublish_template(Origin::signed(0xA), 0));
+		assert_balances!(0xA => (10000, 0), 0xD => (10000 - 300, 300));
+		assert!(TaskAuction::templates(0).is_none());
+	})
+}
+
+#[test]
+fn auction_status() {
+	new_test_ext().execute_with(|| {
+		Now::set(1_000_000);
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		// deadline is 4 blocks of 6 seconds away
+		let status = TaskAuction::auction_status(&auction_key).unwrap();
+		assert_eq!(status.terminal_block, 5);
+		assert_eq!(status.terminal_timestamp, 1_024_000);
+		assert_eq!(status.top_price, None);
+		assert!(!status.assigned);
+		assert_eq!(TaskAuction::estimate_timestamp(0), 994_000);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 1000));
+		let status = TaskAuction::auction_status(&auction_key).unwrap();
+		assert_eq!(status.top_price, Some(1000));
+		assert!(!status.assigned);
+		// base price reaches the bid at the deadline
+		System::set_block_number(5);
+		Now::set(1_024_000);
+		let status = TaskAuction::auction_status(&auction_key).unwrap();
+		assert_eq!(status.terminal_timestamp, 1_024_000);
+		assert!(status.assigned);
+		assert_eq!(TaskAuction::auction_status(&AuctionId(0xA, 99)), None);
+	});
+}
+
+#[test]
+fn creation_grace_period() {
+	new_test_ext().execute_with(|| {
+		CreationGracePeriod::set(2);
+		ListingFeeBase::set(100);
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 800));
+		// cancel within grace period refunds listing fee and bidder keeps no penalty
+		System::set_block_number(2);
+		assert_ok!(TaskAuction::cancel(Origin::signed(0xA), auction_key, None));
+		assert_balances!(0xA => (10000, 0), 0xC => (10000, 0));
+
+		// after grace period the owner pays the bidder's deposit
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 1500));
+		assert_ok!(TaskAuction::bid(Origin::signed(0xD), auction_key, 900));
+		System::set_block_number(4);
+		assert_ok!(TaskAuction::cancel(Origin::signed(0xA), auction_key, None));
+		assert_eq!(Balances::free_balance(&0xA), 10000 - 500 - 100);
+		assert_eq!(Balances::free_balance(&0xD), 10000 + 500);
+	});
+}
+
+#[test]
+fn min_bid_ratio_override() {
+	new_test_ext().execute_with(|| {
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		// overrides are rejected until governance sets bounds
+		assert_err!(
+			TaskAuction::set_min_bid_ratio(Origin::signed(0xA), auction_key, 128),
+			Error::<Test>::MinBidRatioOutOfBounds
+		);
+		assert_err!(
+			TaskAuction::set_min_bid_ratio_bounds(Origin::signed(0xA), 100, 255),
+			sp_runtime::traits::BadOrigin
+		);
+		assert_err!(
+			TaskAuction::set_min_bid_ratio_bounds(Origin::root(), 200, 100),
+			Error::<Test>::MinBidRatioOutOfBounds
+		);
+		assert_ok!(TaskAuction::set_min_bid_ratio_bounds(Origin::root(), 100, 255));
+		assert_err!(
+			TaskAuction::set_min_bid_ratio(Origin::signed(0xA), auction_key, 50),
+			Error::<Test>::MinBidRatioOutOfBounds
+		);
+		assert_err!(
+			TaskAuction::set_min_bid_ratio(Origin::signed(0xC), auction_key, 128),
+			Error::<Test>::OwnerRequired
+		);
+		assert_ok!(TaskAuction::set_min_bid_ratio(Origin::signed(0xA), auction_key, 128));
+		assert_eq!(TaskAuction::auctions(auction_key).unwrap().min_bid_ratio(), 128);
+
+		// next bid must now undercut by about half
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 900));
+		assert_eq!(TaskAuction::max_next_bid(&auction_key), Some(451));
+		assert_err!(
+			TaskAuction::bid(Origin::signed(0xD), auction_key, 800),
+			Error::<Test>::MinBidRatioRequired
+		);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xD), auction_key, 451));
+		// ratio is fixed once bids exist
+		assert_err!(
+			TaskAuction::set_min_bid_ratio(Origin::signed(0xA), auction_key, 255),
+			Error::<Test>::AuctionHasBids
+		);
+	});
+}
+
+#[test]
+fn worker_capacity() {
+	new_test_ext().execute_with(|| {
+		let mut auction_keys = Vec::new();
+		for _ in 0..3 {
+			auction_keys.push(create_auction(0xA, 1000, 500, 5));
+			System::inc_account_nonce(&0xA);
+		}
+		assert_ok!(TaskAuction::set_capacity(Origin::signed(0xC), Some(1)));
+		assert_eq!(TaskAuction::workers(0xC).unwrap().max_concurrent_assignments, Some(1));
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_keys[0], 800));
+		assert_eq!(TaskAuction::active_assignments(0xC), 1);
+		// undercutting own bid doesn't take extra capacity
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_keys[0], 700));
+		assert_eq!(TaskAuction::active_assignments(0xC), 1);
+		assert_err!(
+			TaskAuction::bid(Origin::signed(0xC), auction_keys[1], 800),
+			Error::<Test>::CapacityExceeded
+		);
+		// being outbid frees capacity
+		assert_ok!(TaskAuction::bid(Origin::signed(0xD), auction_keys[0], 600));
+		assert_eq!(TaskAuction::active_assignments(0xC), 0);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_keys[1], 800));
+
+		// when only checked at assignment, speculative bids are allowed
+		CheckCapacityAtBid::set(false);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_keys[2], 800));
+		assert_eq!(TaskAuction::active_assignments(0xC), 2);
+		// but not bids which would be assigned right away
+		System::set_block_number(5);
+		assert_err!(
+			TaskAuction::bid(Origin::signed(0xC), auction_keys[0], 500),
+			Error::<Test>::CapacityExceeded
+		);
+
+		// settling releases the worker
+		assert_ok!(TaskAuction::confirm(Origin::signed(0xA), auction_keys[1]));
+		assert_eq!(TaskAuction::active_assignments(0xC), 1);
+		assert_ok!(TaskAuction::retract(Origin::signed(0xC), auction_keys[2], None));
+		assert_eq!(TaskAuction::active_assignments(0xC), 0);
+	});
+}
+
+#[test]
+fn negotiated_release() {
+	new_test_ext().execute_with(|| {
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 800));
+		// only an assigned worker can propose
+		assert_err!(
+			TaskAuction::propose_release(Origin::signed(0xC), auction_key, 200),
+			Error::<Test>::AuctionNotAssigned
+		);
+		System::set_block_number(5);
+		assert_err!(
+			TaskAuction::propose_release(Origin::signed(0xD), auction_key, 200),
+			Error::<Test>::TopBidRequired
+		);
+		assert_err!(
+			TaskAuction::propose_release(Origin::signed(0xC), auction_key, 600),
+			Error::<Test>::PenaltyExceedsDeposit
+		);
+		assert_err!(
+			TaskAuction::accept_release(Origin::signed(0xA), auction_key),
+			Error::<Test>::ReleaseProposalNotFound
+		);
+		assert_ok!(TaskAuction::propose_release(Origin::signed(0xC), auction_key, 200));
+		assert_err!(
+			TaskAuction::accept_release(Origin::signed(0xC), auction_key),
+			Error::<Test>::OwnerRequired
+		);
+		assert_ok!(TaskAuction::accept_release(Origin::signed(0xA), auction_key));
+		assert_eq!(Balances::reserved_balance(&0xC), 0);
+		assert_eq!(Balances::free_balance(&0xC), 10000 - 200);
+		assert_eq!(Balances::free_balance(&0xA), 10000 - 1500 + 200);
+		assert_eq!(TaskAuction::active_assignments(0xC), 0);
+		assert!(TaskAuction::release_proposals(auction_key).is_none());
+
+		// auction is reopened with a fresh price curve
+		let auction = TaskAuction::auctions(auction_key).unwrap();
+		assert_eq!((auction.initial_block, auction.terminal_block), (5, 9));
+		assert!(TaskAuction::bids(auction_key, BidId(0, 0)).is_none());
+		assert_ok!(TaskAuction::bid(Origin::signed(0xD), auction_key, 900));
+		assert!(!TaskAuction::auction_status(&auction_key).unwrap().assigned);
+	});
+}
+
+#[test]
+fn replay_settlement_fixtures() {
+	use crate::replay::{trace_confirmation, SettlementFixture};
+	use std::collections::BTreeMap;
+	let fixtures = [
+		include_str!("../fixtures/settlement_at_deadline.json"),
+		include_str!("../fixtures/settlement_before_deadline.json"),
+	];
+	for fixture in fixtures {
+		let fixture: SettlementFixture<u64, u128, u64> = serde_json::from_str(fixture).unwrap();
+		new_test_ext().execute_with(|| {
+			System::set_block_number(fixture.initial_block);
+			assert_ok!(TaskAuction::create(
+				Origin::signed(fixture.owner),
+				fixture.arbitrator,
+				fixture.bounty,
+				fixture.deposit,
+				fixture.terminal_block,
+				vec![0; 8],
+				Default::default()
+			));
+			let auction_key = created_key();
+			for (bidder, price) in fixture.bids.iter() {
+				assert_ok!(TaskAuction::bid(Origin::signed(*bidder), auction_key, *price));
+			}
+			System::set_block_number(fixture.settled_block);
+			assert_eq!(trace_confirmation::<Test>(&auction_key).as_ref(), Some(&fixture));
+			assert_ok!(TaskAuction::confirm(Origin::signed(fixture.owner), auction_key));
+			// balances moved exactly by the traced transfers and listing fee
+			let mut expected: BTreeMap<u64, u128> = (0xA..=0xE).map(|who| (who, 10000)).collect();
+			*expected.get_mut(&fixture.owner).unwrap() -= fixture.listing_fee;
+			for transfer in fixture.transfers.iter() {
+				*expected.get_mut(&transfer.from).unwrap() -= transfer.amount;
+				*expected.get_mut(&transfer.to).unwrap() += transfer.amount;
+			}
+			for (who, balance) in expected {
+				assert_eq!(Balances::free_balance(&who), balance);
+				assert_eq!(Balances::reserved_balance(&who), 0);
+			}
+		});
+	}
+}
+
+#[test]
+fn approved_arbitrators() {
+	new_test_ext().execute_with(|| {
+		ApprovedArbitrators::set(Some(vec![0xB]));
+		assert_err!(
+			TaskAuction::create(
+				Origin::signed(0xA),
+				0xC,
+				1000,
+				500,
+				5,
+				vec![0; 8],
+				Default::default()
+			),
+			Error::<Test>::ArbitratorNotApproved
+		);
+		create_auction(0xA, 1000, 500, 5);
+		System::inc_account_nonce(&0xA);
+		// permissionless mode accepts any arbitrator
+		ApprovedArbitrators::set(None);
+		assert_ok!(TaskAuction::create(
+			Origin::signed(0xA),
+			0xC,
+			1000,
+			500,
+			5,
+			vec![0; 8],
+			Default::default()
+		));
+	});
+}
+
+#[test]
+fn private_bid() {
+	use sp_runtime::traits::Hash;
+	new_test_ext().execute_with(|| {
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		// one-time account 0xE bids on behalf of 0xC
+		let salt = [7; 32];
+		let commitment = <Test as frame_system::Config>::Hashing::hash_of(&(0xCu64, salt));
+		assert_ok!(TaskAuction::bid_private(Origin::signed(0xE), auction_key, 800, commitment));
+		assert_eq!(Balances::reserved_balance(&0xE), 500);
+		let bid_key = BidId(0xE, 1);
+		assert_eq!(TaskAuction::bid_commitments(auction_key, bid_key), Some((commitment, None)));
+		// identity stays hidden until assignment
+		assert_err!(
+			TaskAuction::reveal_bidder(Origin::signed(0xE), auction_key, 0xC, salt),
+			Error::<Test>::AuctionNotAssigned
+		);
+		System::set_block_number(5);
+		assert_err!(
+			TaskAuction::reveal_bidder(Origin::signed(0xC), auction_key, 0xC, salt),
+			Error::<Test>::TopBidRequired
+		);
+		assert_err!(
+			TaskAuction::reveal_bidder(Origin::signed(0xE), auction_key, 0xD, salt),
+			Error::<Test>::CommitmentMismatch
+		);
+		// assigned bid is not paid until revealed
+		assert_err!(
+			TaskAuction::confirm(Origin::signed(0xA), auction_key),
+			Error::<Test>::RevealRequired
+		);
+		assert_ok!(TaskAuction::reveal_bidder(Origin::signed(0xE), auction_key, 0xC, salt));
+		assert_eq!(
+			TaskAuction::bid_commitments(auction_key, bid_key),
+			Some((commitment, Some(0xC)))
+		);
+		// settlement clears the commitment
+		assert_ok!(TaskAuction::confirm(Origin::signed(0xA), auction_key));
+		assert_eq!(TaskAuction::bid_commitments(auction_key, bid_key), None);
+		assert_eq!(Balances::free_balance(&0xE), 10000 + 800);
+		// arbitrator can't bid on its own auction through a one-time account
+		System::inc_account_nonce(&0xA);
+		let auction_key = create_auction(0xA, 1000, 500, 10);
+		let commitment = <Test as frame_system::Config>::Hashing::hash_of(&(0xBu64, salt));
+		assert_ok!(TaskAuction::bid_private(Origin::signed(0xD), auction_key, 800, commitment));
+		System::set_block_number(10);
+		assert_err!(
+			TaskAuction::reveal_bidder(Origin::signed(0xD), auction_key, 0xB, salt),
+			Error::<Test>::OriginProhibited
+		);
+	});
+}
+
+#[test]
+fn max_open_disputes() {
+	new_test_ext().execute_with(|| {
+		MaxOpenDisputesPerArbitrator::set(1);
+		let mut auction_keys = Vec::new();
+		for _ in 0..2 {
+			auction_keys.push(create_auction(0xA, 1000, 500, 5));
+			System::inc_account_nonce(&0xA);
+			assert_ok!(TaskAuction::bid(Origin::signed(0xC), *auction_keys.last().unwrap(), 800));
+		}
+		System::set_block_number(5);
+		assert_ok!(TaskAuction::dispute(Origin::signed(0xA), auction_keys[0]));
+		assert_eq!(TaskAuction::open_disputes(0xB), 1);
+		// overloaded arbitrator can't be named in new auctions
+		assert_err!(
+			TaskAuction::create(
+				Origin::signed(0xA),
+				0xB,
+				1000,
+				500,
+				10,
+				vec![0; 8],
+				Default::default()
+			),
+			Error::<Test>::ArbitratorOverloaded
+		);
+		// further disputes are routed to the fallback origin
+		assert_ok!(TaskAuction::dispute(Origin::signed(0xA), auction_keys[1]));
+		assert!(TaskAuction::auctions(auction_keys[1]).unwrap().fallback_arbitration);
+		assert_eq!(TaskAuction::open_disputes(0xB), 1);
+		// ruling frees the arbitrator
+		assert_ok!(TaskAuction::arbitrate(Origin::signed(0xB), auction_keys[0], true, None));
+		assert_eq!(TaskAuction::open_disputes(0xB), 0);
+		assert_ok!(TaskAuction::force_arbitrate(Origin::root(), auction_keys[1], true, None));
+		assert_eq!(TaskAuction::open_disputes(0xB), 0);
+		create_auction(0xA, 1000, 500, 10);
+	});
+}
+
+#[test]
+fn assignment_confirmation_blocks() {
+	new_test_ext().execute_with(|| {
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		assert_err!(
+			TaskAuction::set_assignment_confirmation(Origin::signed(0xC), auction_key, Some(2)),
+			Error::<Test>::OwnerRequired
+		);
+		assert_ok!(TaskAuction::set_assignment_confirmation(
+			Origin::signed(0xA),
+			auction_key,
+			Some(2)
+		));
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 800));
+		assert_err!(
+			TaskAuction::set_assignment_confirmation(Origin::signed(0xA), auction_key, None),
+			Error::<Test>::AuctionHasBids
+		);
+		// base price passes the bid at block 5, but isn't assigned until block 7
+		System::set_block_number(6);
+		assert!(!TaskAuction::auction_status(&auction_key).unwrap().assigned);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xD), auction_key, 700));
+		// new top bid restarts the confirmation period
+		System::set_block_number(7);
+		assert!(!TaskAuction::auction_status(&auction_key).unwrap().assigned);
+		System::set_block_number(8);
+		assert!(TaskAuction::auction_status(&auction_key).unwrap().assigned);
+		assert_err!(
+			TaskAuction::bid(Origin::signed(0xE), auction_key, 600),
+			Error::<Test>::AuctionAssigned
+		);
+		assert_ok!(TaskAuction::confirm(Origin::signed(0xA), auction_key));
+	});
+}
+
+#[test]
+fn set_deposit() {
+	new_test_ext().execute_with(|| {
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 900));
+		assert_err!(
+			TaskAuction::set_deposit(Origin::signed(0xC), auction_key, 800),
+			Error::<Test>::OwnerRequired
+		);
+		assert_err!(
+			TaskAuction::set_deposit(Origin::signed(0xA), auction_key, 100),
+			Error::<Test>::MinDepositRequired
+		);
+		// top bidder can't cover the increase
+		Balances::make_free_balance_be(&0xC, 100);
+		assert_err!(
+			TaskAuction::set_deposit(Origin::signed(0xA), auction_key, 800),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+		assert_eq!(Balances::reserved_balance(&0xA), 1500);
+		Balances::make_free_balance_be(&0xC, 9500);
+		// owner and top bidder escrow the increase
+		assert_ok!(TaskAuction::set_deposit(Origin::signed(0xA), auction_key, 800));
+		assert_eq!(Balances::reserved_balance(&0xA), 1800);
+		assert_eq!(Balances::reserved_balance(&0xC), 800);
+		// new bids reserve the new deposit
+		assert_ok!(TaskAuction::bid(Origin::signed(0xD), auction_key, 800));
+		assert_eq!(Balances::reserved_balance(&0xC), 0);
+		assert_eq!(Balances::reserved_balance(&0xD), 800);
+		assert_ok!(TaskAuction::bundle_bid(Origin::signed(0xE), vec![auction_key], 700));
+		// decrease is returned
+		assert_ok!(TaskAuction::set_deposit(Origin::signed(0xA), auction_key, 600));
+		assert_eq!(Balances::reserved_balance(&0xA), 1600);
+		assert_eq!(Balances::reserved_balance(&0xD), 600);
+		// bundles locked the deposit before it changed
+		assert_err!(
+			TaskAuction::accept_bundle(Origin::signed(0xA), 0xE),
+			Error::<Test>::BundleDepositChanged
+		);
+		// cannot change deposit once assigned
+		System::set_block_number(5);
+		assert_err!(
+			TaskAuction::set_deposit(Origin::signed(0xA), auction_key, 700),
+			Error::<Test>::AuctionAssigned
+		);
+		assert_ok!(TaskAuction::confirm(Origin::signed(0xA), auction_key));
+		assert_eq!(Balances::reserved_balance(&0xA), 0);
+		assert_eq!(Balances::reserved_balance(&0xD), 0);
+	});
+}
+
+#[test]
+fn completion_deadline() {
+	new_test_ext().execute_with(|| {
+		let mut auction_keys = Vec::new();
+		for _ in 0..3 {
+			auction_keys.push(create_auction(0xA, 1000, 500, 5));
+			System::inc_account_nonce(&0xA);
+		}
+		assert_err!(
+			TaskAuction::set_completion_deadline(Origin::signed(0xA), auction_keys[0], 5),
+			Error::<Test>::InvalidCompletionDeadline
+		);
+		assert_ok!(TaskAuction::set_completion_deadline(Origin::signed(0xA), auction_keys[0], 8));
+		assert_ok!(TaskAuction::set_completion_deadline(Origin::signed(0xA), auction_keys[1], 8));
+		assert_err!(
+			TaskAuction::set_completion_deadline(Origin::signed(0xA), auction_keys[2], 8),
+			Error::<Test>::DeadlineQueueFull
+		);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_keys[0], 800));
+		assert_ok!(TaskAuction::bid(Origin::signed(0xD), auction_keys[1], 800));
+		// only one worker acknowledges completion in time
+		run_to_block(5);
+		assert_ok!(TaskAuction::acknowledge_completion(Origin::signed(0xD), auction_keys[1]));
+		run_to_block(8);
+		assert_eq!(
+			get_auction_event(),
+			Some(AuctionEvent::Disputed { auction_key: auction_keys[0] })
+		);
+		assert!(TaskAuction::auctions(auction_keys[0]).unwrap().in_dispute);
+		assert!(!TaskAuction::auctions(auction_keys[1]).unwrap().in_dispute);
+		assert!(TaskAuction::completion_deadlines(8).is_empty());
+		// worker who missed the deadline loses the deposit in arbitration
+		assert_ok!(TaskAuction::arbitrate(Origin::signed(0xB), auction_keys[0], false, None));
+		assert_eq!(Balances::free_balance(&0xC), 10000 - 500);
+	});
+}
+
+#[test]
+fn priority_listing() {
+	new_test_ext().execute_with(|| {
+		let mut auction_keys = Vec::new();
+		for owner in [0xA, 0xC, 0xD] {
+			auction_keys.push(create_auction(owner, 1000, 500, 5));
+		}
+		assert_err!(
+			TaskAuction::promote(Origin::signed(0xC), auction_keys[0], 100),
+			Error::<Test>::OwnerRequired
+		);
+		assert_ok!(TaskAuction::promote(Origin::signed(0xA), auction_keys[0], 100));
+		assert_err!(
+			TaskAuction::promote(Origin::signed(0xA), auction_keys[0], 100),
+			Error::<Test>::AlreadyPromoted
+		);
+		assert_ok!(TaskAuction::promote(Origin::signed(0xC), auction_keys[1], 200));
+		assert_eq!(Balances::reserved_balance(&0xA), 1600);
+		// full list only admits higher stakes
+		assert_err!(
+			TaskAuction::promote(Origin::signed(0xD), auction_keys[2], 100),
+			Error::<Test>::PromotionStakeTooLow
+		);
+		assert_ok!(TaskAuction::promote(Origin::signed(0xD), auction_keys[2], 150));
+		assert_eq!(
+			TaskAuction::promoted_auctions().into_inner(),
+			vec![(auction_keys[1], 200), (auction_keys[2], 150)]
+		);
+		// evicted stake is refunded
+		assert_eq!(Balances::reserved_balance(&0xA), 1500);
+		// settlement refunds the stake
+		assert_ok!(TaskAuction::cancel(Origin::signed(0xC), auction_keys[1], None));
+		assert_eq!(Balances::reserved_balance(&0xC), 0);
+		assert_eq!(TaskAuction::promoted_auctions().into_inner(), vec![(auction_keys[2], 150)]);
+	});
+}
+
+#[test]
+fn storage_estimates() {
+	use frame_support::traits::StorageInfoTrait;
+	let estimates = crate::storage_report::storage_estimates::<Test>();
+	assert_eq!(estimates.len(), TaskAuction::storage_info().len());
+	let estimate = |name: &str| estimates.iter().find(|e| e.storage == name).unwrap().clone();
+	// maps of bounded types report their entry size and proof size
+	let open_disputes = estimate("OpenDisputes");
+	assert_eq!(open_disputes.pallet, "TaskAuction");
+	let max_size = open_disputes.max_size.unwrap();
+	assert!(open_disputes.pov_per_read.unwrap() > max_size);
+	// single values need no branch nodes beyond the entry itself
+	let template_count = estimate("TemplateCount");
+	assert_eq!(template_count.max_values, Some(1));
+	assert_eq!(template_count.pov_per_read, template_count.max_size);
+	// task data is bounded by the maximum data size
+	assert!(estimate("AuctionData").max_size.unwrap() > MaxDataSize::get());
+	// every item is bounded
+	assert!(estimates.iter().all(|e| e.max_size.is_some() && e.pov_per_read.is_some()));
+}
+
+#[test]
+fn run_to_block_hooks() {
+	new_test_ext().execute_with(|| {
+		let auction_key = assign_auction(0xC, 800);
+		assert_eq!(System::block_number(), 5);
+		// counters of the block with the bid were published when it was finalized
+		assert_eq!(TaskAuction::block_metrics(), Default::default());
+		assert!(System::events().iter().any(|record| matches!(
+			&record.event,
+			Event::TaskAuction(AuctionEvent::BlockMetrics { metrics }) if metrics.bids == 1
+		)));
+		assert_balances!(0xA => (10000 - 1500, 1500), 0xC => (10000 - 500, 500));
+		// deadlines are checked as each block is initialized
+		let next_key = create_auction(0xA, 1000, 500, 9);
+		assert_ok!(TaskAuction::set_completion_deadline(Origin::signed(0xA), next_key, 12));
+		assert_ok!(TaskAuction::bid(Origin::signed(0xD), next_key, 800));
+		run_to_block(11);
+		assert!(!TaskAuction::auctions(next_key).unwrap().in_dispute);
+		run_to_block(12);
+		assert!(TaskAuction::auctions(next_key).unwrap().in_dispute);
+		assert!(!TaskAuction::auctions(auction_key).unwrap().in_dispute);
+	});
+}
+
+#[test]
+fn bid_improvement() {
+	new_test_ext().execute_with(|| {
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 900));
+		assert_ok!(TaskAuction::bid(Origin::signed(0xD), auction_key, 800));
+		// others must still undercut by the min bid ratio
+		assert_err!(
+			TaskAuction::bid(Origin::signed(0xC), auction_key, 799),
+			Error::<Test>::MinBidRatioRequired
+		);
+		// top bidder lowers their bid slightly without a new deposit
+		assert_ok!(TaskAuction::bid(Origin::signed(0xD), auction_key, 799));
+		assert_eq!(
+			get_auction_event(),
+			Some(AuctionEvent::BidImproved {
+				auction_key,
+				bid_key: BidId(0xD, 2),
+				price: 799,
+				version: 0,
+				max_next_bid: Some(783)
+			})
+		);
+		assert_eq!(TaskAuction::bids(auction_key, BidId(0, 0)), Some((BidId(0xD, 2), 799)));
+		assert_eq!(TaskAuction::bids(auction_key, BidId(0xD, 2)), Some((BidId(0xC, 1), 799)));
+		assert_balances!(0xC => (10000, 0), 0xD => (9500, 500));
+		assert_err!(
+			TaskAuction::bid(Origin::signed(0xD), auction_key, 799),
+			Error::<Test>::BidImprovementRequired
+		);
+		// assigned bids can't be changed
+		run_to_block(5);
+		assert_err!(
+			TaskAuction::bid(Origin::signed(0xD), auction_key, 700),
+			Error::<Test>::AuctionAssigned
+		);
+	});
+}
+
+#[test]
+fn vested_payout() {
+	new_test_ext().execute_with(|| {
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		assert_err!(
+			TaskAuction::set_vesting_period(Origin::signed(0xA), auction_key, Some(0)),
+			Error::<Test>::InvalidVestingPeriod
+		);
+		assert_ok!(TaskAuction::set_vesting_period(Origin::signed(0xA), auction_key, Some(100)));
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 800));
+		assert_err!(
+			TaskAuction::set_vesting_period(Origin::signed(0xA), auction_key, None),
+			Error::<Test>::AuctionHasBids
+		);
+		run_to_block(5);
+		assert_ok!(TaskAuction::confirm(Origin::signed(0xA), auction_key));
+		assert_eq!(
+			get_auction_event(),
+			Some(AuctionEvent::Confirmed {
+				auction_key,
+				vesting_period: Some(100),
+				price: 800,
+				savings: 0,
+				rate: None
+			})
+		);
+		// payout is transferred but vests linearly from confirmation
+		assert_balances!(0xC => (10800, 0));
+		assert_eq!(VestingSchedules::get(), vec![(0xC, 800, 8, 5)]);
+
+		// payout falls back to a lump sum if no schedule can be added
+		System::inc_account_nonce(&0xA);
+		let auction_key = create_auction(0xA, 1000, 500, 9);
+		assert_ok!(TaskAuction::set_vesting_period(Origin::signed(0xA), auction_key, Some(100)));
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 800));
+		run_to_block(9);
+		assert_ok!(TaskAuction::confirm(Origin::signed(0xA), auction_key));
+		assert_eq!(
+			get_auction_event(),
+			Some(AuctionEvent::Confirmed {
+				auction_key,
+				vesting_period: None,
+				price: 800,
+				savings: 0,
+				rate: None
+			})
+		);
+		assert!(System::events().iter().any(|record| record.event ==
+			Event::TaskAuction(AuctionEvent::VestingFailed { auction_key, worker: 0xC })));
+		assert_eq!(VestingSchedules::get().len(), 1);
+	});
+}
+
+#[test]
+fn deposit_receipts() {
+	new_test_ext().execute_with(|| {
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		// receipts follow the deposit of the top bid
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 900));
+		assert_eq!(Receipts::get().get(&0xC), Some(&500));
+		assert_ok!(TaskAuction::bid(Origin::signed(0xD), auction_key, 800));
+		assert_eq!(Receipts::get().get(&0xC), None);
+		assert_eq!(Receipts::get().get(&0xD), Some(&500));
+		assert_ok!(TaskAuction::set_deposit(Origin::signed(0xA), auction_key, 600));
+		assert_eq!(Receipts::get().get(&0xD), Some(&600));
+		// retracting returns the deposit to the previous bidder
+		assert_ok!(TaskAuction::retract(Origin::signed(0xD), auction_key, None));
+		assert_eq!(Receipts::get().get(&0xD), None);
+		assert_eq!(Receipts::get().get(&0xC), Some(&600));
+		// deposit is not released unless all of its receipts are burned
+		Receipts::set(Default::default());
+		assert_err!(
+			TaskAuction::retract(Origin::signed(0xC), auction_key, None),
+			sp_runtime::DispatchError::Other("deposit receipts missing")
+		);
+		Receipts::set([(0xC, 600)].into_iter().collect());
+		// receipts are burned at settlement
+		run_to_block(5);
+		assert_ok!(TaskAuction::confirm(Origin::signed(0xA), auction_key));
+		assert!(Receipts::get().is_empty());
+	});
+}
+
+#[test]
+fn open_ended_auction() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TaskAuction::create(
+			Origin::signed(0xA),
+			0xB,
+			1000,
+			500,
+			0,
+			vec![0; 8],
+			CreateOptions { open_ended: true, ..Default::default() }
+		));
+		let auction_key = created_key();
+		assert_err!(
+			TaskAuction::accept_bid(Origin::signed(0xA), auction_key),
+			Error::<Test>::TopBidRequired
+		);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 1200));
+		assert_err!(
+			TaskAuction::accept_bid(Origin::signed(0xA), auction_key),
+			Error::<Test>::BidExceedsBounty
+		);
+		// bids are never assigned by the passage of time
+		assert_ok!(TaskAuction::bid(Origin::signed(0xD), auction_key, 900));
+		assert_ok!(TaskAuction::bundle_bid(Origin::signed(0xE), vec![auction_key], 850));
+		assert_err!(
+			TaskAuction::accept_bundle(Origin::signed(0xA), 0xE),
+			Error::<Test>::OpenEndedProhibited
+		);
+		run_to_block(100);
+		assert_eq!(TaskAuction::auctions(auction_key).unwrap().get_base_price(), 0);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 800));
+		assert_err!(
+			TaskAuction::accept_bid(Origin::signed(0xC), auction_key),
+			Error::<Test>::OwnerRequired
+		);
+		assert_ok!(TaskAuction::accept_bid(Origin::signed(0xA), auction_key));
+		assert_err!(
+			TaskAuction::bid(Origin::signed(0xD), auction_key, 700),
+			Error::<Test>::AuctionAssigned
+		);
+		assert_ok!(TaskAuction::confirm(Origin::signed(0xA), auction_key));
+		assert_balances!(0xA => (10000 - 800, 0), 0xC => (10000 + 800, 0));
+
+		// unaccepted auctions expire after a period without new bids
+		System::inc_account_nonce(&0xA);
+		assert_ok!(TaskAuction::create(
+			Origin::signed(0xA),
+			0xB,
+			1000,
+			500,
+			0,
+			vec![0; 8],
+			CreateOptions { open_ended: true, ..Default::default() }
+		));
+		let auction_key = created_key();
+		run_to_block(110);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xD), auction_key, 900));
+		run_to_block(125);
+		assert_err!(
+			TaskAuction::expire_open_auction(Origin::signed(0xE), auction_key),
+			Error::<Test>::OpenAuctionActive
+		);
+		run_to_block(130);
+		assert_ok!(TaskAuction::expire_open_auction(Origin::signed(0xE), auction_key));
+		assert!(TaskAuction::auctions(auction_key).is_none());
+		assert_balances!(0xA => (10000 - 800, 0), 0xD => (10000, 0));
+	});
+}
+
+#[test]
+fn arbitrator_scorecard() {
+	new_test_ext().execute_with(|| {
+		AppealPeriod::set(5);
+		assert_ok!(TaskAuction::set_dispute_sla(Origin::root(), None, Some(3)));
+		// first ruling is made in time and upheld
+		let auction_key = assign_auction(0xC, 800);
+		assert_ok!(TaskAuction::dispute(Origin::signed(0xA), auction_key));
+		run_to_block(7);
+		assert_ok!(TaskAuction::arbitrate(Origin::signed(0xB), auction_key, true, None));
+		// second ruling is late and overturned on appeal
+		let auction_key = assign_auction(0xC, 800);
+		assert_ok!(TaskAuction::dispute(Origin::signed(0xC), auction_key));
+		run_to_block(19);
+		assert_ok!(TaskAuction::arbitrate(Origin::signed(0xB), auction_key, true, None));
+		assert_ok!(TaskAuction::appeal(Origin::signed(0xA), auction_key));
+		assert_ok!(TaskAuction::resolve_appeal(Origin::root(), auction_key, true));
+		assert_eq!(
+			TaskAuction::arbitrator_scorecard(&0xB),
+			pallet_task_auction_runtime_api::ArbitratorScorecard {
+				rulings: 2,
+				average_resolution_blocks: 5,
+				sla_breaches: 1,
+				appealed: 1,
+				overturned: 1,
+			}
+		);
+	});
+}
+
+#[test]
+fn expected_state_guards() {
+	new_test_ext().execute_with(|| {
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 800));
+		// retraction signed before assignment is rejected once the auction is assigned
+		run_to_block(5);
+		assert_err!(
+			TaskAuction::retract(Origin::signed(0xC), auction_key, Some(false)),
+			Error::<Test>::UnexpectedAuctionState
+		);
+		assert_balances!(0xC => (9500, 500));
+		assert_ok!(TaskAuction::retract(Origin::signed(0xC), auction_key, Some(true)));
+		assert_balances!(0xA => (9000, 1500), 0xC => (9500, 0));
+		// cancellation signed expecting to pay a penalty is rejected if the top bid
+		// exceeds the bounty
+		assert_ok!(TaskAuction::bid(Origin::signed(0xD), auction_key, 1100));
+		assert_err!(
+			TaskAuction::cancel(Origin::signed(0xA), auction_key, Some(true)),
+			Error::<Test>::UnexpectedAuctionState
+		);
+		assert_ok!(TaskAuction::cancel(Origin::signed(0xA), auction_key, Some(false)));
+		assert_balances!(0xA => (10500, 0), 0xD => (10000, 0));
+	});
+}
+
+#[test]
+fn deadline_extension() {
+	new_test_ext().execute_with(|| {
+		let auction_key = assign_auction(0xC, 800);
+		assert_err!(
+			TaskAuction::propose_extension(Origin::signed(0xD), auction_key, 8),
+			Error::<Test>::OriginProhibited
+		);
+		assert_err!(
+			TaskAuction::propose_extension(Origin::signed(0xC), auction_key, 5),
+			Error::<Test>::InvalidCompletionDeadline
+		);
+		// worker asks for a deadline and the owner agrees
+		assert_ok!(TaskAuction::propose_extension(Origin::signed(0xC), auction_key, 8));
+		assert_err!(
+			TaskAuction::accept_extension(Origin::signed(0xC), auction_key),
+			Error::<Test>::OriginProhibited
+		);
+		assert_ok!(TaskAuction::accept_extension(Origin::signed(0xA), auction_key));
+		assert_eq!(
+			get_auction_event(),
+			Some(AuctionEvent::ExtensionAgreed { auction_key, deadline: 8 })
+		);
+		assert_eq!(TaskAuction::completion_deadlines(8).into_inner(), vec![auction_key]);
+		// deadline can only move later once set
+		assert_err!(
+			TaskAuction::propose_extension(Origin::signed(0xA), auction_key, 7),
+			Error::<Test>::InvalidCompletionDeadline
+		);
+		assert_ok!(TaskAuction::propose_extension(Origin::signed(0xA), auction_key, 10));
+		assert_ok!(TaskAuction::accept_extension(Origin::signed(0xC), auction_key));
+		assert_err!(
+			TaskAuction::accept_extension(Origin::signed(0xC), auction_key),
+			Error::<Test>::ExtensionProposalNotFound
+		);
+		assert!(TaskAuction::completion_deadlines(8).is_empty());
+		assert_eq!(TaskAuction::completion_deadlines(10).into_inner(), vec![auction_key]);
+		// price and deposits are untouched
+		assert_eq!(TaskAuction::bids(auction_key, BidId(0, 0)).unwrap().1, 800);
+		assert_balances!(0xA => (8500, 1500), 0xC => (9500, 500));
+		run_to_block(8);
+		assert!(!TaskAuction::auctions(auction_key).unwrap().in_dispute);
+	});
+}
+
+#[test]
+fn dispute_fee_quote() {
+	new_test_ext().execute_with(|| {
+		ApprovedArbitrators::set(Some(vec![0xB]));
+		assert_err!(
+			TaskAuction::set_arbitrator_fee(Origin::signed(0xC), 100),
+			Error::<Test>::ArbitratorNotApproved
+		);
+		assert_ok!(TaskAuction::set_arbitrator_fee(Origin::signed(0xB), 100));
+		let auction_key = assign_auction(0xC, 800);
+		// disputer locks the quoted fee along with the dispute
+		assert_ok!(TaskAuction::dispute(Origin::signed(0xC), auction_key));
+		assert_eq!(TaskAuction::dispute_quotes(auction_key), Some((0xC, 100)));
+		assert_balances!(0xC => (9400, 100));
+		// raising the fee mid-dispute doesn't change the quote
+		assert_ok!(TaskAuction::set_arbitrator_fee(Origin::signed(0xB), 300));
+		assert_ok!(TaskAuction::arbitrate(Origin::signed(0xB), auction_key, true, None));
+		assert_balances!(0xB => (10000 + 100 + 500, 0), 0xC => (10000 - 100 + 800, 0));
+		// disputes routed to the fallback origin have no quote for the arbitrator to rule on
+		MaxOpenDisputesPerArbitrator::set(0);
+		let auction_key = assign_auction(0xD, 800);
+		assert_ok!(TaskAuction::dispute(Origin::signed(0xA), auction_key));
+		assert_eq!(TaskAuction::dispute_quotes(auction_key), None);
+		assert_err!(
+			TaskAuction::arbitrate(Origin::signed(0xB), auction_key, true, None),
+			Error::<Test>::DisputeQuoteRequired
+		);
+		assert_ok!(TaskAuction::force_arbitrate(Origin::root(), auction_key, true, None));
+	});
+}
+
+#[test]
+fn marketplace_health() {
+	new_test_ext().execute_with(|| {
+		let auction_key = assign_auction(0xC, 800);
+		assert_ok!(TaskAuction::dispute(Origin::signed(0xA), auction_key));
+		let auction_key = create_auction(0xA, 1000, 500, 10);
+		assert_ok!(TaskAuction::set_completion_deadline(Origin::signed(0xA), auction_key, 12));
+		run_to_block(8);
+		assert_eq!(
+			TaskAuction::marketplace_health(),
+			pallet_task_auction_runtime_api::HealthSnapshot {
+				open_auctions: 2,
+				assigned: 1,
+				disputed: 1,
+				expiry_queue_depth: 1,
+				oldest_dispute_age: Some(3),
+			}
+		);
+	});
+}
+
+#[test]
+fn abandonment() {
+	new_test_ext().execute_with(|| {
+		let auction_key = assign_auction(0xC, 800);
+		let active_key = assign_auction(0xD, 800);
+		// worker of the second auction reports progress
+		assert_ok!(TaskAuction::attach(Origin::signed(0xD), active_key, vec![1]));
+		run_to_block(14);
+		assert_err!(
+			TaskAuction::flag_abandoned(Origin::signed(0xE), auction_key),
+			Error::<Test>::AbandonmentWindowActive
+		);
+		run_to_block(19);
+		assert_err!(
+			TaskAuction::flag_abandoned(Origin::signed(0xE), active_key),
+			Error::<Test>::AbandonmentWindowActive
+		);
+		// silent worker pays part of the deposit and bidding restarts
+		assert_ok!(TaskAuction::flag_abandoned(Origin::signed(0xE), auction_key));
+		assert_eq!(
+			get_auction_event(),
+			Some(AuctionEvent::Abandoned { auction_key, worker: 0xC, penalty: 100 })
+		);
+		assert!(TaskAuction::bids(auction_key, BidId(0, 0)).is_none());
+		let auction = TaskAuction::auctions(auction_key).unwrap();
+		assert_eq!((auction.initial_block, auction.terminal_block), (19, 23));
+		assert_balances!(0xA => (10000 - 2 * 1500 + 100, 2 * 1500), 0xC => (10000 - 100, 0));
+	});
+}
+
+#[test]
+fn reopen_clears_completion_deadline() {
+	new_test_ext().execute_with(|| {
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		assert_ok!(TaskAuction::set_completion_deadline(Origin::signed(0xA), auction_key, 30));
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 800));
+		run_to_block(19);
+		assert_ok!(TaskAuction::flag_abandoned(Origin::signed(0xE), auction_key));
+		assert_eq!(TaskAuction::auctions(auction_key).unwrap().completion_deadline, None);
+		assert!(TaskAuction::completion_deadlines(30).is_empty());
+		// the next worker isn't disputed at the deadline of the previous one
+		assert_ok!(TaskAuction::bid(Origin::signed(0xD), auction_key, 800));
+		run_to_block(31);
+		assert!(!TaskAuction::auctions(auction_key).unwrap().in_dispute);
+	});
+}
+
+#[test]
+fn collusion_review() {
+	new_test_ext().execute_with(|| {
+		let auction_key = assign_auction(0xC, 800);
+		assert_err!(
+			TaskAuction::flag_collusion(Origin::signed(0xD), auction_key),
+			Error::<Test>::OriginProhibited
+		);
+		// arbitrator freezes confirmation pending review
+		assert_ok!(TaskAuction::flag_collusion(Origin::signed(0xB), auction_key));
+		assert_eq!(
+			get_auction_event(),
+			Some(AuctionEvent::CollusionFlagged { auction_key, until: 15 })
+		);
+		assert_err!(
+			TaskAuction::flag_collusion(Origin::root(), auction_key),
+			Error::<Test>::ReviewExists
+		);
+		assert_err!(
+			TaskAuction::confirm(Origin::signed(0xA), auction_key),
+			Error::<Test>::AuctionUnderReview
+		);
+		// freeze lapses without a decision
+		run_to_block(15);
+		assert_ok!(TaskAuction::confirm(Origin::signed(0xA), auction_key));
+		assert_balances!(0xC => (10000 + 800, 0));
+		// confirmed collusion cancels the auction and refunds the parties
+		let auction_key = assign_auction(0xD, 800);
+		assert_ok!(TaskAuction::flag_collusion(Origin::root(), auction_key));
+		assert_ok!(TaskAuction::resolve_collusion_review(Origin::root(), auction_key, true));
+		assert!(TaskAuction::auctions(auction_key).is_none());
+		assert_eq!(TaskAuction::collusion_reviews(auction_key), None);
+		assert_balances!(0xD => (10000, 0));
+		// cleared auctions can't be flagged again
+		let auction_key = assign_auction(0xC, 800);
+		assert_ok!(TaskAuction::flag_collusion(Origin::signed(0xB), auction_key));
+		assert_ok!(TaskAuction::resolve_collusion_review(Origin::root(), auction_key, false));
+		assert_eq!(TaskAuction::collusion_reviews(auction_key), Some(None));
+		assert_err!(
+			TaskAuction::flag_collusion(Origin::signed(0xB), auction_key),
+			Error::<Test>::ReviewExists
+		);
+		assert_err!(
+			TaskAuction::resolve_collusion_review(Origin::root(), auction_key, true),
+			Error::<Test>::ReviewNotFound
+		);
+		assert_ok!(TaskAuction::confirm(Origin::signed(0xA), auction_key));
+	});
+}
+
+#[test]
+fn data_size_class() {
+	new_test_ext().execute_with(|| {
+		ListingFeePerByte::set(10);
+		assert_err!(
+			TaskAuction::create(
+				Origin::signed(0xA),
+				0xB,
+				1000,
+				500,
+				5,
+				vec![0; 100],
+				CreateOptions { size_class: Some(DataSizeClass::Small), ..Default::default() }
+			),
+			Error::<Test>::MaxDataSizeExceeded
+		);
+		// larger classes scale the byte fee
+		assert_ok!(TaskAuction::create(
+			Origin::signed(0xA),
+			0xB,
+			1000,
+			500,
+			5,
+			vec![0; 8],
+			CreateOptions { size_class: Some(DataSizeClass::Large), ..Default::default() }
+		));
+		let auction_key = created_key();
+		let auction = TaskAuction::auctions(auction_key).unwrap();
+		assert_eq!((auction.data_size_class, auction.listing_fee), (DataSizeClass::Large, 320));
+		// plain create picks the smallest class that fits
+		System::inc_account_nonce(&0xA);
+		assert_ok!(TaskAuction::create(
+			Origin::signed(0xA),
+			0xB,
+			1000,
+			500,
+			5,
+			vec![0; 100],
+			Default::default()
+		));
+		let auction_key = created_key();
+		let auction = TaskAuction::auctions(auction_key).unwrap();
+		assert_eq!((auction.data_size_class, auction.listing_fee), (DataSizeClass::Medium, 2000));
+	});
+}
+
+#[test]
+fn boost_bounty() {
+	new_test_ext().execute_with(|| {
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		assert_err!(
+			TaskAuction::boost_bounty(Origin::signed(0xA), auction_key, 500),
+			Error::<Test>::OriginProhibited
+		);
+		assert_ok!(TaskAuction::boost_bounty(Origin::signed(0xC), auction_key, 500));
+		assert_eq!(
+			get_auction_event(),
+			Some(AuctionEvent::BountyBoosted {
+				auction_key,
+				booster: 0xC,
+				amount: 500,
+				bounty: 1500
+			})
+		);
+		assert_balances!(0xC => (9500, 500));
+		// boosted bounty is reachable by the price curve
+		assert_ok!(TaskAuction::bid(Origin::signed(0xD), auction_key, 1200));
+		run_to_block(5);
+		assert_err!(
+			TaskAuction::boost_bounty(Origin::signed(0xE), auction_key, 500),
+			Error::<Test>::AuctionAssigned
+		);
+		// booster pays a third of the price for a third of the bounty
+		assert_ok!(TaskAuction::confirm(Origin::signed(0xA), auction_key));
+		assert_balances!(
+			0xA => (10000 - 800, 0),
+			0xC => (10000 - 400, 0),
+			0xD => (10000 + 1200, 0)
+		);
+		// cancelled auctions refund boosters in full
+		System::inc_account_nonce(&0xA);
+		let auction_key = create_auction(0xA, 1000, 500, 10);
+		assert_ok!(TaskAuction::boost_bounty(Origin::signed(0xE), auction_key, 300));
+		assert_ok!(TaskAuction::cancel(Origin::signed(0xA), auction_key, None));
+		assert_eq!(TaskAuction::boosts(auction_key, 0xE), 0);
+		assert_balances!(0xE => (10000, 0));
+	});
+}
+
+#[test]
+fn boost_refund_on_dispute() {
+	new_test_ext().execute_with(|| {
+		MaxBoostersPerAuction::set(1);
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		assert_ok!(TaskAuction::boost_bounty(Origin::signed(0xC), auction_key, 300));
+		assert_err!(
+			TaskAuction::boost_bounty(Origin::signed(0xD), auction_key, 300),
+			Error::<Test>::MaxBoostersReached
+		);
+		// existing contributors can add to their boost
+		assert_ok!(TaskAuction::boost_bounty(Origin::signed(0xC), auction_key, 200));
+		assert_eq!(TaskAuction::auctions(auction_key).unwrap().boosters, 1);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xE), auction_key, 900));
+		run_to_block(5);
+		assert_ok!(TaskAuction::dispute(Origin::signed(0xA), auction_key));
+		// contributions go back to the booster rather than the owner when not fulfilled
+		assert_ok!(TaskAuction::arbitrate(Origin::signed(0xB), auction_key, false, None));
+		assert_eq!(TaskAuction::boosts(auction_key, 0xC), 0);
+		assert_balances!(
+			0xA => (10000, 0),
+			0xC => (10000, 0),
+			0xE => (10000 - 500, 0)
+		);
+	});
+}
+
+#[test]
+fn redeem_quote() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TaskAuction::create(
+			Origin::signed(0xA),
+			0xB,
+			1000,
+			500,
+			0,
+			vec![0; 8],
+			CreateOptions { open_ended: true, ..Default::default() }
+		));
+		let auction_key = AuctionId(0xA, 0);
+		let quote = Quote { worker: 0xC, price: 800, expiry: 3 };
+		let payload = (auction_key, &quote).encode();
+		// quote must be signed by the quoting worker
+		let forged =
+			SignedQuote { quote: quote.clone(), signature: TestSignature(0xD, payload.clone()) };
+		assert_err!(
+			TaskAuction::redeem_quote(Origin::signed(0xA), auction_key, forged),
+			Error::<Test>::InvalidQuoteSignature
+		);
+		let signed = SignedQuote { quote, signature: TestSignature(0xC, payload) };
+		assert_err!(
+			TaskAuction::redeem_quote(Origin::signed(0xC), auction_key, signed.clone()),
+			Error::<Test>::OwnerRequired
+		);
+		let stale = Quote { worker: 0xC, price: 800, expiry: 0 };
+		let payload = (auction_key, &stale).encode();
+		assert_err!(
+			TaskAuction::redeem_quote(
+				Origin::signed(0xA),
+				auction_key,
+				SignedQuote { quote: stale, signature: TestSignature(0xC, payload) }
+			),
+			Error::<Test>::QuoteExpired
+		);
+		// redeeming places the bid and assigns the open-ended auction at once
+		assert_ok!(TaskAuction::redeem_quote(Origin::signed(0xA), auction_key, signed.clone()));
+		assert_eq!(
+			get_auction_event(),
+			Some(AuctionEvent::QuoteRedeemed { auction_key, bid_key: BidId(0xC, 1), price: 800 })
+		);
+		assert!(TaskAuction::auctions(auction_key).unwrap().accepted);
+		assert_balances!(0xC => (9500, 500));
+		// a redeemed quote can't be replayed
+		assert_err!(
+			TaskAuction::redeem_quote(Origin::signed(0xA), auction_key, signed),
+			Error::<Test>::QuoteAlreadyRedeemed
+		);
+	});
+}
+
+#[test]
+fn queue_retract() {
+	new_test_ext().execute_with(|| {
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 800));
+		assert_ok!(TaskAuction::bid(Origin::signed(0xD), auction_key, 700));
+		assert_err!(
+			TaskAuction::queue_retract(Origin::signed(0xC), auction_key, None),
+			Error::<Test>::TopBidRequired
+		);
+		assert_ok!(TaskAuction::queue_retract(Origin::signed(0xD), auction_key, Some(false)));
+		assert_eq!(TaskAuction::queued_retractions(auction_key, 0xD), Some(Some(false)));
+		// withdrawn with spare weight at the end of the block
+		run_to_block(2);
+		assert_eq!(TaskAuction::queued_retractions(auction_key, 0xD), None);
+		assert!(System::events().iter().any(|record| record.event ==
+			Event::TaskAuction(AuctionEvent::QueuedRetractProcessed {
+				auction_key,
+				bidder: 0xD,
+				result: Ok(())
+			})));
+		assert_eq!(TaskAuction::bids(auction_key, BidId(0, 0)), Some((BidId(0xC, 1), 800)));
+		assert_balances!(0xD => (10000, 0));
+		// retraction no longer matching the expected state fails without losing the deposit
+		run_to_block(5);
+		assert_ok!(TaskAuction::queue_retract(Origin::signed(0xC), auction_key, Some(false)));
+		run_to_block(6);
+		assert!(System::events().iter().any(|record| record.event ==
+			Event::TaskAuction(AuctionEvent::QueuedRetractProcessed {
+				auction_key,
+				bidder: 0xC,
+				result: Err(Error::<Test>::UnexpectedAuctionState.into())
+			})));
+		assert_balances!(0xC => (9500, 500));
+	});
+}
+
+#[test]
+fn cancel_consolation() {
+	new_test_ext().execute_with(|| {
+		CancelConsolation::set(sp_runtime::Perbill::from_percent(10));
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 900));
+		assert_ok!(TaskAuction::bid(Origin::signed(0xD), auction_key, 850));
+		assert_ok!(TaskAuction::bid(Origin::signed(0xE), auction_key, 800));
+		assert_ok!(TaskAuction::bid(Origin::signed(0xD), auction_key, 750));
+		// outbid bidders are consoled once each besides the deposit paid to the top bidder
+		assert_ok!(TaskAuction::cancel(Origin::signed(0xA), auction_key, Some(true)));
+		assert!(System::events().iter().any(|record| record.event ==
+			Event::TaskAuction(AuctionEvent::ConsolationPaid {
+				auction_key,
+				bidder: 0xE,
+				amount: 50
+			})));
+		assert_balances!(
+			0xA => (10000 - 500 - 2 * 50, 0),
+			0xC => (10000 + 50, 0),
+			0xD => (10000 + 500, 0),
+			0xE => (10000 + 50, 0)
+		);
+	});
+}
+
+#[test]
+fn verdict_hash() {
+	new_test_ext().execute_with(|| {
+		let auction_key = assign_auction(0xC, 800);
+		assert_ok!(TaskAuction::dispute(Origin::signed(0xA), auction_key));
+		// arbitrator commits to the reasoning behind the ruling
+		let verdict_hash = Some(sp_core::H256::repeat_byte(1));
+		assert_ok!(TaskAuction::arbitrate(Origin::signed(0xB), auction_key, true, verdict_hash));
+		assert_eq!(
+			get_auction_event(),
+			Some(AuctionEvent::Arbitrated { auction_key, fulfilled: true, verdict_hash })
+		);
+		assert_eq!(TaskAuction::receipt(&auction_key).unwrap().verdict_hash, verdict_hash);
+	});
+}
+
+#[test]
+fn dispute_holds() {
+	new_test_ext().execute_with(|| {
+		let auction_key = assign_auction(0xC, 800);
+		assert_ok!(TaskAuction::dispute(Origin::signed(0xA), auction_key));
+		// escrow of both parties moves to the pallet account
+		assert_eq!(TaskAuction::dispute_holds(auction_key), Some((0xC, 1500, 500)));
+		assert_balances!(TaskAuction::account_id() => (2000, 0), 0xA => (8500, 0));
+		// slashing by other pallets can't reach the held escrow
+		assert_eq!(CompetingSlasher::slash(&0xA, 1500), 0);
+		assert_eq!(CompetingSlasher::slash(&0xC, 500), 0);
+		assert_ok!(TaskAuction::arbitrate(Origin::signed(0xB), auction_key, true, None));
+		assert_eq!(TaskAuction::dispute_holds(auction_key), None);
+		assert_balances!(
+			TaskAuction::account_id() => (0, 0),
+			0xA => (10000 - 800 - 500, 0),
+			0xB => (10000 + 500, 0),
+			0xC => (10000 + 800, 0)
+		);
+	});
+}
+
+#[test]
+fn batched_bids() {
+	new_test_ext().execute_with(|| {
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		assert_err!(
+			TaskAuction::set_bid_batching(Origin::signed(0xC), auction_key, true),
+			Error::<Test>::OwnerRequired
+		);
+		assert_ok!(TaskAuction::set_bid_batching(Origin::signed(0xA), auction_key, true));
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 900));
+		assert_ok!(TaskAuction::bid(Origin::signed(0xD), auction_key, 800));
+		assert_ok!(TaskAuction::bid(Origin::signed(0xE), auction_key, 850));
+		assert_ok!(TaskAuction::bid(Origin::signed(0xD), auction_key, 700));
+		assert_err!(
+			TaskAuction::bid(Origin::signed(0xD), auction_key, 750),
+			Error::<Test>::BidImprovementRequired
+		);
+		// nothing is committed before the end of the block
+		assert_eq!(TaskAuction::bids(auction_key, BidId(0, 0)), None);
+		assert_balances!(0xC => (9500, 500), 0xD => (9500, 500), 0xE => (9500, 500));
+		run_to_block(2);
+		assert_eq!(TaskAuction::staged_bids(auction_key, 0xD), None);
+		assert_eq!(TaskAuction::bids(auction_key, BidId(0, 0)), Some((BidId(0xD, 1), 700)));
+		assert!(System::events().iter().any(|record| record.event ==
+			Event::TaskAuction(AuctionEvent::StagedBidRefunded {
+				auction_key,
+				bidder: 0xE,
+				price: 850
+			})));
+		assert_balances!(0xC => (10000, 0), 0xD => (9500, 500), 0xE => (10000, 0));
+		assert_ok!(TaskAuction::bundle_bid(Origin::signed(0xE), vec![auction_key], 650));
+		assert_err!(
+			TaskAuction::accept_bundle(Origin::signed(0xA), 0xE),
+			Error::<Test>::BidBatchingEnabled
+		);
+	});
+}
+
+#[test]
+fn settlement_receipt() {
+	new_test_ext().execute_with(|| {
+		RemoteChains::set([(0xA, 2000)].into_iter().collect());
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		assert_err!(
+			TaskAuction::request_settlement_receipt(Origin::signed(0xC), auction_key),
+			sp_runtime::traits::BadOrigin
+		);
+		assert_ok!(TaskAuction::request_settlement_receipt(Origin::signed(0xA), auction_key));
+		assert_eq!(TaskAuction::receipt_destinations(auction_key), Some(2000));
+		assert_ok!(TaskAuction::cancel(Origin::signed(0xA), auction_key, None));
+		// outcome and amounts are sent back to the chain of the owner once
+		assert_eq!(SentReceipts::get(), vec![(2000, auction_key, None, 0)]);
+		assert_eq!(TaskAuction::receipt_destinations(auction_key), None);
+		assert!(System::events().iter().any(|record| record.event ==
+			Event::TaskAuction(AuctionEvent::SettlementReceiptSent {
+				auction_key,
+				destination: 2000,
+				result: Ok(())
+			})));
+	});
+}
+
+#[test]
+fn proxy_bid_prohibited() {
+	new_test_ext().execute_with(|| {
+		// 0xC acts for the arbitrator and 0xD for the owner
+		ProxyPairs::set(vec![(0xB, 0xC), (0xA, 0xD)]);
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		assert_err!(
+			TaskAuction::bid(Origin::signed(0xC), auction_key, 800),
+			Error::<Test>::OriginProhibited
+		);
+		assert_err!(
+			TaskAuction::bid(Origin::signed(0xD), auction_key, 800),
+			Error::<Test>::OriginProhibited
+		);
+		assert_err!(
+			TaskAuction::bundle_bid(Origin::signed(0xC), vec![auction_key], 800),
+			Error::<Test>::OriginProhibited
+		);
+		// staged bids are checked as well
+		assert_ok!(TaskAuction::set_bid_batching(Origin::signed(0xA), auction_key, true));
+		assert_err!(
+			TaskAuction::bid(Origin::signed(0xC), auction_key, 800),
+			Error::<Test>::OriginProhibited
+		);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xE), auction_key, 800));
+	});
+}
+
+#[test]
+fn task_requirements() {
+	new_test_ext().execute_with(|| {
+		let requirements = TaskRequirements {
+			deadline: 10,
+			deliverable: Deliverable::Code,
+			quantity: 1,
+			capability: None,
+		};
+		for invalid in [
+			TaskRequirements { quantity: 0, ..requirements.clone() },
+			TaskRequirements { deadline: 5, ..requirements.clone() },
+		] {
+			assert_err!(
+				TaskAuction::create(
+					Origin::signed(0xA),
+					0xB,
+					1000,
+					500,
+					5,
+					vec![0; 8],
+					CreateOptions { requirements: Some(invalid), ..Default::default() }
+				),
+				Error::<Test>::InvalidRequirements
+			);
+		}
+		assert_ok!(TaskAuction::create(
+			Origin::signed(0xA),
+			0xB,
+			1000,
+			500,
+			5,
+			vec![0; 8],
+			CreateOptions { requirements: Some(requirements.clone()), ..Default::default() }
+		));
+		let auction_key = AuctionId(0xA, 0);
+		assert_eq!(TaskAuction::requirements(auction_key), Some(requirements));
+		assert_err!(
+			TaskAuction::bid_with_deliverable(
+				Origin::signed(0xC),
+				auction_key,
+				800,
+				Deliverable::Dataset
+			),
+			Error::<Test>::DeliverableMismatch
+		);
+		assert_ok!(TaskAuction::bid_with_deliverable(
+			Origin::signed(0xC),
+			auction_key,
+			800,
+			Deliverable::Code
+		));
+		assert_ok!(TaskAuction::cancel(Origin::signed(0xA), auction_key, None));
+		assert_eq!(TaskAuction::requirements(auction_key), None);
+	});
+}
+
+#[test]
+fn savings_share() {
+	new_test_ext().execute_with(|| {
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		let half = sp_runtime::Permill::from_percent(50);
+		assert_err!(
+			TaskAuction::set_savings_share(Origin::signed(0xC), auction_key, half),
+			Error::<Test>::OwnerRequired
+		);
+		assert_ok!(TaskAuction::set_savings_share(Origin::signed(0xA), auction_key, half));
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 600));
+		run_to_block(5);
+		assert_ok!(TaskAuction::confirm(Origin::signed(0xA), auction_key));
+		// worker gets half of the 400 saved below the bounty
+		assert_eq!(
+			get_auction_event(),
+			Some(AuctionEvent::Confirmed {
+				auction_key,
+				vesting_period: None,
+				price: 800,
+				savings: 200,
+				rate: None
+			})
+		);
+		assert_balances!(0xA => (10000 - 800, 0), 0xC => (10000 + 800, 0));
+	});
+}
+
+#[test]
+fn dispute_tombstone() {
+	new_test_ext().execute_with(|| {
+		let auction_key = assign_auction(0xC, 800);
+		assert_ok!(TaskAuction::dispute(Origin::signed(0xA), auction_key));
+		let verdict_hash = Some(sp_core::H256::repeat_byte(1));
+		assert_ok!(TaskAuction::arbitrate(Origin::signed(0xB), auction_key, false, verdict_hash));
+		// parties and hashes outlive the auction for the retention period
+		let tombstone = TaskAuction::tombstones(auction_key).unwrap();
+		assert_eq!(
+			(tombstone.worker, tombstone.arbitrator, tombstone.fulfilled, tombstone.verdict_hash),
+			(0xC, 0xB, false, verdict_hash)
+		);
+		assert_eq!(
+			tombstone.data_hash,
+			<sp_runtime::traits::BlakeTwo256 as sp_runtime::traits::Hash>::hash(&[0; 8])
+		);
+		assert_eq!(tombstone.settled_block, 5);
+		run_to_block(14);
+		assert!(TaskAuction::tombstones(auction_key).is_some());
+		run_to_block(15);
+		assert_eq!(TaskAuction::tombstones(auction_key), None);
+	});
+}
+
+#[test]
+fn create_with_bid() {
+	new_test_ext().execute_with(|| {
+		let auction_key = AuctionId(0xA, 0);
+		let sign = |worker: u64, price: u128| {
+			let quote = Quote { worker, price, expiry: 3 };
+			let payload = (auction_key, &quote).encode();
+			SignedQuote { quote, signature: TestSignature(worker, payload) }
+		};
+		assert_err!(
+			TaskAuction::create(
+				Origin::signed(0xA),
+				0xB,
+				1000,
+				500,
+				5,
+				vec![0; 8],
+				CreateOptions {
+					quote: Some(SignedQuote {
+						signature: TestSignature(0xD, vec![]),
+						..sign(0xC, 800)
+					}),
+					..Default::default()
+				}
+			),
+			Error::<Test>::InvalidQuoteSignature
+		);
+		// auction is rolled back with the failed bid of the arbitrator
+		assert_err!(
+			TaskAuction::create(
+				Origin::signed(0xA),
+				0xB,
+				1000,
+				500,
+				5,
+				vec![0; 8],
+				CreateOptions { quote: Some(sign(0xB, 800)), ..Default::default() }
+			),
+			Error::<Test>::OriginProhibited
+		);
+		assert!(TaskAuction::auctions(auction_key).is_none());
+		assert_balances!(0xA => (10000, 0));
+		assert_ok!(TaskAuction::create(
+			Origin::signed(0xA),
+			0xB,
+			1000,
+			500,
+			5,
+			vec![0; 8],
+			CreateOptions { quote: Some(sign(0xC, 800)), ..Default::default() }
+		));
+		assert_eq!(TaskAuction::bids(auction_key, BidId(0, 0)), Some((BidId(0xC, 1), 800)));
+		assert_balances!(0xA => (8500, 1500), 0xC => (9500, 500));
+		// the recorded quote can't be replayed on the created auction
+		assert_err!(
+			TaskAuction::redeem_quote(Origin::signed(0xA), auction_key, sign(0xC, 800)),
+			Error::<Test>::QuoteAlreadyRedeemed
+		);
+	});
+}
+
+#[test]
+fn max_bounty() {
+	new_test_ext().execute_with(|| {
+		MaxBounty::set(5000);
+		assert_err!(
+			TaskAuction::create(
+				Origin::signed(0xA),
+				0xB,
+				5001,
+				500,
+				5,
+				vec![0; 8],
+				Default::default()
+			),
+			Error::<Test>::MaxBountyExceeded
+		);
+		let auction_key = create_auction(0xA, 5000, 500, 5);
+		assert_err!(
+			TaskAuction::extend(Origin::signed(0xA), auction_key, 5600, 9),
+			Error::<Test>::MaxBountyExceeded
+		);
+		assert_err!(
+			TaskAuction::boost_bounty(Origin::signed(0xC), auction_key, 1),
+			Error::<Test>::MaxBountyExceeded
+		);
+		// reserve of amounts near the balance limit overflows instead of wrapping
+		MaxBounty::set(u128::MAX);
+		System::inc_account_nonce(&0xA);
+		assert_err!(
+			TaskAuction::create(
+				Origin::signed(0xA),
+				0xB,
+				u128::MAX - 100,
+				500,
+				5,
+				vec![0; 8],
+				Default::default()
+			),
+			sp_runtime::ArithmeticError::Overflow
+		);
+		assert_err!(
+			TaskAuction::boost_bounty(Origin::signed(0xC), auction_key, u128::MAX - 4000),
+			sp_runtime::ArithmeticError::Overflow
+		);
+		assert_eq!(TaskAuction::estimate_create(u128::MAX - 100, 500, 8).reserve_total, u128::MAX);
+		assert_balances!(0xA => (4500, 5500), 0xC => (10000, 0));
+	});
+}
+
+#[test]
+fn account_statement() {
+	new_test_ext().execute_with(|| {
+		let auction_key = assign_auction(0xC, 800);
+		assert_ok!(TaskAuction::confirm(Origin::signed(0xA), auction_key));
+		assert_eq!(TaskAuction::account_statement(&0xC, 0).earned, 800);
+		assert_eq!(TaskAuction::account_statement(&0xA, 0).spent, 800);
+		// totals roll over into the next period
+		run_to_block(100);
+		System::inc_account_nonce(&0xA);
+		let auction_key = assign_auction(0xC, 800);
+		assert_ok!(TaskAuction::dispute(Origin::signed(0xA), auction_key));
+		assert_ok!(TaskAuction::arbitrate(Origin::signed(0xB), auction_key, false, None));
+		let statement = TaskAuction::account_statement(&0xC, 1);
+		assert_eq!((statement.start_block, statement.end_block), (100, 200));
+		assert_eq!((statement.earned, statement.slashed), (0, 500));
+		assert_eq!(TaskAuction::account_statement(&0xB, 1).arbitration_income, 500);
+		assert_eq!(TaskAuction::account_statement(&0xC, 0).slashed, 0);
+	});
+}
+
+#[test]
+fn auto_bid() {
+	new_test_ext().execute_with(|| {
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 900));
+		assert_ok!(TaskAuction::set_auto_bid(Origin::signed(0xC), auction_key, 600, 50));
+		// outbid bidder counters by the decrement
+		assert_ok!(TaskAuction::bid(Origin::signed(0xD), auction_key, 800));
+		assert_eq!(TaskAuction::bids(auction_key, BidId(0, 0)), Some((BidId(0xC, 3), 750)));
+		// counter is capped by the minimum bid ratio and stops at the floor
+		assert_ok!(TaskAuction::bid(Origin::signed(0xD), auction_key, 650));
+		assert_eq!(TaskAuction::bids(auction_key, BidId(0, 0)), Some((BidId(0xC, 5), 600)));
+		assert_ok!(TaskAuction::bid(Origin::signed(0xD), auction_key, 580));
+		assert_eq!(TaskAuction::bids(auction_key, BidId(0, 0)), Some((BidId(0xD, 6), 580)));
+		assert_eq!(TaskAuction::auto_bids(auction_key, 0xC), None);
+		assert_eq!(
+			get_auction_event(),
+			Some(AuctionEvent::AutoBidExhausted { auction_key, bidder: 0xC })
+		);
+		assert_balances!(0xC => (10000, 0), 0xD => (9500, 500));
+	});
+}
+
+#[test]
+fn quoted_settlement() {
+	new_test_ext().execute_with(|| {
+		let rate = |n, d| sp_runtime::FixedU128::saturating_from_rational(n, d);
+		assert_err!(
+			TaskAuction::create(
+				Origin::signed(0xA),
+				0xB,
+				1000,
+				500,
+				5,
+				vec![0; 8],
+				CreateOptions { quote_asset: Some(1), ..Default::default() }
+			),
+			Error::<Test>::UnknownQuoteAsset
+		);
+		QuoteRates::set(vec![(1, rate(2, 1))].into_iter().collect());
+		assert_ok!(TaskAuction::create(
+			Origin::signed(0xA),
+			0xB,
+			1000,
+			500,
+			5,
+			vec![0; 8],
+			CreateOptions { quote_asset: Some(1), ..Default::default() }
+		));
+		let auction_key = AuctionId(0xA, 0);
+		// 600 of the quote asset is worth more than the whole bounty
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 600));
+		assert_ok!(TaskAuction::bid(Origin::signed(0xD), auction_key, 400));
+		run_to_block(5);
+		// converted at the rate of confirmation, or the rate of creation once the oracle stops
+		QuoteRates::set(vec![(1, rate(9, 4))].into_iter().collect());
+		assert!(TaskAuction::auctions(auction_key).unwrap().is_assigned(&0xD, 400));
+		QuoteRates::set(Default::default());
+		assert!(!TaskAuction::auctions(auction_key).unwrap().is_assigned(&0xD, 600));
+		QuoteRates::set(vec![(1, rate(9, 4))].into_iter().collect());
+		assert_ok!(TaskAuction::confirm(Origin::signed(0xA), auction_key));
+		assert_eq!(
+			get_auction_event(),
+			Some(AuctionEvent::Confirmed {
+				auction_key,
+				vesting_period: None,
+				price: 900,
+				savings: 0,
+				rate: Some(rate(9, 4))
+			})
+		);
+		assert_balances!(0xA => (10000 - 900, 0), 0xD => (10000 + 900, 0));
+	});
+}
+
+#[test]
+fn operators() {
+	new_test_ext().execute_with(|| {
+		create_auction(0xA, 1000, 500, 5);
+		System::inc_account_nonce(&0xA);
+		create_auction(0xA, 1000, 500, 5);
+		let (first_key, second_key) = (AuctionId(0xA, 0), AuctionId(0xA, 1));
+		assert_err!(
+			TaskAuction::set_operator(Origin::signed(0xC), Some(first_key), 0xE, OPERATOR_CANCEL),
+			Error::<Test>::OwnerRequired
+		);
+		assert_ok!(TaskAuction::set_operator(Origin::signed(0xA), None, 0xE, OPERATOR_EXTEND));
+		assert_ok!(TaskAuction::set_operator(
+			Origin::signed(0xA),
+			Some(first_key),
+			0xE,
+			OPERATOR_CANCEL
+		));
+		// operator extends any auction of the owner, escrowed by the owner
+		assert_ok!(TaskAuction::extend(Origin::signed(0xE), second_key, 2000, 10));
+		assert_balances!(0xA => (10000 - 3000 - 1000, 3000 + 1000), 0xE => (10000, 0));
+		assert_err!(
+			TaskAuction::confirm(Origin::signed(0xE), first_key),
+			Error::<Test>::OwnerRequired
+		);
+		assert_err!(
+			TaskAuction::cancel(Origin::signed(0xE), second_key, None),
+			Error::<Test>::OwnerRequired
+		);
+		assert_ok!(TaskAuction::cancel(Origin::signed(0xE), first_key, None));
+		assert_balances!(0xA => (10000 - 2000 - 500, 2000 + 500), 0xE => (10000, 0));
+		// no permissions remove the operator
+		assert_ok!(TaskAuction::set_operator(Origin::signed(0xA), None, 0xE, 0));
+		assert_eq!(TaskAuction::operators(0xA, (0xE, None::<AuctionId<u64, u64>>)), 0);
+		assert_err!(
+			TaskAuction::extend(Origin::signed(0xE), second_key, 3000, 10),
+			Error::<Test>::OwnerRequired
+		);
+	});
+}
+
+#[test]
+fn retract_top_up() {
+	use frame_support::traits::ReservableCurrency;
+	new_test_ext().execute_with(|| {
+		TopUpPeriod::set(3);
+		create_auction(0xA, 1000, 500, 5);
+		System::inc_account_nonce(&0xA);
+		create_auction(0xA, 1000, 500, 5);
+		let (auction_key, other_key) = (AuctionId(0xA, 0), AuctionId(0xA, 1));
+		assert_ok!(TaskAuction::bid(Origin::signed(0xE), auction_key, 900));
+		assert_ok!(TaskAuction::bid(Origin::signed(0xD), auction_key, 800));
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 700));
+		// D ties up its free balance elsewhere before C retracts
+		assert_ok!(Balances::reserve(&0xD, 9600));
+		assert_ok!(TaskAuction::retract(Origin::signed(0xC), auction_key, None));
+		let deadline = System::block_number() + 3;
+		assert_eq!(
+			get_auction_event(),
+			Some(AuctionEvent::Retracted { auction_key, bid_key: BidId(0xD, 2), price: 800 })
+		);
+		assert_eq!(TaskAuction::auctions(auction_key).unwrap().top_up_deadline, Some(deadline));
+		assert_err!(
+			TaskAuction::withdraw_expired_bid(Origin::signed(0xD), auction_key),
+			Error::<Test>::BidNotExpired
+		);
+		// D keeps the top bid by topping up within the period
+		Balances::unreserve(&0xD, 9600);
+		assert_ok!(TaskAuction::top_up(Origin::signed(0xD), auction_key));
+		assert_balances!(0xD => (10000 - 500, 500));
+		assert_err!(
+			TaskAuction::top_up(Origin::signed(0xD), auction_key),
+			Error::<Test>::TopUpNotRequired
+		);
+		// E ties up its balance while holding the deposit of another auction
+		assert_ok!(TaskAuction::bid(Origin::signed(0xE), other_key, 900));
+		assert_ok!(Balances::reserve(&0xE, 9100));
+		assert_ok!(TaskAuction::retract(Origin::signed(0xD), auction_key, None));
+		assert_balances!(0xD => (10000, 0));
+		// outbidding an unfunded top bid leaves the other deposit of E reserved
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 700));
+		assert_balances!(0xE => (400, 9600));
+		assert_ok!(TaskAuction::retract(Origin::signed(0xC), auction_key, None));
+		let deadline = System::block_number() + 3;
+		assert!(!TaskAuction::auctions(auction_key).unwrap().is_assigned(&0xE, 0));
+		// E is dropped once the period passes without a top-up
+		run_to_block(deadline);
+		assert_eq!(
+			get_auction_event(),
+			Some(AuctionEvent::TopUpExpired { auction_key, bid_key: BidId(0, 0), price: 1000 })
+		);
+		assert_eq!(TaskAuction::auctions(auction_key).unwrap().top_up_deadline, None);
+		assert_balances!(0xE => (400, 9600));
+	});
+}
+
+#[test]
+fn projects() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TaskAuction::create_project(Origin::signed(0xA), b"site".to_vec(), 0xB, 500, 5));
+		// members take the arbitrator, deposit and duration of the project
+		let create_in_project = |owner| {
+			TaskAuction::create(
+				Origin::signed(owner),
+				0xD,
+				1000,
+				0,
+				0,
+				vec![0; 8],
+				CreateOptions {
+					source: TermsSource::Project { project_id: 0 },
+					..Default::default()
+				},
+			)
+		};
+		assert_err!(create_in_project(0xC), Error::<Test>::OwnerRequired);
+		for _ in 0..3 {
+			assert_ok!(create_in_project(0xA));
+			System::inc_account_nonce(&0xA);
+		}
+		assert_err!(create_in_project(0xA), Error::<Test>::MaxAuctionsPerProjectReached);
+		let auction_key = AuctionId(0xA, 0);
+		assert_eq!(TaskAuction::auctions(auction_key).unwrap().arbitrator, 0xB);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 800));
+		run_to_block(6);
+		let summary = TaskAuction::project_summary(0).unwrap();
+		assert_eq!((summary.open, summary.assigned, summary.total_bounty), (2, 1, 3000));
+		// cancelled in bounded batches, the assigned auction leaves the project
+		assert_ok!(TaskAuction::cancel_project(Origin::signed(0xA), 0, 2));
+		assert_eq!(
+			get_auction_event(),
+			Some(AuctionEvent::ProjectCancelled { project_id: 0, remaining: 1 })
+		);
+		assert_err!(create_in_project(0xA), Error::<Test>::ProjectCancelled);
+		assert_ok!(TaskAuction::cancel_project(Origin::signed(0xA), 0, 2));
+		// the assigned auction can't be cancelled and is reported
+		assert!(System::events().iter().any(|record| match &record.event {
+			Event::TaskAuction(AuctionEvent::ProjectAuctionCancelFailed {
+				project_id: 0,
+				auction_key: failed,
+				..
+			}) => *failed == auction_key,
+			_ => false,
+		}));
+		assert!(TaskAuction::projects(0).is_none());
+		assert!(TaskAuction::auctions(auction_key).is_some());
+		assert_balances!(0xA => (10000 - 1500, 1500));
+	});
+}
+
+#[test]
+fn rescale_open_auctions() {
+	new_test_ext().execute_with(|| {
+		let double = sp_runtime::FixedU128::saturating_from_integer(2);
+		create_auction(0xA, 1000, 500, 5);
+		System::inc_account_nonce(&0xA);
+		assert_ok!(TaskAuction::create(
+			Origin::signed(0xA),
+			0xB,
+			1000,
+			500,
+			5,
+			vec![0; 8],
+			CreateOptions { fixed_bounty: true, ..Default::default() }
+		));
+		// C can't escrow twice its bounty
+		create_auction(0xC, 9000, 500, 5);
+		run_to_block(2);
+		assert_err!(
+			TaskAuction::rescale_open_auctions(Origin::signed(0xA), double),
+			sp_runtime::traits::BadOrigin
+		);
+		assert_ok!(TaskAuction::rescale_open_auctions(Origin::root(), double));
+		assert_err!(
+			TaskAuction::rescale_open_auctions(Origin::root(), double),
+			Error::<Test>::RescaleInProgress
+		);
+		// listed while the rescale is pending, so already at the new scale
+		create_auction(0xD, 2000, 500, 5);
+		// bounties are adjusted in on_idle
+		run_to_block(3);
+		assert!(TaskAuction::pending_rescale().is_none());
+		let bounty = |auction_key| TaskAuction::auctions(auction_key).unwrap().bounty;
+		assert_eq!(bounty(AuctionId(0xA, 0)), 2000);
+		assert_eq!(bounty(AuctionId(0xA, 1)), 1000);
+		assert_eq!(bounty(AuctionId(0xC, 0)), 9000);
+		assert_eq!(bounty(AuctionId(0xD, 0)), 2000);
+		assert_eq!(TaskAuction::auctions(AuctionId(0xA, 0)).unwrap().version, 1);
+		assert_eq!(TaskAuction::auctions(AuctionId(0xD, 0)).unwrap().version, 0);
+		assert_balances!(
+			0xA => (10000 - 4000, 4000),
+			0xC => (500, 9500),
+			0xD => (10000 - 2500, 2500)
+		);
+	});
+}
+
+#[test]
+fn clock_override() {
+	use frame_support::traits::ReservableCurrency;
+	new_test_ext().execute_with(|| {
+		TopUpPeriod::set(3);
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xE), auction_key, 900));
+		assert_ok!(TaskAuction::bid(Origin::signed(0xD), auction_key, 800));
+		// base price follows the provider rather than the system block number
+		let auction = TaskAuction::auctions(auction_key).unwrap();
+		assert!(!auction.is_assigned(&0xD, 800));
+		ClockOverride::set(Some(5));
+		assert!(auction.is_assigned(&0xD, 800));
+		ClockOverride::set(None);
+		// E is promoted without its deposit, with a top-up deadline at block 4
+		assert_ok!(Balances::reserve(&0xE, 9600));
+		assert_ok!(TaskAuction::retract(Origin::signed(0xD), auction_key, None));
+		assert_eq!(TaskAuction::auctions(auction_key).unwrap().top_up_deadline, Some(4));
+		run_to_block(2);
+		// deadlines of provider blocks skipped between two blocks are still processed
+		ClockOverride::set(Some(10));
+		run_to_block(3);
+		assert_eq!(TaskAuction::deadlines_processed_until(), Some(10));
+		assert_eq!(TaskAuction::auctions(auction_key).unwrap().top_up_deadline, None);
+		assert_eq!(TaskAuction::bids(auction_key, BidId(0, 0)), None);
+	});
+}
+
+#[test]
+fn multi_asset_bounty() {
+	new_test_ext().execute_with(|| {
+		let free = |who| AssetBalances::get().get(&(1, who)).copied().unwrap_or(0);
+		let locked = |who| LockedAssets::get().get(&(1, who)).copied().unwrap_or(0);
+		let insufficient = sp_runtime::DispatchError::Other("insufficient asset balance");
+		AssetBalances::set(vec![((1, 0xA), 2000), ((1, 0xC), 500)].into_iter().collect());
+		assert_err!(
+			TaskAuction::create(
+				Origin::signed(0xA),
+				0xB,
+				1000,
+				500,
+				5,
+				vec![0; 8],
+				CreateOptions { asset: Some(2), ..Default::default() }
+			),
+			insufficient
+		);
+		assert_ok!(TaskAuction::create(
+			Origin::signed(0xA),
+			0xB,
+			1000,
+			500,
+			5,
+			vec![0; 8],
+			CreateOptions { asset: Some(1), ..Default::default() }
+		));
+		let auction_key = AuctionId(0xA, 0);
+		assert_eq!(
+			get_auction_event(),
+			Some(AuctionEvent::BountyAssetSet { auction_key, asset_id: 1 })
+		);
+		// bounty and deposit are escrowed in the asset, boosts only in the native currency
+		assert_eq!((free(0xA), locked(0xA)), (500, 1500));
+		assert_balances!(0xA => (10000, 0));
+		assert_err!(
+			TaskAuction::boost_bounty(Origin::signed(0xD), auction_key, 100),
+			Error::<Test>::NativeAuctionRequired
+		);
+		assert_err!(TaskAuction::bid(Origin::signed(0xD), auction_key, 800), insufficient);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 800));
+		assert_eq!((free(0xC), locked(0xC)), (0, 500));
+		run_to_block(5);
+		// settlement fails unless every escrowed amount is returned
+		let escrowed = LockedAssets::get();
+		LockedAssets::set(Default::default());
+		assert_err!(TaskAuction::confirm(Origin::signed(0xA), auction_key), insufficient);
+		LockedAssets::set(escrowed);
+		assert_ok!(TaskAuction::confirm(Origin::signed(0xA), auction_key));
+		assert_eq!((free(0xA), locked(0xA)), (2000 - 800, 0));
+		assert_eq!((free(0xC), locked(0xC)), (500 + 800, 0));
+		assert_balances!(0xA => (10000, 0), 0xC => (10000, 0));
+	});
+}
+
+#[test]
+fn settlement_snapshot() {
+	new_test_ext().execute_with(|| {
+		let snapshots = || {
+			System::events()
+				.into_iter()
+				.filter_map(|record| match record.event {
+					Event::TaskAuction(event @ AuctionEvent::SettlementSnapshot { .. }) =>
+						Some(event),
+					_ => None,
+				})
+				.collect::<Vec<_>>()
+		};
+		let cancelled_key = create_auction(0xA, 1000, 500, 5);
+		assert_ok!(TaskAuction::cancel(Origin::signed(0xA), cancelled_key, None));
+		System::inc_account_nonce(&0xA);
+		assert!(snapshots().is_empty());
+		EmitSettlementSnapshots::set(true);
+		let auction_key = assign_auction(0xC, 800);
+		let head = TaskAuction::auctions(auction_key).unwrap().encode();
+		assert_ok!(TaskAuction::confirm(Origin::signed(0xA), auction_key));
+		assert_eq!(
+			snapshots(),
+			vec![AuctionEvent::SettlementSnapshot {
+				auction_key,
+				head,
+				winning_bid: Some((BidId(0xC, 1), 800)),
+				worker: Some(0xC),
+				price: 800,
+				boosted: 0,
+				outcome: Outcome::Confirmed,
+				settled_block: 5,
+			}]
+		);
+	});
+}
+
+#[test]
+fn decrement_schedule() {
+	use crate::{ConstantDecrement, DecrementSchedule, ShrinkingDecrement};
+	new_test_ext().execute_with(|| {
+		create_auction(0xA, 1000, 500, 5);
+		let mut auction = TaskAuction::auctions(AuctionId(0xA, 0)).unwrap();
+		let shrinking = |auction, block| {
+			<ShrinkingDecrement as DecrementSchedule<Test>>::min_bid_ratio(auction, block)
+		};
+		// constant schedule keeps the configured ratio until the terminal block
+		assert_eq!(<ConstantDecrement as DecrementSchedule<Test>>::min_bid_ratio(&auction, 5), 250);
+		assert_eq!(auction.min_bid_ratio(), 250);
+		// shrinking schedule requires smaller decrements as the terminal block approaches
+		auction.min_bid_ratio = Some(55);
+		assert_eq!(shrinking(&auction, 1), 55);
+		assert_eq!(shrinking(&auction, 3), 155);
+		assert_eq!(shrinking(&auction, 5), 255);
+		assert_eq!(shrinking(&auction, 9), 255);
+		auction.open_ended = true;
+		assert_eq!(shrinking(&auction, 3), 55);
+	});
+}
+
+#[test]
+fn resolve_default() {
+	new_test_ext().execute_with(|| {
+		let auction_key = assign_auction(0xC, 800);
+		assert_err!(
+			TaskAuction::resolve_default(Origin::signed(0xC), auction_key),
+			Error::<Test>::AuctionNotDisputed
+		);
+		assert_ok!(TaskAuction::dispute(Origin::signed(0xA), auction_key));
+		assert_err!(
+			TaskAuction::resolve_default(Origin::signed(0xC), auction_key),
+			Error::<Test>::ArbitrationPeriodActive
+		);
+		run_to_block(25);
+		assert_err!(
+			TaskAuction::resolve_default(Origin::signed(0xD), auction_key),
+			Error::<Test>::OriginProhibited
+		);
+		// arbitrator missed the period, the worker gets half the price and the arbitrator nothing
+		assert_ok!(TaskAuction::resolve_default(Origin::signed(0xC), auction_key));
+		assert_eq!(
+			get_auction_event(),
+			Some(AuctionEvent::DefaultJudgment { auction_key, worker: 0xC, paid: 400 })
+		);
+		assert_balances!(0xA => (10000 - 400, 0), 0xB => (10000, 0), 0xC => (10000 + 400, 0));
+		assert!(TaskAuction::auctions(auction_key).is_none());
+	});
+}
+
+#[test]
+fn extend_past_terminal() {
+	new_test_ext().execute_with(|| {
+		let auction_key = create_auction(0xA, 1000, 500, 5);
+		// live auctions can still be extended to any terminal block
+		assert_ok!(TaskAuction::extend(Origin::signed(0xA), auction_key, 1600, 4));
+		assert_eq!(
+			get_auction_event(),
+			Some(AuctionEvent::Extended { auction_key, bounty: 1600, terminal_block: 4 })
+		);
+		run_to_block(6);
+		// expired auction without bids must be relisted into the future
+		assert_err!(
+			TaskAuction::extend(Origin::signed(0xA), auction_key, 2200, 6),
+			Error::<Test>::FutureTerminalBlockRequired
+		);
+		assert_ok!(TaskAuction::extend(Origin::signed(0xA), auction_key, 2200, 10));
+		assert_eq!(
+			get_auction_event(),
+			Some(AuctionEvent::Relisted {
+				auction_key,
+				bounty: 2200,
+				initial_block: 6,
+				terminal_block: 10
+			})
+		);
+		// price curve restarts from the relisting block
+		let auction = TaskAuction::auctions(auction_key).unwrap();
+		assert_eq!((auction.initial_block, auction.get_base_price()), (6, 0));
+		assert_balances!(0xA => (10000 - 2700, 2700));
+	});
+}
+
+#[test]
+fn milestones() {
+	new_test_ext().execute_with(|| {
+		let percent = sp_runtime::Perbill::from_percent;
+		assert_err!(
+			TaskAuction::create(
+				Origin::signed(0xA),
+				0xB,
+				1000,
+				500,
+				5,
+				vec![0; 8],
+				CreateOptions { milestones: vec![percent(50), percent(40)], ..Default::default() }
+			),
+			Error::<Test>::InvalidMilestones
+		);
+		assert_ok!(TaskAuction::create(
+			Origin::signed(0xA),
+			0xB,
+			1000,
+			500,
+			5,
+			vec![0; 8],
+			CreateOptions {
+				milestones: vec![percent(20), percent(30), percent(50)],
+				..Default::default()
+			}
+		));
+		let auction_key = AuctionId(0xA, 0);
+		assert_ok!(TaskAuction::bid(Origin::signed(0xC), auction_key, 800));
+		assert_err!(
+			TaskAuction::confirm_milestone(Origin::signed(0xA), auction_key, 1),
+			Error::<Test>::AuctionNotAssigned
+		);
+		run_to_block(5);
+		// each milestone pays its share of the price out of the escrow of the owner
+		assert_ok!(TaskAuction::confirm_milestone(Origin::signed(0xA), auction_key, 1));
+		assert_eq!(
+			get_auction_event(),
+			Some(AuctionEvent::MilestoneConfirmed { auction_key, index: 1, paid: 240 })
+		);
+		assert_balances!(0xA => (10000 - 1500, 1260), 0xC => (10000 - 500 + 240, 500));
+		assert_err!(
+			TaskAuction::confirm_milestone(Origin::signed(0xA), auction_key, 1),
+			Error::<Test>::MilestoneConfirmed
+		);
+		assert_err!(
+			TaskAuction::confirm_milestone(Origin::signed(0xA), auction_key, 3),
+			Error::<Test>::MilestoneNotFound
+		);
+		assert_ok!(TaskAuction::confirm_milestone(Origin::signed(0xA), auction_key, 0));
+		assert_balances!(0xA => (10000 - 1500, 1100), 0xC => (10000 - 500 + 400, 500));
+		// last milestone confirms the auction, paying the rest of the price
+		assert_ok!(TaskAuction::confirm_milestone(Origin::signed(0xA), auction_key, 2));
+		assert_balances!(0xA => (10000 - 800, 0), 0xC => (10000 + 800, 0));
+		assert!(TaskAuction::auctions(auction_key).is_none());
+		assert_eq!(TaskAuction::milestones(auction_key), None);
+	});
+}
+
+#[test]
+fn confirm_items() {
+	new_test_ext().execute_with(|| {
+		let auction_key = assign_auction(0xC, 800);
+		assert_err!(
+			TaskAuction::confirm_items(Origin::signed(0xA), auction_key, 1),
+			Error::<Test>::ItemsNotSubmitted
+		);
+		assert_err!(
+			TaskAuction::submit_items(Origin::signed(0xD), auction_key, 4),
+			Error::<Test>::TopBidRequired
+		);
+		assert_ok!(TaskAuction::submit_items(Origin::signed(0xC), auction_key, 4));
+		assert_eq!(
+			get_auction_event(),
+			Some(AuctionEvent::ItemsSubmitted { auction_key, count: 4 })
+		);
+		// each subset pays its proportion of the price out of the escrow of the owner
+		assert_ok!(TaskAuction::confirm_items(Origin::signed(0xA), auction_key, 1));
+		assert_eq!(
+			get_auction_event(),
+			Some(AuctionEvent::ItemsConfirmed { auction_key, confirmed: 1, paid: 200 })
+		);
+		assert_balances!(0xA => (10000 - 1500, 1300), 0xC => (10000 - 500 + 200, 500));
+		assert_err!(
+			TaskAuction::submit_items(Origin::signed(0xC), auction_key, 2),
+			Error::<Test>::ItemsInProgress
+		);
+		assert_err!(
+			TaskAuction::confirm_items(Origin::signed(0xA), auction_key, 4),
+			Error::<Test>::ItemCountExceeded
+		);
+		assert_ok!(TaskAuction::confirm_items(Origin::signed(0xA), auction_key, 2));
+		assert_balances!(0xA => (10000 - 1500, 900), 0xC => (10000 - 500 + 600, 500));
+		// last item confirms the auction, paying the rest of the price
+		assert_ok!(TaskAuction::confirm_items(Origin::signed(0xA), auction_key, 1));
+		assert_balances!(0xA => (10000 - 800, 0), 0xC => (10000 + 800, 0));
+		assert!(TaskAuction::auctions(auction_key).is_none());
+		assert_eq!(TaskAuction::delivered_items(auction_key), None);
+	});
+}
+
+#[test]
+fn circuit_breaker() {
+	new_test_ext().execute_with(|| {
+		DisputeRateWindow::set(10);
+		let settled = assign_auction(0xC, 800);
+		let first = assign_auction(0xD, 800);
+		let second = assign_auction(0xE, 800);
+		assert_err!(
+			TaskAuction::clear_circuit_breaker(Origin::root()),
+			Error::<Test>::CircuitBreakerNotTripped
+		);
+		// a single dispute is below the minimum sample
+		assert_ok!(TaskAuction::confirm(Origin::signed(0xA), settled));
+		assert_ok!(TaskAuction::dispute(Origin::signed(0xA), first));
+		run_to_block(System::block_number() + 1);
+		assert_eq!(TaskAuction::circuit_breaker(), None);
+		// disputes then exceed half of the settlements
+		assert_ok!(TaskAuction::dispute(Origin::signed(0xA), second));
+		run_to_block(System::block_number() + 1);
+		assert!(TaskAuction::circuit_breaker().is_some());
+		assert!(System::events().iter().any(|record| record.event ==
+			Event::TaskAuction(AuctionEvent::CircuitBreakerTripped {
+				disputes: 2,
+				settlements: 1
+			})));
+		assert_err!(
+			TaskAuction::create(
+				Origin::signed(0xA),
+				0xB,
+				1000,
+				500,
+				50,
+				vec![0; 8],
+				Default::default()
+			),
+			Error::<Test>::CreationSuspended
+		);
+		// governance resumes creation
+		assert_err!(
+			TaskAuction::clear_circuit_breaker(Origin::signed(0xA)),
+			sp_runtime::traits::BadOrigin
+		);
+		assert_ok!(TaskAuction::clear_circuit_breaker(Origin::root()));
+		assert_eq!(TaskAuction::dispute_rate(), Default::default());
+		create_auction(0xA, 1000, 500, 50);
+	});
+}
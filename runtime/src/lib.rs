@@ -14,7 +14,10 @@ use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
-	traits::{AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, Verify},
+	traits::{
+		AccountIdLookup, BlakeTwo256, Block as BlockT, ConvertInto, IdentifyAccount, NumberFor,
+		Verify,
+	},
 	transaction_validity::{TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, MultiSignature,
 };
@@ -31,7 +34,7 @@ pub use frame_support::{
 		constants::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight, WEIGHT_PER_SECOND},
 		IdentityFee, Weight,
 	},
-	StorageValue,
+	PalletId, StorageValue,
 };
 pub use pallet_balances::Call as BalancesCall;
 pub use pallet_timestamp::Call as TimestampCall;
@@ -121,6 +124,10 @@ pub const MINUTES: BlockNumber = 60_000 / (MILLISECS_PER_BLOCK as BlockNumber);
 pub const HOURS: BlockNumber = MINUTES * 60;
 pub const DAYS: BlockNumber = HOURS * 24;
 
+// Balances are measured in units of the smallest denomination.
+pub const MILLIUNIT: Balance = 1_000_000_000;
+pub const UNIT: Balance = 1_000 * MILLIUNIT;
+
 /// The version information used to identify this runtime when compiled natively.
 #[cfg(feature = "std")]
 pub fn native_version() -> NativeVersion {
@@ -274,18 +281,207 @@ impl pallet_sudo::Config for Runtime {
 }
 
 parameter_types! {
+	pub const MinBounty: Balance = UNIT;
+	pub const MaxBounty: Balance = 1_000_000 * UNIT;
+	pub const MinDeposit: Balance = 100 * MILLIUNIT;
 	pub const MinBidRatio: u8 = 250;
 	pub const MaxDataSize: u32 = 1024;
+	pub const ListingFeeBase: Balance = 10 * MILLIUNIT;
+	pub const ListingFeePerByte: Balance = MILLIUNIT / 100;
+	pub const ListingRefundPeriod: BlockNumber = 10 * MINUTES;
+	pub const RetractCooldown: BlockNumber = MINUTES;
+	pub const OwnerInactivity: BlockNumber = 7 * DAYS;
+	pub const MaxAttachments: u32 = 16;
+	pub const MaxAttachmentSize: u32 = 128;
+	pub const AttachmentDepositPerByte: Balance = MILLIUNIT;
+	pub const MaxBundleSize: u32 = 16;
+	pub const DeferPayouts: bool = false;
+	pub const MaxBidsPerAuction: u32 = 64;
+	pub const MaxLocationLocks: u32 = 32;
+	pub const RatingPeriod: BlockNumber = 7 * DAYS;
+	pub const AppealPeriod: BlockNumber = DAYS;
+	pub const MaxTemplates: u32 = 1024;
+	pub const MaxTemplateNameLength: u32 = 64;
+	pub const TemplateDeposit: Balance = 100 * MILLIUNIT;
+	pub const MaxProjects: u32 = 1024;
+	pub const MaxProjectNameLength: u32 = 64;
+	pub const MaxAuctionsPerProject: u32 = 64;
+	pub const MaxMilestones: u32 = 16;
+	pub const ProjectDeposit: Balance = 100 * MILLIUNIT;
+	pub const ExpectedBlockTime: u64 = MILLISECS_PER_BLOCK;
+	pub const CreationGracePeriod: BlockNumber = 10 * MINUTES;
+	pub const CheckCapacityAtBid: bool = true;
+	pub const EmitSettlementSnapshots: bool = false;
+	pub const MaxOpenDisputesPerArbitrator: u32 = 32;
+	pub const MaxDeadlinesPerBlock: u32 = 64;
+	pub const MaxPromoted: u32 = 32;
+	pub const OpenAuctionTimeout: BlockNumber = 30 * DAYS;
+	pub const AbandonmentWindow: BlockNumber = 3 * DAYS;
+	pub const AbandonmentPenalty: Perbill = Perbill::from_percent(20);
+	pub const CollusionReviewPeriod: BlockNumber = 14 * DAYS;
+	pub const MaxBoostersPerAuction: u32 = 16;
+	pub const CancelConsolation: Perbill = Perbill::from_percent(5);
+	pub const MaxConsolation: Balance = 10 * MILLIUNIT;
+	pub const MaxConsoledBidders: u32 = 8;
+	pub const ArbitrationPeriod: BlockNumber = 30 * DAYS;
+	pub const DefaultJudgmentShare: Perbill = Perbill::from_percent(50);
+	pub const DisputeRateWindow: BlockNumber = 7 * DAYS;
+	pub const MaxDisputeRate: Perbill = Perbill::from_percent(20);
+	pub const MinDisputeSample: u32 = 20;
+	pub const DisputeRetention: BlockNumber = 30 * DAYS;
+	pub const Decimals: u8 = 12;
+	pub const StatsPeriod: BlockNumber = 365 * DAYS;
+	pub const TopUpPeriod: BlockNumber = 10 * MINUTES;
+	pub const TaskAuctionPalletId: PalletId = PalletId(*b"py/tauct");
+}
+
+parameter_types! {
+	pub const MaxArbitrators: u32 = 100;
+}
+
+/// Approved arbitrators, managed by root.
+impl pallet_membership::Config<pallet_membership::Instance1> for Runtime {
+	type Event = Event;
+	type AddOrigin = frame_system::EnsureRoot<AccountId>;
+	type RemoveOrigin = frame_system::EnsureRoot<AccountId>;
+	type SwapOrigin = frame_system::EnsureRoot<AccountId>;
+	type ResetOrigin = frame_system::EnsureRoot<AccountId>;
+	type PrimeOrigin = frame_system::EnsureRoot<AccountId>;
+	type MembershipInitialized = ();
+	type MembershipChanged = ();
+	type MaxMembers = MaxArbitrators;
+	type WeightInfo = pallet_membership::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const MinVestedTransfer: Balance = 100 * MILLIUNIT;
+}
+
+/// Linear vesting of task payouts.
+impl pallet_vesting::Config for Runtime {
+	type Event = Event;
+	type Currency = Balances;
+	type BlockNumberToBalance = ConvertInto;
+	type MinVestedTransfer = MinVestedTransfer;
+	type WeightInfo = pallet_vesting::weights::SubstrateWeight<Runtime>;
+	const MAX_VESTING_SCHEDULES: u32 = 28;
+}
+
+/// Signed origins of local accounts, with no chain to send receipts to.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct EnsureLocalOwner;
+#[cfg(feature = "runtime-benchmarks")]
+impl frame_support::traits::EnsureOrigin<Origin> for EnsureLocalOwner {
+	type Success = (AccountId, ());
+
+	fn try_origin(o: Origin) -> Result<Self::Success, Origin> {
+		match o.clone().into() {
+			Ok(frame_system::RawOrigin::Signed(who)) => Ok((who, ())),
+			_ => Err(o),
+		}
+	}
+
+	fn successful_origin() -> Origin {
+		Origin::signed(AccountId::new([0; 32]))
+	}
 }
 
 /// Configure the task auction pallet.
 impl pallet_task_auction::Config for Runtime {
 	type Event = Event;
 	type Currency = Balances;
-	type MinBounty = ExistentialDeposit;
-	type MinDeposit = ExistentialDeposit;
+	type MinBounty = MinBounty;
+	type MaxBounty = MaxBounty;
+	type MinDeposit = MinDeposit;
 	type MinBidRatio = MinBidRatio;
+	type MinBidPrice = ExistentialDeposit;
 	type MaxDataSize = MaxDataSize;
+	type ListingFeeBase = ListingFeeBase;
+	type ListingFeePerByte = ListingFeePerByte;
+	type ListingRefundPeriod = ListingRefundPeriod;
+	type ListingFeeDestination = ();
+	type RetractCooldown = RetractCooldown;
+	type OwnerInactivity = OwnerInactivity;
+	type MaxAttachments = MaxAttachments;
+	type MaxAttachmentSize = MaxAttachmentSize;
+	type AttachmentDepositPerByte = AttachmentDepositPerByte;
+	type MaxBundleSize = MaxBundleSize;
+	type DeferPayouts = DeferPayouts;
+	type MaxBidsPerAuction = MaxBidsPerAuction;
+	type MaxLocationLocks = MaxLocationLocks;
+	type RatingPeriod = RatingPeriod;
+	type AppealPeriod = AppealPeriod;
+	type MaxTemplates = MaxTemplates;
+	type MaxTemplateNameLength = MaxTemplateNameLength;
+	type TemplateDeposit = TemplateDeposit;
+	type MaxProjects = MaxProjects;
+	type MaxProjectNameLength = MaxProjectNameLength;
+	type MaxAuctionsPerProject = MaxAuctionsPerProject;
+	type MaxMilestones = MaxMilestones;
+	type ProjectDeposit = ProjectDeposit;
+	type ExpectedBlockTime = ExpectedBlockTime;
+	type CreationGracePeriod = CreationGracePeriod;
+	type CheckCapacityAtBid = CheckCapacityAtBid;
+	type EmitSettlementSnapshots = EmitSettlementSnapshots;
+	type MaxOpenDisputesPerArbitrator = MaxOpenDisputesPerArbitrator;
+	type MaxDeadlinesPerBlock = MaxDeadlinesPerBlock;
+	type MaxPromoted = MaxPromoted;
+	type OpenAuctionTimeout = OpenAuctionTimeout;
+	type AbandonmentWindow = AbandonmentWindow;
+	type AbandonmentPenalty = AbandonmentPenalty;
+	type CollusionReviewPeriod = CollusionReviewPeriod;
+	type MaxBoostersPerAuction = MaxBoostersPerAuction;
+	type CancelConsolation = CancelConsolation;
+	type MaxConsolation = MaxConsolation;
+	type MaxConsoledBidders = MaxConsoledBidders;
+	type ArbitrationPeriod = ArbitrationPeriod;
+	type DefaultJudgmentShare = DefaultJudgmentShare;
+	type DisputeRateWindow = DisputeRateWindow;
+	type MaxDisputeRate = MaxDisputeRate;
+	type MinDisputeSample = MinDisputeSample;
+	type PalletId = TaskAuctionPalletId;
+	type DisputeRetention = DisputeRetention;
+	type Decimals = Decimals;
+	type StatsPeriod = StatsPeriod;
+	type TopUpPeriod = TopUpPeriod;
+	type PriceModel = pallet_task_auction::DistancePrice;
+	type DecrementSchedule = pallet_task_auction::ConstantDecrement;
+	type Escrow = pallet_task_auction::ReserveEscrow<Balances>;
+	type Telemetry = ();
+	type DepositReceipts = ();
+	type UnixTime = Timestamp;
+	// parachains with asynchronous backing measure auctions in relay chain blocks instead
+	type BlockNumberProvider = System;
+	// benchmarks list auctions with arbitrators that were never approved
+	#[cfg(any(feature = "permissionless-arbitration", feature = "runtime-benchmarks"))]
+	type ArbitratorSet = frame_support::traits::Everything;
+	#[cfg(not(any(feature = "permissionless-arbitration", feature = "runtime-benchmarks")))]
+	type ArbitratorSet = Arbitrators;
+	type Vesting = Vesting;
+	type ForceOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type GovernanceOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type ArbitrationOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type AttestationOrigin = frame_system::EnsureRoot<Self::AccountId>;
+	type QuoteSignature = Signature;
+	type QuoteSigner = <Signature as Verify>::Signer;
+	// no XCM configured, auctions are only created locally
+	type ReceiptDestination = ();
+	// benchmarks request receipts for local owners standing in for remote ones
+	#[cfg(feature = "runtime-benchmarks")]
+	type RemoteOrigin = EnsureLocalOwner;
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type RemoteOrigin = frame_system::EnsureNever<(AccountId, ())>;
+	type ReceiptSender = ();
+	// runtimes with the proxy pallet use pallet_task_auction::ProxyLinks<Runtime>
+	type AccountLinks = ();
+	// no oracle configured, auctions can't be quoted in another asset
+	type QuoteAsset = u32;
+	type PriceOracle = ();
+	// auctions stay in the native currency until an assets pallet is added, which can back
+	// escrow with FungiblesEscrow<Assets, TaskAuctionPalletId>
+	type AssetId = u32;
+	type AssetEscrow = ();
+	type WeightInfo = pallet_task_auction::weights::SubstrateWeight<Runtime>;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -303,6 +499,8 @@ construct_runtime!(
 		Balances: pallet_balances,
 		TransactionPayment: pallet_transaction_payment,
 		Sudo: pallet_sudo,
+		Arbitrators: pallet_membership::<Instance1>,
+		Vesting: pallet_vesting,
 		// Include the custom logic from the pallet-template in the runtime.
 		TaskAuction: pallet_task_auction,
 	}
@@ -322,7 +520,10 @@ pub type SignedExtra = (
 	frame_system::CheckEra<Runtime>,
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
-	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+	pallet_task_auction::WaiveSettlementFees<
+		Runtime,
+		pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+	>,
 );
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<Address, Call, Signature, SignedExtra>;
@@ -466,6 +667,67 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_task_auction_runtime_api::TaskAuctionApi<Block, AccountId, Index, Balance, BlockNumber>
+		for Runtime
+	{
+		fn max_next_bid(auction_key: (AccountId, Index)) -> Option<Balance> {
+			TaskAuction::max_next_bid(&auction_key.into())
+		}
+
+		fn min_create_params() -> (Balance, Balance) {
+			TaskAuction::min_create_params()
+		}
+
+		fn estimate_create(
+			bounty: Balance,
+			deposit: Balance,
+			data_len: u32,
+		) -> pallet_task_auction_runtime_api::CreateEstimate<Balance> {
+			TaskAuction::estimate_create(bounty, deposit, data_len)
+		}
+
+		fn rating(who: AccountId) -> (u32, u32) {
+			let summary = TaskAuction::ratings(who);
+			(summary.sum, summary.count)
+		}
+
+		fn arbitration_preview(
+			auction_key: (AccountId, Index),
+		) -> Option<(
+			Vec<pallet_task_auction_runtime_api::FundsMovement<AccountId, Balance>>,
+			Vec<pallet_task_auction_runtime_api::FundsMovement<AccountId, Balance>>,
+		)> {
+			TaskAuction::arbitration_preview(&auction_key.into())
+		}
+
+		fn auction_status(
+			auction_key: (AccountId, Index),
+		) -> Option<pallet_task_auction_runtime_api::AuctionStatus<Balance, BlockNumber>> {
+			TaskAuction::auction_status(&auction_key.into())
+		}
+
+		fn estimate_timestamp(block: BlockNumber) -> u64 {
+			TaskAuction::estimate_timestamp(block)
+		}
+
+		fn arbitrator_scorecard(
+			arbitrator: AccountId,
+		) -> pallet_task_auction_runtime_api::ArbitratorScorecard<BlockNumber> {
+			TaskAuction::arbitrator_scorecard(&arbitrator)
+		}
+
+		fn marketplace_health() -> pallet_task_auction_runtime_api::HealthSnapshot<BlockNumber> {
+			TaskAuction::marketplace_health()
+		}
+
+		fn account_statement(
+			who: AccountId,
+			period: u32,
+		) -> pallet_task_auction_runtime_api::AccountStatement<Balance, BlockNumber> {
+			TaskAuction::account_statement(&who, period)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (